@@ -1,15 +1,21 @@
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
-use std::sync::{Arc, Mutex};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
 use std::time::{Duration, Instant};
-use tauri::{AppHandle, Emitter, State};
+use tauri::{AppHandle, Emitter, Manager, State};
 use uuid::Uuid;
 
 // Import media types
 use app_lib::media::{TrelloBoard, TrelloCard, VideoLink};
 
+use crate::commands::{fetch_sprout_video_details, get_username};
+use crate::state::UploadState;
+
 // Performance optimization constants
 const PROGRESS_UPDATE_INTERVAL: Duration = Duration::from_millis(100); // Update UI every 100ms
 const SKIP_PATTERNS: &[&str] = &[
@@ -28,9 +34,34 @@ const SKIP_PATTERNS: &[&str] = &[
     ".DS_Store",
 ];
 
+// Association limits
+const MAX_VIDEO_LINKS: usize = 20;
+
 // Stale breadcrumbs detection constants
 const STALE_SIZE_THRESHOLD_BYTES: u64 = 1024; // 1KB - minimum folder size change to consider breadcrumbs stale
 
+// Fast size mode only descends this many folders below a project root
+const FAST_SIZE_MAX_DEPTH: usize = 2;
+// Levels calculate_folder_size_parallel may descend looking for work to split
+const PARALLEL_SIZE_SPLIT_DEPTH: usize = 3;
+
+// Footage subfolders matching this are cameras; group 1 is the camera label.
+// Matches "Camera 1", "Camera 01", "Camera A", "Cam 2", "cam_b" and so on.
+const DEFAULT_CAMERA_FOLDER_PATTERN: &str = r"(?i)^cam(?:era)?[\s_-]*([0-9]+|[A-Za-z])$";
+
+// Set from the cameraFolderPattern app setting; None uses the default pattern
+static CUSTOM_CAMERA_FOLDER_REGEX: RwLock<Option<regex::Regex>> = RwLock::new(None);
+
+// Duplicate detection reads this many leading bytes of each clip
+const FOOTAGE_HASH_SAMPLE_BYTES: usize = 1024 * 1024;
+
+// Incremental scans keep folder fingerprints here, in the app data dir
+const SCAN_CACHE_FILE: &str = "baker_scan_cache.json";
+
+// Completed scans are kept across restarts, newest MAX_RETAINED_SCANS only
+const SCAN_HISTORY_FILE: &str = "baker_scan_history.json";
+const MAX_RETAINED_SCANS: usize = 20;
+
 // Data structures matching TypeScript interfaces
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProjectFolder {
@@ -47,9 +78,34 @@ pub struct ProjectFolder {
     #[serde(rename = "cameraCount")]
     camera_count: i32,
     #[serde(rename = "validationErrors")]
-    validation_errors: Vec<String>,
+    validation_errors: Vec<ValidationError>,
     #[serde(rename = "invalidBreadcrumbs")]
     invalid_breadcrumbs: bool,
+    /// Size in bytes as measured by the scan (a lower bound in fast size mode)
+    #[serde(rename = "folderSize", default)]
+    folder_size: u64,
+}
+
+/// A project structure problem with a stable code for the frontend to match on
+///
+/// Codes: FOLDER_NOT_FOUND, MISSING_SUBFOLDER (with `subfolder` set) and
+/// NO_CAMERAS. `message` is the same human-readable text as before.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidationError {
+    code: String,
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subfolder: Option<String>,
+}
+
+impl ValidationError {
+    fn new(code: &str, message: impl Into<String>, subfolder: Option<&str>) -> Self {
+        Self {
+            code: code.to_string(),
+            message: message.into(),
+            subfolder: subfolder.map(|s| s.to_string()),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -84,10 +140,51 @@ pub struct BreadcrumbsFile {
     /// Array of Trello cards associated with this project
     #[serde(rename = "trelloCards", skip_serializing_if = "Option::is_none")]
     pub trello_cards: Option<Vec<TrelloCard>>,
+
+    /// One entry per write, oldest first
+    #[serde(
+        rename = "modificationHistory",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub modification_history: Option<Vec<AuditEntry>>,
+
+    /// Cold-storage archive holding this project's Footage folder, if archived
+    #[serde(rename = "archivedTo", skip_serializing_if = "Option::is_none")]
+    pub archived_to: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub by: String,
+    pub at: String,
+    pub action: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FileInfo {
+    pub camera: i32,
+    /// Raw suffix of the camera folder (e.g. "A" or "01"); camera is its numeric index
+    #[serde(
+        rename = "cameraLabel",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub camera_label: Option<String>,
+    pub name: String,
+    pub path: String,
+    /// Hex SHA-256 of the file contents, only recorded when hashing was requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LockedFile {
+    path: String,
+    reason: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MissingFile {
     camera: i32,
     name: String,
     path: String,
@@ -113,6 +210,70 @@ pub struct ScanResult {
     total_folder_size: u64,
     errors: Vec<ScanError>,
     projects: Vec<ProjectFolder>,
+    #[serde(default)]
+    metrics: ScanMetrics,
+    /// True if the scan was stopped by baker_cancel_scan before finishing
+    #[serde(default)]
+    cancelled: bool,
+    /// Projects reused from the incremental scan cache without revalidating
+    #[serde(rename = "bakerScanSkipped", default)]
+    baker_scan_skipped: i32,
+}
+
+/// Time spent in each phase of a scan, in milliseconds
+///
+/// Enumeration is whatever remains of the total once the other phases are
+/// subtracted: reading directories, skip checks and progress events.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScanMetrics {
+    #[serde(rename = "totalMs")]
+    total_ms: f64,
+    #[serde(rename = "enumerationMs")]
+    enumeration_ms: f64,
+    #[serde(rename = "validationMs")]
+    validation_ms: f64,
+    #[serde(rename = "sizeCalculationMs")]
+    size_calculation_ms: f64,
+    #[serde(rename = "staleDetectionMs")]
+    stale_detection_ms: f64,
+    #[serde(rename = "foldersPerSecond")]
+    folders_per_second: f64,
+}
+
+/// Aggregate counts for a scan, so the dashboard needn't fetch every project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScanSummary {
+    #[serde(rename = "totalProjects")]
+    total_projects: usize,
+    #[serde(rename = "validProjects")]
+    valid_projects: usize,
+    #[serde(rename = "invalidProjects")]
+    invalid_projects: usize,
+    #[serde(rename = "staleBreadcrumbs")]
+    stale_breadcrumbs: usize,
+    #[serde(rename = "missingBreadcrumbs")]
+    missing_breadcrumbs: usize,
+    #[serde(rename = "totalCameras")]
+    total_cameras: i64,
+    #[serde(rename = "totalFolderSize")]
+    total_folder_size: u64,
+    #[serde(rename = "averageFolderSize")]
+    average_folder_size: u64,
+    #[serde(rename = "largestProject")]
+    largest_project: Option<LargestProject>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LargestProject {
+    path: String,
+    name: String,
+    #[serde(rename = "folderSize")]
+    folder_size: u64,
+}
+
+/// Helper: Milliseconds elapsed since `start`
+fn elapsed_ms(start: Instant) -> f64 {
+    start.elapsed().as_secs_f64() * 1000.0
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -125,6 +286,7 @@ pub struct ScanError {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScanOptions {
+    /// Folder levels to descend below the root; 0 means unlimited, negative is rejected
     #[serde(rename = "maxDepth")]
     max_depth: i32,
     #[serde(rename = "includeHidden")]
@@ -133,6 +295,35 @@ pub struct ScanOptions {
     create_missing: bool,
     #[serde(rename = "backupOriginals")]
     backup_originals: bool,
+    #[serde(rename = "sizeMode")]
+    size_mode: Option<SizeMode>,
+    /// Count folders before scanning so progress events carry a real total
+    #[serde(rename = "estimateTotal", default = "default_true")]
+    estimate_total: bool,
+    /// Reuse cached results for project folders that haven't changed since the last scan
+    #[serde(default)]
+    incremental: bool,
+    /// Hash footage contents: recorded by baker_update_breadcrumbs, compared by stale checks
+    #[serde(rename = "hashFiles", default)]
+    hash_files: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// How folder sizes are totalled during a scan
+///
+/// `Exact` walks every file in every project. `Fast` only counts files within
+/// FAST_SIZE_MAX_DEPTH folders of the project root, which covers the standard
+/// Footage/Camera N layout but undercounts anything nested deeper (e.g. camera
+/// card structures copied verbatim), so the total is a lower bound.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SizeMode {
+    #[default]
+    Exact,
+    Fast,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -149,6 +340,27 @@ pub struct FailedUpdate {
     error: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportResult {
+    projects: Vec<ProjectImportResult>,
+    #[serde(rename = "totalImported")]
+    total_imported: usize,
+    #[serde(rename = "totalSkipped")]
+    total_skipped: usize,
+    #[serde(rename = "totalFailed")]
+    total_failed: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectImportResult {
+    #[serde(rename = "projectPath")]
+    project_path: String,
+    imported: usize,
+    skipped: usize,
+    failed: usize,
+    errors: Vec<String>,
+}
+
 // Event payloads for progress tracking
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScanProgressEvent {
@@ -167,16 +379,179 @@ pub struct ScanProgressEvent {
 // Scan state management
 pub struct ScanState {
     scans: Arc<Mutex<HashMap<String, ScanResult>>>,
+    cancel_flags: Arc<Mutex<HashMap<String, Arc<AtomicBool>>>>,
+    history_path: Option<PathBuf>,
 }
 
 impl ScanState {
-    pub fn new() -> Self {
+    /// Restore completed scans saved by previous sessions
+    ///
+    /// A missing or unreadable history file just starts an empty history.
+    pub fn load(app: &AppHandle) -> Self {
+        let history_path = app
+            .path()
+            .app_data_dir()
+            .ok()
+            .map(|dir| dir.join(SCAN_HISTORY_FILE));
+
+        let scans: HashMap<String, ScanResult> = history_path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|content| match serde_json::from_str(&content) {
+                Ok(scans) => Some(scans),
+                Err(e) => {
+                    println!("[Baker] Ignoring unreadable scan history: {}", e);
+                    None
+                }
+            })
+            .unwrap_or_default();
+
+        println!("[Baker] Loaded {} scans from history", scans.len());
+
+        Self {
+            scans: Arc::new(Mutex::new(scans)),
+            cancel_flags: Arc::new(Mutex::new(HashMap::new())),
+            history_path,
+        }
+    }
+}
+
+/// Helper: Drop all but the newest MAX_RETAINED_SCANS scans and write the rest to disk
+fn persist_scan_history(
+    scans: &mut HashMap<String, ScanResult>,
+    history_path: &Path,
+) -> Result<(), String> {
+    if scans.len() > MAX_RETAINED_SCANS {
+        let mut by_age: Vec<(String, String)> = scans
+            .iter()
+            .map(|(id, result)| (result.start_time.clone(), id.clone()))
+            .collect();
+        by_age.sort();
+
+        let excess = scans.len() - MAX_RETAINED_SCANS;
+        for (_, id) in by_age.into_iter().take(excess) {
+            scans.remove(&id);
+        }
+    }
+
+    if let Some(parent) = history_path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+
+    let json = serde_json::to_string(scans).map_err(|e| e.to_string())?;
+    fs::write(history_path, json).map_err(|e| e.to_string())
+}
+
+/// A project folder as seen by a previous incremental scan
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedFolder {
+    #[serde(rename = "modifiedMs")]
+    modified_ms: u64,
+    #[serde(rename = "entryCount")]
+    entry_count: usize,
+    #[serde(rename = "sizeMode")]
+    size_mode: SizeMode,
+    /// Stale detection differs with hashing, so the flag is part of the key
+    #[serde(rename = "hashFiles", default)]
+    hash_files: bool,
+    #[serde(rename = "folderSize")]
+    folder_size: u64,
+    project: ProjectFolder,
+}
+
+/// On-disk cache of project folders for incremental scans, keyed by path
+///
+/// A folder is reused while its own mtime and top-level entry count are
+/// unchanged and it was scanned with the same size mode and hashing option. Edits deeper inside (e.g. a clip replaced in Footage/Camera 1)
+/// don't touch either, so a full scan is still needed to catch those.
+#[derive(Default)]
+struct ScanCache {
+    /// None when the scan isn't incremental; lookups miss and nothing is saved
+    path: Option<PathBuf>,
+    entries: HashMap<String, CachedFolder>,
+}
+
+impl ScanCache {
+    fn load(path: PathBuf) -> Self {
+        let entries = fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+
         Self {
-            scans: Arc::new(Mutex::new(HashMap::new())),
+            path: Some(path),
+            entries,
+        }
+    }
+
+    fn lookup(&self, dir: &Path, size_mode: SizeMode, hash_files: bool) -> Option<&CachedFolder> {
+        self.path.as_ref()?;
+
+        let cached = self.entries.get(&dir.to_string_lossy().to_string())?;
+        let (modified_ms, entry_count) = folder_fingerprint(dir)?;
+
+        (cached.modified_ms == modified_ms
+            && cached.entry_count == entry_count
+            && cached.size_mode == size_mode
+            && cached.hash_files == hash_files)
+            .then_some(cached)
+    }
+
+    fn store(
+        &mut self,
+        dir: &Path,
+        project: &ProjectFolder,
+        folder_size: u64,
+        size_mode: SizeMode,
+        hash_files: bool,
+    ) {
+        if self.path.is_none() {
+            return;
+        }
+
+        if let Some((modified_ms, entry_count)) = folder_fingerprint(dir) {
+            self.entries.insert(
+                dir.to_string_lossy().to_string(),
+                CachedFolder {
+                    modified_ms,
+                    entry_count,
+                    size_mode,
+                    hash_files,
+                    folder_size,
+                    project: project.clone(),
+                },
+            );
+        }
+    }
+
+    fn save(&self) {
+        let Some(path) = &self.path else {
+            return;
+        };
+
+        let result = serde_json::to_string(&self.entries)
+            .map_err(|e| e.to_string())
+            .and_then(|json| fs::write(path, json).map_err(|e| e.to_string()));
+
+        if let Err(e) = result {
+            println!("[Baker] Failed to save scan cache: {}", e);
         }
     }
 }
 
+/// Helper: A folder's mtime in milliseconds and its number of direct entries
+fn folder_fingerprint(dir: &Path) -> Option<(u64, usize)> {
+    let modified_ms = fs::metadata(dir)
+        .and_then(|metadata| metadata.modified())
+        .ok()?
+        .duration_since(std::time::UNIX_EPOCH)
+        .ok()?
+        .as_millis() as u64;
+    let entry_count = fs::read_dir(dir).ok()?.count();
+
+    Some((modified_ms, entry_count))
+}
+
 // Helper functions
 fn get_current_timestamp() -> String {
     chrono::Utc::now().to_rfc3339()
@@ -193,7 +568,35 @@ fn should_skip_directory(path: &Path) -> bool {
     }
 }
 
-fn calculate_folder_size(path: &Path) -> Result<u64, std::io::Error> {
+/// Helper: Folder size according to the scan's size mode
+fn calculate_folder_size_with_mode(path: &Path, mode: SizeMode) -> Result<u64, std::io::Error> {
+    match mode {
+        SizeMode::Exact => calculate_folder_size_parallel(path),
+        SizeMode::Fast => calculate_folder_size_shallow(path, FAST_SIZE_MAX_DEPTH),
+    }
+}
+
+/// Helper: Sum file sizes, not descending more than `max_depth` folders below `path`
+fn calculate_folder_size_shallow(path: &Path, max_depth: usize) -> Result<u64, std::io::Error> {
+    let mut total = 0u64;
+
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+
+        if file_type.is_dir() {
+            if max_depth > 0 {
+                total += calculate_folder_size_shallow(&entry.path(), max_depth - 1)?;
+            }
+        } else if let Ok(metadata) = entry.metadata() {
+            total += metadata.len();
+        }
+    }
+
+    Ok(total)
+}
+
+pub(crate) fn calculate_folder_size(path: &Path) -> Result<u64, std::io::Error> {
     let mut total_size = 0u64;
 
     fn visit_dir(dir: &Path, total: &mut u64) -> Result<(), std::io::Error> {
@@ -218,7 +621,96 @@ fn calculate_folder_size(path: &Path) -> Result<u64, std::io::Error> {
     Ok(total_size)
 }
 
-fn check_breadcrumbs_stale(path: &Path) -> Result<bool, std::io::Error> {
+/// Helper: Exact folder size, walking subfolders on separate threads
+///
+/// Projects usually keep everything under a single Footage folder, so the walk
+/// descends up to PARALLEL_SIZE_SPLIT_DEPTH levels until there are enough
+/// folders to give each core a share, then sums each share on its own thread.
+fn calculate_folder_size_parallel(path: &Path) -> Result<u64, std::io::Error> {
+    let workers = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(4);
+
+    let mut total = 0u64;
+    let mut frontier = vec![path.to_path_buf()];
+
+    for _ in 0..PARALLEL_SIZE_SPLIT_DEPTH {
+        if frontier.len() >= workers {
+            break;
+        }
+
+        let mut next = Vec::new();
+        for dir in &frontier {
+            for entry in fs::read_dir(dir)? {
+                let entry = entry?;
+                let entry_path = entry.path();
+
+                if entry_path.is_dir() {
+                    next.push(entry_path);
+                } else if let Ok(metadata) = entry.metadata() {
+                    total += metadata.len();
+                }
+            }
+        }
+        frontier = next;
+    }
+
+    if frontier.is_empty() {
+        return Ok(total);
+    }
+
+    let chunk_size = frontier.len().div_ceil(workers);
+    let frontier_total = std::thread::scope(|scope| {
+        let handles: Vec<_> = frontier
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk.iter().try_fold(0u64, |sum, dir| {
+                        calculate_folder_size(dir).map(|size| sum + size)
+                    })
+                })
+            })
+            .collect();
+
+        handles.into_iter().try_fold(0u64, |sum, handle| {
+            let size = handle
+                .join()
+                .map_err(|_| std::io::Error::other("Folder size worker panicked"))??;
+            Ok::<u64, std::io::Error>(sum + size)
+        })
+    })?;
+
+    Ok(total + frontier_total)
+}
+
+/// Helper: Hex SHA-256 of a file's full contents
+fn hash_file_sha256(path: &Path) -> Result<String, std::io::Error> {
+    let mut file = fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0u8; 1024 * 1024];
+
+    loop {
+        let n = file.read(&mut buffer)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buffer[..n]);
+    }
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Check whether breadcrumbs no longer match the files on disk
+///
+/// `known_size` is an exact folder size already computed by the caller (e.g.
+/// during a scan), which saves walking the project a second time. With
+/// `compare_hashes`, files that have a recorded sha256 are re-hashed, which
+/// catches clips re-rendered in place at the same size.
+fn check_breadcrumbs_stale(
+    path: &Path,
+    known_size: Option<u64>,
+    compare_hashes: bool,
+) -> Result<bool, std::io::Error> {
     let breadcrumbs_path = path.join("breadcrumbs.json");
 
     if !breadcrumbs_path.exists() {
@@ -240,45 +732,7 @@ fn check_breadcrumbs_stale(path: &Path) -> Result<bool, std::io::Error> {
     };
 
     // Scan actual current files
-    let mut actual_files = Vec::new();
-    let footage_path = path.join("Footage");
-
-    if let Ok(entries) = fs::read_dir(&footage_path) {
-        for entry in entries {
-            if let Ok(entry) = entry {
-                let folder_name = entry.file_name();
-                let name_str = folder_name.to_string_lossy().to_string();
-
-                if name_str.starts_with("Camera ") && entry.path().is_dir() {
-                    if let Some(camera_num_str) = name_str.strip_prefix("Camera ") {
-                        if let Ok(camera_num) = camera_num_str.parse::<i32>() {
-                            if let Ok(camera_files) = fs::read_dir(entry.path()) {
-                                for file in camera_files {
-                                    if let Ok(file) = file {
-                                        let file_name =
-                                            file.file_name().to_string_lossy().to_string();
-
-                                        // Skip hidden files (starting with .) like .DS_Store
-                                        if file_name.starts_with('.') {
-                                            continue;
-                                        }
-
-                                        if file.path().is_file() {
-                                            actual_files.push(FileInfo {
-                                                camera: camera_num,
-                                                name: file_name.clone(),
-                                                path: format!("Footage/{}/{}", name_str, file_name),
-                                            });
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-        }
-    }
+    let mut actual_files = collect_camera_files(path);
 
     // Compare files: check if counts or content differ
     if existing_breadcrumbs.files.len() != actual_files.len() {
@@ -297,8 +751,20 @@ fn check_breadcrumbs_stale(path: &Path) -> Result<bool, std::io::Error> {
         }
     }
 
+    if compare_hashes {
+        for existing in existing_files.iter() {
+            if let Some(expected) = &existing.sha256 {
+                match hash_file_sha256(&path.join(&existing.path)) {
+                    Ok(actual) if &actual == expected => {}
+                    _ => return Ok(true), // Contents changed or unreadable = stale
+                }
+            }
+        }
+    }
+
     // Compare folder size to detect file content changes (with 1KB threshold)
-    let current_folder_size = calculate_folder_size(path).unwrap_or(0);
+    let current_folder_size =
+        known_size.unwrap_or_else(|| calculate_folder_size(path).unwrap_or(0));
     if let Some(existing_size) = existing_breadcrumbs.folder_size_bytes {
         let size_diff = if current_folder_size > existing_size {
             current_folder_size - existing_size
@@ -346,12 +812,16 @@ fn has_invalid_breadcrumbs_file(path: &Path) -> bool {
     }
 }
 
-fn validate_project_folder(path: &Path) -> (bool, Vec<String>, i32) {
+fn validate_project_folder(path: &Path) -> (bool, Vec<ValidationError>, i32) {
     let mut errors = Vec::new();
     let mut camera_count = 0;
 
     if !path.exists() {
-        errors.push("Folder does not exist".to_string());
+        errors.push(ValidationError::new(
+            "FOLDER_NOT_FOUND",
+            "Folder does not exist",
+            None,
+        ));
         return (false, errors, 0);
     }
 
@@ -361,7 +831,11 @@ fn validate_project_folder(path: &Path) -> (bool, Vec<String>, i32) {
     for folder in &required_folders {
         let subfolder = path.join(folder);
         if !subfolder.exists() || !subfolder.is_dir() {
-            errors.push(format!("Missing required subfolder: {}", folder));
+            errors.push(ValidationError::new(
+                "MISSING_SUBFOLDER",
+                format!("Missing required subfolder: {}", folder),
+                Some(folder),
+            ));
         }
     }
 
@@ -373,7 +847,7 @@ fn validate_project_folder(path: &Path) -> (bool, Vec<String>, i32) {
                 if let Ok(entry) = entry {
                     let file_name = entry.file_name();
                     let name_str = file_name.to_string_lossy();
-                    if name_str.starts_with("Camera ") && entry.path().is_dir() {
+                    if parse_camera_folder(&name_str).is_some() && entry.path().is_dir() {
                         camera_count += 1;
                     }
                 }
@@ -382,12 +856,161 @@ fn validate_project_folder(path: &Path) -> (bool, Vec<String>, i32) {
     }
 
     if camera_count == 0 {
-        errors.push("No Camera folders found in Footage directory".to_string());
+        errors.push(ValidationError::new(
+            "NO_CAMERAS",
+            "No Camera folders found in Footage directory",
+            None,
+        ));
     }
 
     (errors.is_empty(), errors, camera_count)
 }
 
+/// Replace the camera folder pattern, or restore the default with None
+///
+/// The pattern must have a capture group for the camera label.
+pub(crate) fn set_camera_folder_pattern(pattern: Option<&str>) -> Result<(), String> {
+    let regex = match pattern {
+        Some(pattern) => Some(compile_camera_folder_pattern(pattern)?),
+        None => None,
+    };
+
+    let mut custom = CUSTOM_CAMERA_FOLDER_REGEX
+        .write()
+        .map_err(|_| "Failed to acquire lock")?;
+    *custom = regex;
+    Ok(())
+}
+
+pub(crate) fn compile_camera_folder_pattern(pattern: &str) -> Result<regex::Regex, String> {
+    let regex = regex::Regex::new(pattern).map_err(|e| format!("Invalid pattern: {}", e))?;
+
+    if regex.captures_len() < 2 {
+        return Err("Pattern needs a capture group for the camera label".to_string());
+    }
+
+    Ok(regex)
+}
+
+/// Helper: Parse a Footage subfolder name into its numeric index (if any) and raw label
+///
+/// Numeric labels keep their value ("01" is camera 1); single letters have no
+/// index of their own and are numbered by `camera_indices`.
+fn parse_camera_folder(folder_name: &str) -> Option<(Option<i32>, String)> {
+    static DEFAULT_REGEX: OnceLock<regex::Regex> = OnceLock::new();
+
+    let custom = CUSTOM_CAMERA_FOLDER_REGEX
+        .read()
+        .ok()
+        .and_then(|regex| regex.clone());
+    let regex = custom.as_ref().unwrap_or_else(|| {
+        DEFAULT_REGEX.get_or_init(|| {
+            regex::Regex::new(DEFAULT_CAMERA_FOLDER_PATTERN).expect("valid camera pattern")
+        })
+    });
+
+    let label = regex.captures(folder_name)?.get(1)?.as_str().to_string();
+
+    if let Ok(index) = label.parse::<i32>() {
+        return Some((Some(index), label));
+    }
+
+    let mut chars = label.chars();
+    match (chars.next(), chars.next()) {
+        (Some(letter), None) if letter.is_ascii_alphabetic() => Some((None, label)),
+        _ => None,
+    }
+}
+
+/// Helper: Camera index for each label returned by `parse_camera_folder`
+///
+/// Lettered cameras are numbered in alphabetical order after the highest
+/// numeric camera, so "Camera A" next to "Camera 1" and "Camera 2" becomes
+/// camera 3 rather than colliding with camera 1.
+fn camera_indices(cameras: &[(Option<i32>, String)]) -> HashMap<String, i32> {
+    let highest_numeric = cameras
+        .iter()
+        .filter_map(|(index, _)| *index)
+        .max()
+        .unwrap_or(0);
+
+    let mut letters: Vec<String> = cameras
+        .iter()
+        .filter(|(index, _)| index.is_none())
+        .map(|(_, label)| label.to_ascii_uppercase())
+        .collect();
+    letters.sort();
+    letters.dedup();
+
+    let mut indices: HashMap<String, i32> = cameras
+        .iter()
+        .filter_map(|(index, label)| index.map(|index| (label.clone(), index)))
+        .collect();
+    for (_, label) in cameras.iter().filter(|(index, _)| index.is_none()) {
+        let rank = letters
+            .iter()
+            .position(|letter| *letter == label.to_ascii_uppercase())
+            .unwrap_or(0);
+        indices.insert(label.clone(), highest_numeric + rank as i32 + 1);
+    }
+
+    indices
+}
+
+/// Helper: List footage files in every camera folder of a project, unsorted
+fn collect_camera_files(project_path: &Path) -> Vec<FileInfo> {
+    let mut files = Vec::new();
+
+    let Ok(entries) = fs::read_dir(project_path.join("Footage")) else {
+        return files;
+    };
+
+    let camera_folders: Vec<(fs::DirEntry, (Option<i32>, String))> = entries
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| {
+            let camera = parse_camera_folder(&entry.file_name().to_string_lossy())?;
+            Some((entry, camera))
+        })
+        .collect();
+    let indices = camera_indices(
+        &camera_folders
+            .iter()
+            .map(|(_, camera)| camera.clone())
+            .collect::<Vec<_>>(),
+    );
+
+    for (entry, (_, label)) in camera_folders {
+        let folder_name = entry.file_name().to_string_lossy().to_string();
+        let camera = indices[&label];
+
+        let Ok(camera_files) = fs::read_dir(entry.path()) else {
+            continue;
+        };
+
+        for file in camera_files.flatten() {
+            let file_name = file.file_name().to_string_lossy().to_string();
+
+            // Skip hidden files (starting with .) like .DS_Store
+            if file_name.starts_with('.') {
+                continue;
+            }
+
+            if file.path().is_file() {
+                files.push(FileInfo {
+                    camera,
+                    camera_label: Some(label.clone()),
+                    name: file_name.clone(),
+                    path: format!("Footage/{}/{}", folder_name, file_name),
+                    sha256: None,
+                });
+            }
+        }
+    }
+
+    files
+}
+
 fn has_breadcrumbs_file(path: &Path) -> bool {
     let breadcrumbs_path = path.join("breadcrumbs.json");
 
@@ -429,11 +1052,113 @@ fn has_breadcrumbs_file(path: &Path) -> bool {
     }
 }
 
+/// Helper: Record a non-fatal filesystem error and emit it as a baker_scan_error event
+///
+/// These events carry `fatal: false` so listeners can keep the scan running.
+fn record_scan_error(
+    result: &mut ScanResult,
+    app_handle: &AppHandle,
+    scan_id: &str,
+    path: &Path,
+    error: &std::io::Error,
+) {
+    let error_type = if error.kind() == std::io::ErrorKind::PermissionDenied {
+        "permission"
+    } else {
+        "io"
+    };
+
+    let scan_error = ScanError {
+        path: path.to_string_lossy().to_string(),
+        r#type: error_type.to_string(),
+        message: error.to_string(),
+        timestamp: get_current_timestamp(),
+    };
+    println!(
+        "[Baker] Skipping {} ({}): {}",
+        scan_error.path, error_type, scan_error.message
+    );
+
+    let error_event = serde_json::json!({
+        "scanId": scan_id,
+        "fatal": false,
+        "error": scan_error
+    });
+    let _ = app_handle.emit("baker_scan_error", error_event);
+
+    result.errors.push(scan_error);
+}
+
+/// Helper: Emit a baker_scan_discovery event for a project folder found by a scan
+fn emit_scan_discovery(app_handle: &AppHandle, scan_id: &str, project: &ProjectFolder) {
+    let discovery_event = serde_json::json!({
+        "scanId": scan_id,
+        "projectPath": project.path,
+        "isValid": project.is_valid,
+        "hasBreadcrumbs": project.has_breadcrumbs,
+        "invalidBreadcrumbs": project.invalid_breadcrumbs,
+        "errors": project.validation_errors
+    });
+    let _ = app_handle.emit("baker_scan_discovery", discovery_event);
+}
+
+/// Helper: Estimate how many folders a scan will visit
+///
+/// Mirrors visit_directory's filters without validating anything: hidden and
+/// SKIP_PATTERNS folders are ignored, and folders that look like projects
+/// (Footage, Graphics or breadcrumbs.json present) are counted but not entered.
+fn count_scan_folders(
+    dir: &Path,
+    depth: i32,
+    max_depth: i32,
+    include_hidden: bool,
+    cancel_flag: &AtomicBool,
+) -> i32 {
+    if (max_depth > 0 && depth > max_depth) || cancel_flag.load(Ordering::Relaxed) {
+        return 0;
+    }
+
+    let Ok(entries) = fs::read_dir(dir) else {
+        return 0;
+    };
+
+    let mut count = 0;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+
+        let name = entry.file_name();
+        if (!include_hidden && name.to_string_lossy().starts_with('.'))
+            || should_skip_directory(&path)
+        {
+            continue;
+        }
+
+        count += 1;
+
+        let looks_like_project = path.join("Footage").exists()
+            || path.join("Graphics").exists()
+            || path.join("breadcrumbs.json").exists();
+        let unbounded_symlink =
+            max_depth == 0 && entry.file_type().map(|t| t.is_symlink()).unwrap_or(false);
+
+        if !looks_like_project && !unbounded_symlink {
+            count += count_scan_folders(&path, depth + 1, max_depth, include_hidden, cancel_flag);
+        }
+    }
+
+    count
+}
+
 fn scan_directory_recursive(
     root_path: &Path,
     options: &ScanOptions,
     app_handle: &AppHandle,
     scan_id: &str,
+    cancel_flag: &AtomicBool,
+    scan_cache: &mut ScanCache,
 ) -> Result<ScanResult, String> {
     let mut result = ScanResult {
         start_time: get_current_timestamp(),
@@ -446,30 +1171,73 @@ fn scan_directory_recursive(
         total_folder_size: 0,
         errors: Vec::new(),
         projects: Vec::new(),
+        metrics: ScanMetrics::default(),
+        cancelled: false,
+        baker_scan_skipped: 0,
     };
 
+    let scan_start = Instant::now();
+    let size_mode = options.size_mode.unwrap_or_default();
     let mut folders_scanned = 0;
     let mut last_progress_update = Instant::now();
 
+    let estimated_total = if options.estimate_total {
+        let estimate = count_scan_folders(
+            root_path,
+            0,
+            options.max_depth,
+            options.include_hidden,
+            cancel_flag,
+        );
+        println!("[Baker] Estimated {} folders to scan", estimate);
+        estimate
+    } else {
+        0
+    };
+
     fn visit_directory(
         dir: &Path,
         depth: i32,
         max_depth: i32,
         include_hidden: bool,
+        size_mode: SizeMode,
         result: &mut ScanResult,
         folders_scanned: &mut i32,
         app_handle: &AppHandle,
         scan_id: &str,
         last_progress_update: &mut Instant,
+        cancel_flag: &AtomicBool,
+        estimated_total: i32,
+        scan_cache: &mut ScanCache,
+        hash_files: bool,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        if depth > max_depth {
+        // A max_depth of 0 is unlimited; SKIP_PATTERNS still bound the walk
+        if max_depth > 0 && depth > max_depth {
             return Ok(());
         }
 
-        let entries = fs::read_dir(dir)?;
+        // An unreadable folder is reported and skipped rather than ending its parent's walk
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(e) => {
+                record_scan_error(result, app_handle, scan_id, dir, &e);
+                return Ok(());
+            }
+        };
 
         for entry in entries {
-            let entry = entry?;
+            // Unwind as soon as the scan is cancelled
+            if cancel_flag.load(Ordering::Relaxed) {
+                return Ok(());
+            }
+
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(e) => {
+                    record_scan_error(result, app_handle, scan_id, dir, &e);
+                    continue;
+                }
+            };
             let path = entry.path();
 
             if path.is_dir() {
@@ -494,7 +1262,8 @@ fn scan_directory_recursive(
                     let progress_event = ScanProgressEvent {
                         scan_id: scan_id.to_string(),
                         folders_scanned: *folders_scanned,
-                        total_folders: *folders_scanned,
+                        // The estimate can fall short (e.g. folders created mid-scan)
+                        total_folders: estimated_total.max(*folders_scanned),
                         current_path: path.to_string_lossy().to_string(),
                         projects_found: result.valid_projects,
                     };
@@ -503,10 +1272,27 @@ fn scan_directory_recursive(
                     *last_progress_update = Instant::now();
                 }
 
+                // Unchanged since the last incremental scan: reuse it as-is
+                if let Some(cached) = scan_cache.lookup(&path, size_mode, hash_files) {
+                    let mut project_folder = cached.project.clone();
+                    project_folder.last_scanned = get_current_timestamp();
+
+                    if project_folder.is_valid {
+                        result.valid_projects += 1;
+                    }
+                    result.total_folder_size += cached.folder_size;
+                    result.baker_scan_skipped += 1;
+                    emit_scan_discovery(app_handle, scan_id, &project_folder);
+                    result.projects.push(project_folder);
+                    continue;
+                }
+
                 // Check if this folder is a valid project
+                let validation_start = Instant::now();
                 let (is_valid, validation_errors, camera_count) = validate_project_folder(&path);
                 let has_breadcrumbs = has_breadcrumbs_file(&path);
                 let invalid_breadcrumbs = has_invalid_breadcrumbs_file(&path);
+                result.metrics.validation_ms += elapsed_ms(validation_start);
 
                 // Debug logging for each folder checked
                 println!("[Baker] Sub-folder: {} | Valid: {} | HasBreadcrumbs: {} | InvalidBreadcrumbs: {} | CameraCount: {}", 
@@ -518,11 +1304,22 @@ fn scan_directory_recursive(
                         result.valid_projects += 1;
                     }
 
+                    // Calculate and accumulate folder size
+                    let size_start = Instant::now();
+                    let folder_size =
+                        calculate_folder_size_with_mode(&path, size_mode).unwrap_or(0);
+                    result.total_folder_size += folder_size;
+                    result.metrics.size_calculation_ms += elapsed_ms(size_start);
+
+                    // Reuse the size for the stale check when it's exact
+                    let stale_start = Instant::now();
                     let stale_breadcrumbs = if has_breadcrumbs {
-                        check_breadcrumbs_stale(&path).unwrap_or(false)
+                        let known_size = (size_mode == SizeMode::Exact).then_some(folder_size);
+                        check_breadcrumbs_stale(&path, known_size, hash_files).unwrap_or(false)
                     } else {
                         false
                     };
+                    result.metrics.stale_detection_ms += elapsed_ms(stale_start);
 
                     let project_folder = ProjectFolder {
                         path: path.to_string_lossy().to_string(),
@@ -534,46 +1331,40 @@ fn scan_directory_recursive(
                         camera_count,
                         validation_errors: validation_errors.clone(),
                         invalid_breadcrumbs,
+                        folder_size,
                     };
 
-                    // Calculate and accumulate folder size
-                    let folder_size = calculate_folder_size(&path).unwrap_or(0);
-                    result.total_folder_size += folder_size;
-
+                    scan_cache.store(&path, &project_folder, folder_size, size_mode, hash_files);
+                    emit_scan_discovery(app_handle, scan_id, &project_folder);
                     result.projects.push(project_folder);
                 } else if !validation_errors.is_empty() {
                     // Only recurse if folder is not a partial project structure
                     let has_footage_or_graphics =
                         path.join("Footage").exists() || path.join("Graphics").exists();
 
-                    if !has_footage_or_graphics {
+                    // Without a depth limit a symlink cycle would never terminate
+                    let unbounded_symlink = max_depth == 0
+                        && entry.file_type().map(|t| t.is_symlink()).unwrap_or(false);
+
+                    if !has_footage_or_graphics && !unbounded_symlink {
                         visit_directory(
                             &path,
                             depth + 1,
                             max_depth,
                             include_hidden,
+                            size_mode,
                             result,
                             folders_scanned,
                             app_handle,
                             scan_id,
                             last_progress_update,
+                            cancel_flag,
+                            estimated_total,
+                            scan_cache,
+                            hash_files,
                         )?;
                     }
                 }
-
-                // Emit discovery event for valid projects or folders with breadcrumbs
-                if is_valid || has_breadcrumbs || invalid_breadcrumbs {
-                    let discovery_event = serde_json::json!({
-                        "scanId": scan_id,
-                        "projectPath": path.to_string_lossy(),
-                        "isValid": is_valid,
-                        "hasBreadcrumbs": has_breadcrumbs,
-                        "invalidBreadcrumbs": invalid_breadcrumbs,
-                        "errors": validation_errors
-                    });
-
-                    let _ = app_handle.emit("baker_scan_discovery", discovery_event);
-                }
             }
         }
 
@@ -581,9 +1372,11 @@ fn scan_directory_recursive(
     }
 
     // First check the root directory itself
+    let validation_start = Instant::now();
     let (is_valid, validation_errors, camera_count) = validate_project_folder(root_path);
     let has_breadcrumbs = has_breadcrumbs_file(root_path);
     let invalid_breadcrumbs = has_invalid_breadcrumbs_file(root_path);
+    result.metrics.validation_ms += elapsed_ms(validation_start);
 
     println!("[Baker] ===== ROOT FOLDER ANALYSIS =====");
     println!("[Baker] Path: {}", root_path.display());
@@ -601,11 +1394,20 @@ fn scan_directory_recursive(
             result.valid_projects += 1;
         }
 
-        let stale_breadcrumbs = if has_breadcrumbs {
-            check_breadcrumbs_stale(&root_path).unwrap_or(false)
+        // Calculate and accumulate folder size for root folder
+        let size_start = Instant::now();
+        let root_folder_size = calculate_folder_size_with_mode(root_path, size_mode).unwrap_or(0);
+        result.total_folder_size += root_folder_size;
+        result.metrics.size_calculation_ms += elapsed_ms(size_start);
+
+        let stale_start = Instant::now();
+        let stale_breadcrumbs = if has_breadcrumbs {
+            let known_size = (size_mode == SizeMode::Exact).then_some(root_folder_size);
+            check_breadcrumbs_stale(&root_path, known_size, options.hash_files).unwrap_or(false)
         } else {
             false
         };
+        result.metrics.stale_detection_ms += elapsed_ms(stale_start);
 
         let project_folder = ProjectFolder {
             path: root_path.to_string_lossy().to_string(),
@@ -621,38 +1423,55 @@ fn scan_directory_recursive(
             camera_count,
             validation_errors: validation_errors.clone(),
             invalid_breadcrumbs,
+            folder_size: root_folder_size,
         };
 
-        // Calculate and accumulate folder size for root folder
-        let root_folder_size = calculate_folder_size(&root_path).unwrap_or(0);
-        result.total_folder_size += root_folder_size;
-
+        emit_scan_discovery(app_handle, scan_id, &project_folder);
         result.projects.push(project_folder);
-
-        // Emit discovery event for root folder
-        let discovery_event = serde_json::json!({
-            "scanId": scan_id,
-            "projectPath": root_path.to_string_lossy(),
-            "isValid": is_valid,
-            "hasBreadcrumbs": has_breadcrumbs,
-            "invalidBreadcrumbs": invalid_breadcrumbs,
-            "errors": validation_errors
-        });
-        let _ = app_handle.emit("baker_scan_discovery", discovery_event);
     }
 
     // Then scan subdirectories
-    match visit_directory(
+    let visit_result = visit_directory(
         root_path,
         0,
         options.max_depth,
         options.include_hidden,
+        size_mode,
         &mut result,
         &mut folders_scanned,
         app_handle,
         scan_id,
         &mut last_progress_update,
-    ) {
+        cancel_flag,
+        estimated_total,
+        scan_cache,
+        options.hash_files,
+    );
+
+    scan_cache.save();
+    if result.baker_scan_skipped > 0 {
+        println!(
+            "[Baker] Reused {} unchanged projects from the scan cache",
+            result.baker_scan_skipped
+        );
+    }
+
+    result.cancelled = cancel_flag.load(Ordering::Relaxed);
+
+    let metrics = &mut result.metrics;
+    metrics.total_ms = elapsed_ms(scan_start);
+    metrics.enumeration_ms = (metrics.total_ms
+        - metrics.validation_ms
+        - metrics.size_calculation_ms
+        - metrics.stale_detection_ms)
+        .max(0.0);
+    metrics.folders_per_second = if metrics.total_ms > 0.0 {
+        folders_scanned as f64 / (metrics.total_ms / 1000.0)
+    } else {
+        0.0
+    };
+
+    match visit_result {
         Ok(_) => {
             result.end_time = Some(get_current_timestamp());
             Ok(result)
@@ -698,8 +1517,8 @@ pub async fn baker_start_scan(
         return Err(error_msg);
     }
 
-    if options.max_depth < 1 {
-        return Err("Max depth must be at least 1".to_string());
+    if options.max_depth < 0 {
+        return Err("Max depth cannot be negative (use 0 for unlimited)".to_string());
     }
 
     let scan_id = Uuid::new_v4().to_string();
@@ -710,8 +1529,28 @@ pub async fn baker_start_scan(
     let path_clone = path.to_path_buf();
     let options_clone = options.clone();
     let scans_ref = state.scans.clone();
+    let cancel_flags_ref = state.cancel_flags.clone();
+    let history_path = state.history_path.clone();
     let app_handle_clone = app_handle.clone();
 
+    let mut scan_cache = if options.incremental {
+        app_handle
+            .path()
+            .app_data_dir()
+            .map(|dir| {
+                let _ = fs::create_dir_all(&dir);
+                ScanCache::load(dir.join(SCAN_CACHE_FILE))
+            })
+            .unwrap_or_default()
+    } else {
+        ScanCache::default()
+    };
+
+    let cancel_flag = Arc::new(AtomicBool::new(false));
+    if let Ok(mut flags) = state.cancel_flags.lock() {
+        flags.insert(scan_id.clone(), cancel_flag.clone());
+    }
+
     tokio::spawn(async move {
         println!(
             "[Baker] Starting background scan task for ID: {}",
@@ -724,15 +1563,42 @@ pub async fn baker_start_scan(
             &options_clone,
             &app_handle_clone,
             &scan_id_clone,
+            &cancel_flag,
+            &mut scan_cache,
         ) {
+            Ok(result) if result.cancelled => {
+                println!(
+                    "[Baker] Scan cancelled after {:.2}s: {} projects found, {} folders scanned",
+                    scan_start.elapsed().as_secs_f32(),
+                    result.valid_projects,
+                    result.total_folders
+                );
+
+                if let Ok(mut scans) = scans_ref.lock() {
+                    scans.insert(scan_id_clone.clone(), result.clone());
+                }
+
+                let cancelled_event = serde_json::json!({
+                    "scanId": scan_id_clone,
+                    "result": result
+                });
+
+                let _ = app_handle_clone.emit("baker_scan_cancelled", cancelled_event);
+            }
             Ok(result) => {
                 let scan_duration = scan_start.elapsed();
                 println!("[Baker] Scan completed successfully in {:.2}s: {} projects found, {} folders scanned", 
                     scan_duration.as_secs_f32(), result.valid_projects, result.total_folders);
 
-                // Store result
+                // Store result, persisting it so it survives a restart
                 if let Ok(mut scans) = scans_ref.lock() {
                     scans.insert(scan_id_clone.clone(), result.clone());
+
+                    if let Some(history_path) = &history_path {
+                        if let Err(e) = persist_scan_history(&mut scans, history_path) {
+                            println!("[Baker] Failed to save scan history: {}", e);
+                        }
+                    }
                 }
 
                 // Emit completion event
@@ -753,6 +1619,7 @@ pub async fn baker_start_scan(
 
                 let error_event = serde_json::json!({
                     "scanId": scan_id_clone,
+                    "fatal": true,
                     "error": {
                         "path": path_clone.to_string_lossy(),
                         "type": "filesystem",
@@ -764,6 +1631,10 @@ pub async fn baker_start_scan(
                 let _ = app_handle_clone.emit("baker_scan_error", error_event);
             }
         }
+
+        if let Ok(mut flags) = cancel_flags_ref.lock() {
+            flags.remove(&scan_id_clone);
+        }
     });
 
     Ok(scan_id)
@@ -782,25 +1653,268 @@ pub async fn baker_get_scan_status(
         .ok_or_else(|| "Scan ID not found".to_string())
 }
 
+/// Phase timing breakdown for a completed scan
 #[tauri::command]
-pub async fn baker_cancel_scan(scan_id: String, state: State<'_, ScanState>) -> Result<(), String> {
-    // In a real implementation, we would need a way to signal the scan task to stop
-    // For now, just mark the scan as completed
-    let mut scans = state.scans.lock().map_err(|_| "Failed to acquire lock")?;
+pub async fn baker_get_scan_metrics(
+    scan_id: String,
+    state: State<'_, ScanState>,
+) -> Result<ScanMetrics, String> {
+    let scans = state.scans.lock().map_err(|_| "Failed to acquire lock")?;
 
-    if let Some(result) = scans.get_mut(&scan_id) {
-        if result.end_time.is_none() {
-            result.end_time = Some(get_current_timestamp());
-        }
+    scans
+        .get(&scan_id)
+        .map(|result| result.metrics.clone())
+        .ok_or_else(|| "Scan ID not found".to_string())
+}
+
+/// Totals across every project found by a scan
+#[tauri::command]
+pub async fn baker_get_scan_summary(
+    scan_id: String,
+    state: State<'_, ScanState>,
+) -> Result<ScanSummary, String> {
+    let scans = state.scans.lock().map_err(|_| "Failed to acquire lock")?;
+    let result = scans.get(&scan_id).ok_or("Scan ID not found")?;
+    let projects = &result.projects;
+
+    let total_folder_size: u64 = projects.iter().map(|project| project.folder_size).sum();
+    let average_folder_size = if projects.is_empty() {
+        0
     } else {
+        total_folder_size / projects.len() as u64
+    };
+
+    let largest_project = projects
+        .iter()
+        .max_by_key(|project| project.folder_size)
+        .map(|project| LargestProject {
+            path: project.path.clone(),
+            name: project.name.clone(),
+            folder_size: project.folder_size,
+        });
+
+    Ok(ScanSummary {
+        total_projects: projects.len(),
+        valid_projects: projects.iter().filter(|project| project.is_valid).count(),
+        invalid_projects: projects.iter().filter(|project| !project.is_valid).count(),
+        stale_breadcrumbs: projects
+            .iter()
+            .filter(|project| project.stale_breadcrumbs)
+            .count(),
+        missing_breadcrumbs: projects
+            .iter()
+            .filter(|project| !project.has_breadcrumbs)
+            .count(),
+        total_cameras: projects
+            .iter()
+            .map(|project| project.camera_count as i64)
+            .sum(),
+        total_folder_size,
+        average_folder_size,
+        largest_project,
+    })
+}
+
+/// Write a completed scan to disk as "json" (the full ScanResult) or "csv" (one row per project)
+#[tauri::command]
+pub async fn baker_export_scan_results(
+    scan_id: String,
+    output_path: String,
+    format: String,
+    state: State<'_, ScanState>,
+) -> Result<String, String> {
+    let result = {
+        let scans = state.scans.lock().map_err(|_| "Failed to acquire lock")?;
+        scans.get(&scan_id).cloned().ok_or("Scan ID not found")?
+    };
+
+    match format.to_lowercase().as_str() {
+        "json" => {
+            let json = serde_json::to_string_pretty(&result)
+                .map_err(|e| format!("Failed to serialize scan results: {}", e))?;
+            fs::write(&output_path, json)
+                .map_err(|e| format!("Failed to write scan report: {}", e))?;
+        }
+        "csv" => {
+            let mut writer = csv::Writer::from_path(&output_path)
+                .map_err(|e| format!("Failed to create scan report: {}", e))?;
+
+            writer
+                .write_record([
+                    "path",
+                    "name",
+                    "is_valid",
+                    "has_breadcrumbs",
+                    "stale_breadcrumbs",
+                    "camera_count",
+                    "folder_size_bytes",
+                ])
+                .map_err(|e| format!("Failed to write scan report: {}", e))?;
+
+            for project in &result.projects {
+                writer
+                    .write_record([
+                        project.path.clone(),
+                        project.name.clone(),
+                        project.is_valid.to_string(),
+                        project.has_breadcrumbs.to_string(),
+                        project.stale_breadcrumbs.to_string(),
+                        project.camera_count.to_string(),
+                        project.folder_size.to_string(),
+                    ])
+                    .map_err(|e| format!("Failed to write scan report: {}", e))?;
+            }
+
+            writer
+                .flush()
+                .map_err(|e| format!("Failed to write scan report: {}", e))?;
+        }
+        other => {
+            return Err(format!(
+                "Unsupported export format '{}' (expected json or csv)",
+                other
+            ))
+        }
+    }
+
+    println!("[Baker] Exported scan {} to {}", scan_id, output_path);
+    Ok(output_path)
+}
+
+#[tauri::command]
+pub async fn baker_cancel_scan(scan_id: String, state: State<'_, ScanState>) -> Result<(), String> {
+    // Signal a running scan; the task stores its partial result once it unwinds
+    {
+        let flags = state
+            .cancel_flags
+            .lock()
+            .map_err(|_| "Failed to acquire lock")?;
+
+        if let Some(flag) = flags.get(&scan_id) {
+            flag.store(true, Ordering::Relaxed);
+            println!("[Baker] Cancellation requested for scan: {}", scan_id);
+            return Ok(());
+        }
+    }
+
+    // Already finished scans have nothing left to stop
+    let scans = state.scans.lock().map_err(|_| "Failed to acquire lock")?;
+    if !scans.contains_key(&scan_id) {
         return Err("Scan ID not found".to_string());
     }
 
     Ok(())
 }
 
+/// Helper: Fingerprint a project's footage from file paths, sizes and leading bytes
+///
+/// Only the first FOOTAGE_HASH_SAMPLE_BYTES of each clip are read so hashing a
+/// multi-terabyte archive stays fast. Returns None when there is no footage.
+fn compute_footage_hash(project_path: &Path, files: &[FileInfo]) -> Result<Option<String>, String> {
+    if files.is_empty() {
+        return Ok(None);
+    }
+
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0u8; FOOTAGE_HASH_SAMPLE_BYTES];
+
+    for file in files {
+        let file_path = project_path.join(&file.path);
+        let mut handle = fs::File::open(&file_path)
+            .map_err(|e| format!("Failed to open {}: {}", file_path.display(), e))?;
+        let size = handle
+            .metadata()
+            .map_err(|e| format!("Failed to read metadata for {}: {}", file_path.display(), e))?
+            .len();
+
+        let mut read = 0;
+        while read < buffer.len() {
+            let n = handle
+                .read(&mut buffer[read..])
+                .map_err(|e| format!("Failed to read {}: {}", file_path.display(), e))?;
+            if n == 0 {
+                break;
+            }
+            read += n;
+        }
+
+        hasher.update(file.path.as_bytes());
+        hasher.update(size.to_le_bytes());
+        hasher.update(&buffer[..read]);
+    }
+
+    Ok(Some(format!("{:x}", hasher.finalize())))
+}
+
+/// Group projects from a finished scan whose footage content matches
+///
+/// Each returned cluster holds two or more project paths; projects without
+/// footage are never reported as duplicates.
+#[tauri::command]
+pub async fn baker_find_duplicate_projects(
+    scan_id: String,
+    state: State<'_, ScanState>,
+) -> Result<Vec<Vec<String>>, String> {
+    let project_paths: Vec<String> = {
+        let scans = state.scans.lock().map_err(|_| "Failed to acquire lock")?;
+        let scan = scans.get(&scan_id).ok_or("Scan ID not found")?;
+        scan.projects
+            .iter()
+            .filter(|project| project.is_valid)
+            .map(|project| project.path.clone())
+            .collect()
+    };
+
+    let mut groups: HashMap<String, Vec<String>> = HashMap::new();
+
+    for project_path in project_paths {
+        let files = match baker_scan_current_files(project_path.clone()).await {
+            Ok(files) => files,
+            Err(e) => {
+                println!(
+                    "[Baker] Skipping {} in duplicate check: {}",
+                    project_path, e
+                );
+                continue;
+            }
+        };
+
+        let hash_path = project_path.clone();
+        let hash = tokio::task::spawn_blocking(move || {
+            compute_footage_hash(Path::new(&hash_path), &files)
+        })
+        .await
+        .map_err(|e| format!("Hash task failed: {}", e))?;
+
+        match hash {
+            Ok(Some(hash)) => groups.entry(hash).or_default().push(project_path),
+            Ok(None) => {}
+            Err(e) => println!(
+                "[Baker] Skipping {} in duplicate check: {}",
+                project_path, e
+            ),
+        }
+    }
+
+    let mut clusters: Vec<Vec<String>> = groups
+        .into_values()
+        .filter(|paths| paths.len() > 1)
+        .map(|mut paths| {
+            paths.sort();
+            paths
+        })
+        .collect();
+    clusters.sort();
+
+    Ok(clusters)
+}
+
+/// Validate a single project folder; `hash_files` opts in to hashing footage for the stale check
 #[tauri::command]
-pub async fn baker_validate_folder(folder_path: String) -> Result<ProjectFolder, String> {
+pub async fn baker_validate_folder(
+    folder_path: String,
+    hash_files: Option<bool>,
+) -> Result<ProjectFolder, String> {
     let path = Path::new(&folder_path);
 
     if !path.exists() {
@@ -810,8 +1924,10 @@ pub async fn baker_validate_folder(folder_path: String) -> Result<ProjectFolder,
     let (is_valid, validation_errors, camera_count) = validate_project_folder(path);
     let has_breadcrumbs = has_breadcrumbs_file(path);
     let invalid_breadcrumbs = has_invalid_breadcrumbs_file(path);
+    let folder_size = calculate_folder_size_parallel(path).unwrap_or(0);
     let stale_breadcrumbs = if has_breadcrumbs {
-        check_breadcrumbs_stale(path).unwrap_or(false)
+        check_breadcrumbs_stale(path, Some(folder_size), hash_files.unwrap_or(false))
+            .unwrap_or(false)
     } else {
         false
     };
@@ -830,6 +1946,7 @@ pub async fn baker_validate_folder(folder_path: String) -> Result<ProjectFolder,
         camera_count,
         validation_errors,
         invalid_breadcrumbs,
+        folder_size,
     })
 }
 
@@ -863,11 +1980,52 @@ pub async fn baker_update_breadcrumbs(
     project_paths: Vec<String>,
     create_missing: bool,
     backup_originals: bool,
+    hash_files: Option<bool>,
 ) -> Result<BatchUpdateResult, String> {
     if project_paths.is_empty() {
         return Err("Project paths cannot be empty".to_string());
     }
 
+    Ok(update_breadcrumbs_batch(
+        project_paths,
+        create_missing,
+        backup_originals,
+        hash_files.unwrap_or(false),
+    ))
+}
+
+/// Retry the paths that failed in a previous baker_update_breadcrumbs call
+///
+/// Behaves like the update command. A breadcrumbs.json.bak left by the failed
+/// attempt is refreshed from the current file, so it never sits stale next to
+/// the newer write.
+#[tauri::command]
+pub async fn baker_retry_failed_updates(
+    failed_paths: Vec<String>,
+    create_missing: bool,
+    backup_originals: bool,
+    hash_files: Option<bool>,
+) -> Result<BatchUpdateResult, String> {
+    if failed_paths.is_empty() {
+        return Err("No failed paths to retry".to_string());
+    }
+
+    println!("[Baker] Retrying {} failed update(s)", failed_paths.len());
+
+    Ok(update_breadcrumbs_batch(
+        failed_paths,
+        create_missing,
+        backup_originals,
+        hash_files.unwrap_or(false),
+    ))
+}
+
+fn update_breadcrumbs_batch(
+    project_paths: Vec<String>,
+    create_missing: bool,
+    backup_originals: bool,
+    hash_files: bool,
+) -> BatchUpdateResult {
     let mut result = BatchUpdateResult {
         successful: Vec::new(),
         failed: Vec::new(),
@@ -918,61 +2076,24 @@ pub async fn baker_update_breadcrumbs(
         }
 
         // Scan for files in camera folders
-        let mut files = Vec::new();
-        let footage_path = path.join("Footage");
+        let mut files = collect_camera_files(path);
 
-        if let Ok(entries) = fs::read_dir(&footage_path) {
-            for entry in entries {
-                if let Ok(entry) = entry {
-                    let folder_name = entry.file_name();
-                    let name_str = folder_name.to_string_lossy();
-
-                    if name_str.starts_with("Camera ") && entry.path().is_dir() {
-                        if let Some(camera_num_str) = name_str.strip_prefix("Camera ") {
-                            if let Ok(camera_num) = camera_num_str.parse::<i32>() {
-                                if let Ok(camera_files) = fs::read_dir(entry.path()) {
-                                    for file in camera_files {
-                                        if let Ok(file) = file {
-                                            let file_name =
-                                                file.file_name().to_string_lossy().to_string();
-
-                                            // Skip hidden files (starting with .) like .DS_Store
-                                            if file_name.starts_with('.') {
-                                                continue;
-                                            }
-
-                                            if file.path().is_file() {
-                                                files.push(FileInfo {
-                                                    camera: camera_num,
-                                                    name: file_name.clone(),
-                                                    path: format!(
-                                                        "Footage/{}/{}",
-                                                        name_str, file_name
-                                                    ),
-                                                });
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
+        if hash_files {
+            for file in files.iter_mut() {
+                match hash_file_sha256(&path.join(&file.path)) {
+                    Ok(hash) => file.sha256 = Some(hash),
+                    Err(e) => println!("[Baker] Failed to hash {}: {}", file.path, e),
                 }
             }
         }
 
-        let breadcrumbs = if exists {
+        let mut breadcrumbs = if exists {
             // Update existing
             match fs::read_to_string(&breadcrumbs_path) {
                 Ok(content) => {
                     match serde_json::from_str::<BreadcrumbsFile>(&content) {
                         Ok(mut existing) => {
                             existing.files = files;
-                            // Preserve original creator and add Baker update suffix
-                            if !existing.created_by.ends_with(" - updated by Baker") {
-                                existing.created_by =
-                                    format!("{} - updated by Baker", existing.created_by);
-                            }
                             existing.last_modified = Some(get_current_timestamp());
                             existing.scanned_by = Some("Baker".to_string());
                             // Recalculate folder size to ensure it's up to date
@@ -1002,6 +2123,8 @@ pub async fn baker_update_breadcrumbs(
                                 trello_card_url: None,
                                 video_links: None,
                                 trello_cards: None,
+                                modification_history: None,
+                                archived_to: None,
                             }
                         }
                     }
@@ -1033,36 +2156,36 @@ pub async fn baker_update_breadcrumbs(
                 trello_card_url: None,
                 video_links: None,
                 trello_cards: None,
+                modification_history: None,
+                archived_to: None,
             }
         };
 
-        // Write breadcrumbs file
-        match serde_json::to_string_pretty(&breadcrumbs) {
-            Ok(json_content) => {
-                if let Err(e) = fs::write(&breadcrumbs_path, json_content) {
-                    result.failed.push(FailedUpdate {
-                        path: project_path.clone(),
-                        error: format!("Failed to write breadcrumbs file: {}", e),
-                    });
+        record_modification(
+            &mut breadcrumbs,
+            if exists { "rescanned" } else { "created" },
+        );
+
+        // Write breadcrumbs file atomically so an interrupted batch can't truncate it
+        match write_breadcrumbs_json(&project_path, &breadcrumbs) {
+            Ok(()) => {
+                result.successful.push(project_path.clone());
+                if exists {
+                    result.updated.push(project_path);
                 } else {
-                    result.successful.push(project_path.clone());
-                    if exists {
-                        result.updated.push(project_path);
-                    } else {
-                        result.created.push(project_path);
-                    }
+                    result.created.push(project_path);
                 }
             }
             Err(e) => {
                 result.failed.push(FailedUpdate {
                     path: project_path.clone(),
-                    error: format!("Failed to serialize breadcrumbs: {}", e),
+                    error: e,
                 });
             }
         }
     }
 
-    Ok(result)
+    result
 }
 
 #[tauri::command]
@@ -1077,51 +2200,74 @@ pub async fn baker_scan_current_files(project_path: String) -> Result<Vec<FileIn
         return Err("Project path is not a directory".to_string());
     }
 
-    // Scan for files in camera folders (same logic as baker_update_breadcrumbs)
-    let mut files = Vec::new();
-    let footage_path = path.join("Footage");
+    let mut files = collect_camera_files(path);
 
-    if let Ok(entries) = fs::read_dir(&footage_path) {
-        for entry in entries {
-            if let Ok(entry) = entry {
-                let folder_name = entry.file_name();
-                let name_str = folder_name.to_string_lossy();
-
-                if name_str.starts_with("Camera ") && entry.path().is_dir() {
-                    if let Some(camera_num_str) = name_str.strip_prefix("Camera ") {
-                        if let Ok(camera_num) = camera_num_str.parse::<i32>() {
-                            if let Ok(camera_files) = fs::read_dir(entry.path()) {
-                                for file in camera_files {
-                                    if let Ok(file) = file {
-                                        let file_name =
-                                            file.file_name().to_string_lossy().to_string();
-
-                                        // Skip hidden files (starting with .) like .DS_Store
-                                        if file_name.starts_with('.') {
-                                            continue;
-                                        }
-
-                                        if file.path().is_file() {
-                                            files.push(FileInfo {
-                                                camera: camera_num,
-                                                name: file_name.clone(),
-                                                path: format!("Footage/{}/{}", name_str, file_name),
-                                            });
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
+    // Sort files by camera number and then by name
+    files.sort_by(|a, b| a.camera.cmp(&b.camera).then_with(|| a.name.cmp(&b.name)));
+
+    Ok(files)
+}
+
+/// Preflight check for footage files that can't be written, moved or renamed
+///
+/// Each file is opened for writing without truncating, which fails for read-only
+/// files and, on Windows, for files another process (e.g. an NLE) holds open.
+#[tauri::command]
+pub async fn baker_check_file_locks(project_path: String) -> Result<Vec<LockedFile>, String> {
+    let files = baker_scan_current_files(project_path.clone()).await?;
+    let project_root = Path::new(&project_path);
+
+    let mut locked = Vec::new();
+    for file in files {
+        let full_path = project_root.join(&file.path);
+
+        let reason = match fs::metadata(&full_path) {
+            Ok(metadata) if metadata.permissions().readonly() => Some("Read-only".to_string()),
+            Ok(_) => match fs::OpenOptions::new().write(true).open(&full_path) {
+                Ok(_) => None,
+                // ERROR_SHARING_VIOLATION / ERROR_LOCK_VIOLATION on Windows
+                Err(e) if matches!(e.raw_os_error(), Some(32) | Some(33)) && cfg!(windows) => {
+                    Some("Locked by another process".to_string())
                 }
-            }
+                Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => {
+                    Some("Permission denied".to_string())
+                }
+                Err(e) => Some(format!("Cannot open for writing: {}", e)),
+            },
+            Err(e) => Some(format!("Cannot read metadata: {}", e)),
+        };
+
+        if let Some(reason) = reason {
+            locked.push(LockedFile {
+                path: file.path,
+                reason,
+            });
         }
     }
 
-    // Sort files by camera number and then by name
-    files.sort_by(|a, b| a.camera.cmp(&b.camera).then_with(|| a.name.cmp(&b.name)));
+    Ok(locked)
+}
 
-    Ok(files)
+/// List files referenced in breadcrumbs that no longer exist under the project
+#[tauri::command]
+pub async fn baker_verify_footage_paths(project_path: String) -> Result<Vec<MissingFile>, String> {
+    let breadcrumbs = baker_read_breadcrumbs(project_path.clone())
+        .await?
+        .ok_or("No breadcrumbs file found")?;
+    let project_root = Path::new(&project_path);
+
+    let missing = breadcrumbs
+        .files
+        .into_iter()
+        .filter(|file| !project_root.join(&file.path).is_file())
+        .map(|file| MissingFile {
+            camera: file.camera,
+            name: file.name,
+            path: file.path,
+        })
+        .collect();
+
+    Ok(missing)
 }
 
 #[tauri::command]
@@ -1159,6 +2305,80 @@ pub async fn baker_read_raw_breadcrumbs(project_path: String) -> Result<Option<S
     }
 }
 
+/// Delete a project's breadcrumbs.json, copying it to breadcrumbs.json.bak first if requested
+///
+/// Only a regular file named breadcrumbs.json directly inside the project is
+/// removed; directories and symlinks with that name are refused.
+#[tauri::command]
+pub async fn baker_delete_breadcrumbs(project_path: String, backup: bool) -> Result<(), String> {
+    let path = Path::new(&project_path);
+
+    if !path.is_dir() {
+        return Err("Project path does not exist".to_string());
+    }
+
+    let breadcrumbs_path = path.join("breadcrumbs.json");
+
+    let metadata = fs::symlink_metadata(&breadcrumbs_path)
+        .map_err(|_| "No breadcrumbs file found".to_string())?;
+    if !metadata.file_type().is_file() {
+        return Err("breadcrumbs.json is not a regular file".to_string());
+    }
+
+    if backup {
+        fs::copy(&breadcrumbs_path, path.join("breadcrumbs.json.bak"))
+            .map_err(|e| format!("Failed to create backup: {}", e))?;
+    }
+
+    fs::remove_file(&breadcrumbs_path)
+        .map_err(|e| format!("Failed to delete breadcrumbs file: {}", e))?;
+
+    println!("[Baker] Deleted breadcrumbs for {}", project_path);
+    Ok(())
+}
+
+/// Read a project's modification history, oldest first
+///
+/// Breadcrumbs written before the history was introduced return an empty list.
+#[tauri::command]
+pub async fn baker_get_audit_trail(project_path: String) -> Result<Vec<AuditEntry>, String> {
+    let breadcrumbs = baker_read_breadcrumbs(project_path)
+        .await?
+        .ok_or("No breadcrumbs file found")?;
+
+    Ok(breadcrumbs.modification_history.unwrap_or_default())
+}
+
+/// Write breadcrumbs only if nobody else has written them since they were read
+///
+/// `expected_last_modified` is the lastModified value the caller read (None if
+/// the file did not exist). The file is re-read just before writing and a
+/// "Conflict:" error is returned if it has changed in the meantime.
+#[tauri::command]
+pub async fn baker_write_breadcrumbs_if_unchanged(
+    project_path: String,
+    breadcrumbs: BreadcrumbsFile,
+    expected_last_modified: Option<String>,
+) -> Result<BreadcrumbsFile, String> {
+    let current_last_modified = baker_read_breadcrumbs(project_path.clone())
+        .await?
+        .and_then(|current| current.last_modified);
+
+    if current_last_modified != expected_last_modified {
+        return Err(format!(
+            "Conflict: breadcrumbs were modified externally (expected lastModified {:?}, found {:?})",
+            expected_last_modified, current_last_modified
+        ));
+    }
+
+    let mut breadcrumbs = breadcrumbs;
+    breadcrumbs.last_modified = Some(get_current_timestamp());
+
+    write_breadcrumbs_file(&project_path, &mut breadcrumbs, "edited")?;
+
+    Ok(breadcrumbs)
+}
+
 // ============================================================================
 // Feature 004: Multiple Video Links and Trello Cards
 // ============================================================================
@@ -1169,6 +2389,56 @@ fn extract_trello_card_id(url: &str) -> Option<String> {
     re.captures(url)?.get(1).map(|m| m.as_str().to_string())
 }
 
+/// Helper: Extract Sprout Video ID from a video page or embed URL
+pub(crate) fn extract_sprout_video_id(url: &str) -> Option<String> {
+    let re = regex::Regex::new(r"sproutvideo\.com/(?:videos|embed)/([a-zA-Z0-9]{6,})").ok()?;
+    re.captures(url)?.get(1).map(|m| m.as_str().to_string())
+}
+
+/// Helper: Check a video link's URL and fill in its Sprout Video ID when missing
+fn validate_video_link(video_link: &mut VideoLink) -> Result<(), String> {
+    let url = video_link.url.trim().to_string();
+
+    if url.is_empty() {
+        return Err("Video URL cannot be empty".to_string());
+    }
+
+    let parsed = reqwest::Url::parse(&url).map_err(|e| format!("Invalid video URL: {}", e))?;
+    if parsed.scheme() != "http" && parsed.scheme() != "https" {
+        return Err(format!(
+            "Video URL must start with http:// or https:// (got {}://)",
+            parsed.scheme()
+        ));
+    }
+    if parsed.host_str().is_none() {
+        return Err("Video URL is missing a host".to_string());
+    }
+
+    let is_sprout = parsed
+        .host_str()
+        .is_some_and(|host| host == "sproutvideo.com" || host.ends_with(".sproutvideo.com"));
+    if is_sprout && video_link.sprout_video_id.is_none() {
+        video_link.sprout_video_id =
+            Some(extract_sprout_video_id(&url).ok_or(
+                "Could not find a video ID in the Sprout Video URL (expected /videos/<id>)",
+            )?);
+    }
+
+    video_link.url = url;
+    Ok(())
+}
+
+/// Helper: Whether two video URLs point at the same video
+///
+/// URLs are compared parsed, which lowercases the host, so
+/// "https://SproutVideo.com/videos/x" matches "https://sproutvideo.com/videos/x".
+fn is_same_video_url(a: &str, b: &str) -> bool {
+    match (reqwest::Url::parse(a.trim()), reqwest::Url::parse(b.trim())) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a.trim() == b.trim(),
+    }
+}
+
 /// Helper: Migrate legacy trelloCardUrl to trelloCards array
 fn migrate_trello_card_url(breadcrumbs: &BreadcrumbsFile) -> Vec<TrelloCard> {
     // If already has new format, return it
@@ -1187,6 +2457,8 @@ fn migrate_trello_card_url(breadcrumbs: &BreadcrumbsFile) -> Vec<TrelloCard> {
                 title: format!("Card {}", card_id), // Default title
                 board_name: None,
                 last_fetched: None,
+                due: None,
+                labels: Vec::new(),
             }];
         }
     }
@@ -1207,49 +2479,240 @@ fn ensure_backward_compatible_write(breadcrumbs: &mut BreadcrumbsFile) {
     }
 }
 
-/// Helper: Write breadcrumbs file to disk
-fn write_breadcrumbs_file(project_path: &str, breadcrumbs: &BreadcrumbsFile) -> Result<(), String> {
+/// Helper: Append an audit entry for a write by the current user
+fn record_modification(breadcrumbs: &mut BreadcrumbsFile, action: &str) {
+    breadcrumbs
+        .modification_history
+        .get_or_insert_with(Vec::new)
+        .push(AuditEntry {
+            by: get_username().name,
+            at: get_current_timestamp(),
+            action: action.to_string(),
+        });
+}
+
+/// Helper: Record an audit entry and write breadcrumbs file to disk
+pub(crate) fn write_breadcrumbs_file(
+    project_path: &str,
+    breadcrumbs: &mut BreadcrumbsFile,
+    action: &str,
+) -> Result<(), String> {
+    record_modification(breadcrumbs, action);
+    write_breadcrumbs_json(project_path, breadcrumbs)
+}
+
+/// Helper: Serialize breadcrumbs to disk as-is
+/// Writes to a temporary sibling first and renames it into place, so an
+/// interrupted write never leaves a truncated breadcrumbs.json behind
+fn write_breadcrumbs_json(project_path: &str, breadcrumbs: &BreadcrumbsFile) -> Result<(), String> {
     let path = Path::new(project_path);
     let breadcrumbs_path = path.join("breadcrumbs.json");
+    let temp_path = path.join("breadcrumbs.json.tmp");
 
     let json = serde_json::to_string_pretty(breadcrumbs)
         .map_err(|e| format!("Failed to serialize breadcrumbs: {}", e))?;
 
-    fs::write(&breadcrumbs_path, json)
-        .map_err(|e| format!("Failed to write breadcrumbs file: {}", e))?;
+    fs::write(&temp_path, json).map_err(|e| format!("Failed to write breadcrumbs file: {}", e))?;
 
-    Ok(())
-}
-
-#[tauri::command]
-pub async fn baker_get_video_links(project_path: String) -> Result<Vec<VideoLink>, String> {
-    let breadcrumbs = baker_read_breadcrumbs(project_path).await?;
+    fs::rename(&temp_path, &breadcrumbs_path).map_err(|e| {
+        let _ = fs::remove_file(&temp_path);
+        format!("Failed to replace breadcrumbs file: {}", e)
+    })?;
 
-    match breadcrumbs {
-        Some(b) => Ok(b.video_links.unwrap_or_default()),
-        None => Ok(Vec::new()),
-    }
+    Ok(())
 }
 
+/// Rewrite a project's breadcrumbs.json in canonical form
+///
+/// Migrates the legacy trelloCardUrl into trelloCards, recomputes the folder size,
+/// sorts files by camera then name and drops empty link arrays. last_modified and
+/// modificationHistory are left untouched so running this repeatedly produces
+/// identical output.
 #[tauri::command]
-pub async fn baker_associate_video_link(
-    project_path: String,
-    video_link: VideoLink,
-) -> Result<BreadcrumbsFile, String> {
+pub async fn baker_normalize_breadcrumbs(project_path: String) -> Result<BreadcrumbsFile, String> {
     let mut breadcrumbs = baker_read_breadcrumbs(project_path.clone())
         .await?
         .ok_or("No breadcrumbs file found")?;
 
-    // Initialize video_links if None
-    if breadcrumbs.video_links.is_none() {
-        breadcrumbs.video_links = Some(Vec::new());
-    }
+    let path = Path::new(&project_path);
 
-    let videos = breadcrumbs.video_links.as_mut().unwrap();
+    // Migrate legacy trelloCardUrl into the trelloCards array
+    let cards = migrate_trello_card_url(&breadcrumbs);
+    breadcrumbs.trello_cards = if cards.is_empty() { None } else { Some(cards) };
+    ensure_backward_compatible_write(&mut breadcrumbs);
 
-    // Validate max 20 videos
-    if videos.len() >= 20 {
-        return Err("Maximum of 20 videos per project reached".to_string());
+    if breadcrumbs
+        .video_links
+        .as_ref()
+        .map_or(false, |links| links.is_empty())
+    {
+        breadcrumbs.video_links = None;
+    }
+
+    // Recalculate folder size so it reflects what is on disk now
+    breadcrumbs.folder_size_bytes = calculate_folder_size(path).ok();
+
+    breadcrumbs
+        .files
+        .sort_by(|a, b| a.camera.cmp(&b.camera).then_with(|| a.name.cmp(&b.name)));
+
+    write_breadcrumbs_json(&project_path, &breadcrumbs)?;
+
+    Ok(breadcrumbs)
+}
+
+/// Helper: Escape text for inclusion in XML element content or attributes
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Helper: Render breadcrumbs as a minimal XMP packet
+/// Title, creator and dates use the standard dc/xmp namespaces; video links
+/// and Trello cards go in a bucket namespace as ordered rdf:Seq resources
+fn breadcrumbs_to_xmp(breadcrumbs: &BreadcrumbsFile) -> String {
+    let mut xmp = String::new();
+
+    xmp.push_str("<?xpacket begin=\"\u{feff}\" id=\"W5M0MpCehiHzreSzNTczkc9d\"?>\n");
+    xmp.push_str("<x:xmpmeta xmlns:x=\"adobe:ns:meta/\">\n");
+    xmp.push_str(" <rdf:RDF xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\">\n");
+    xmp.push_str("  <rdf:Description rdf:about=\"\"\n");
+    xmp.push_str("    xmlns:dc=\"http://purl.org/dc/elements/1.1/\"\n");
+    xmp.push_str("    xmlns:xmp=\"http://ns.adobe.com/xap/1.0/\"\n");
+    xmp.push_str("    xmlns:bucket=\"https://github.com/twentynineteen/bucket/ns/1.0/\">\n");
+
+    xmp.push_str(&format!(
+        "   <dc:title><rdf:Alt><rdf:li xml:lang=\"x-default\">{}</rdf:li></rdf:Alt></dc:title>\n",
+        escape_xml(&breadcrumbs.project_title)
+    ));
+    xmp.push_str(&format!(
+        "   <dc:creator><rdf:Seq><rdf:li>{}</rdf:li></rdf:Seq></dc:creator>\n",
+        escape_xml(&breadcrumbs.created_by)
+    ));
+    xmp.push_str(&format!(
+        "   <xmp:CreateDate>{}</xmp:CreateDate>\n",
+        escape_xml(&breadcrumbs.creation_date_time)
+    ));
+    if let Some(last_modified) = &breadcrumbs.last_modified {
+        xmp.push_str(&format!(
+            "   <xmp:ModifyDate>{}</xmp:ModifyDate>\n",
+            escape_xml(last_modified)
+        ));
+    }
+
+    let video_links = breadcrumbs.video_links.clone().unwrap_or_default();
+    if !video_links.is_empty() {
+        xmp.push_str("   <bucket:videoLinks>\n    <rdf:Seq>\n");
+        for link in &video_links {
+            xmp.push_str("     <rdf:li rdf:parseType=\"Resource\">\n");
+            xmp.push_str(&format!(
+                "      <bucket:url>{}</bucket:url>\n",
+                escape_xml(&link.url)
+            ));
+            xmp.push_str(&format!(
+                "      <bucket:title>{}</bucket:title>\n",
+                escape_xml(&link.title)
+            ));
+            if let Some(video_id) = &link.sprout_video_id {
+                xmp.push_str(&format!(
+                    "      <bucket:sproutVideoId>{}</bucket:sproutVideoId>\n",
+                    escape_xml(video_id)
+                ));
+            }
+            xmp.push_str("     </rdf:li>\n");
+        }
+        xmp.push_str("    </rdf:Seq>\n   </bucket:videoLinks>\n");
+    }
+
+    let trello_cards = migrate_trello_card_url(breadcrumbs);
+    if !trello_cards.is_empty() {
+        xmp.push_str("   <bucket:trelloCards>\n    <rdf:Seq>\n");
+        for card in &trello_cards {
+            xmp.push_str("     <rdf:li rdf:parseType=\"Resource\">\n");
+            xmp.push_str(&format!(
+                "      <bucket:url>{}</bucket:url>\n",
+                escape_xml(&card.url)
+            ));
+            xmp.push_str(&format!(
+                "      <bucket:cardId>{}</bucket:cardId>\n",
+                escape_xml(&card.card_id)
+            ));
+            xmp.push_str(&format!(
+                "      <bucket:title>{}</bucket:title>\n",
+                escape_xml(&card.title)
+            ));
+            xmp.push_str("     </rdf:li>\n");
+        }
+        xmp.push_str("    </rdf:Seq>\n   </bucket:trelloCards>\n");
+    }
+
+    xmp.push_str("  </rdf:Description>\n");
+    xmp.push_str(" </rdf:RDF>\n");
+    xmp.push_str("</x:xmpmeta>\n");
+    xmp.push_str("<?xpacket end=\"w\"?>\n");
+
+    xmp
+}
+
+/// Export a project's title, video links and Trello cards as an XMP sidecar
+#[tauri::command]
+pub async fn baker_export_xmp(project_path: String, output_path: String) -> Result<(), String> {
+    let breadcrumbs = baker_read_breadcrumbs(project_path)
+        .await?
+        .ok_or("No breadcrumbs file found")?;
+
+    fs::write(&output_path, breadcrumbs_to_xmp(&breadcrumbs))
+        .map_err(|e| format!("Failed to write XMP file {}: {}", output_path, e))?;
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn baker_get_video_links(project_path: String) -> Result<Vec<VideoLink>, String> {
+    let breadcrumbs = baker_read_breadcrumbs(project_path).await?;
+
+    match breadcrumbs {
+        Some(b) => Ok(b.video_links.unwrap_or_default()),
+        None => Ok(Vec::new()),
+    }
+}
+
+#[tauri::command]
+pub async fn baker_associate_video_link(
+    project_path: String,
+    video_link: VideoLink,
+) -> Result<BreadcrumbsFile, String> {
+    let mut video_link = video_link;
+    validate_video_link(&mut video_link)?;
+
+    let mut breadcrumbs = baker_read_breadcrumbs(project_path.clone())
+        .await?
+        .ok_or("No breadcrumbs file found")?;
+
+    // Initialize video_links if None
+    if breadcrumbs.video_links.is_none() {
+        breadcrumbs.video_links = Some(Vec::new());
+    }
+
+    let videos = breadcrumbs.video_links.as_mut().unwrap();
+
+    // Validate max 20 videos
+    if videos.len() >= MAX_VIDEO_LINKS {
+        return Err("Maximum of 20 videos per project reached".to_string());
+    }
+
+    // Check for the same URL or Sprout video ID
+    let already_linked = videos.iter().any(|existing| {
+        is_same_video_url(&existing.url, &video_link.url)
+            || (video_link.sprout_video_id.is_some()
+                && existing.sprout_video_id == video_link.sprout_video_id)
+    });
+    if already_linked {
+        return Err("This video is already linked to the project".to_string());
     }
 
     // Add new video
@@ -1259,11 +2722,345 @@ pub async fn baker_associate_video_link(
     breadcrumbs.last_modified = Some(chrono::Utc::now().to_rfc3339());
 
     // Write to disk
-    write_breadcrumbs_file(&project_path, &breadcrumbs)?;
+    write_breadcrumbs_file(&project_path, &mut breadcrumbs, "video_link_added")?;
 
     Ok(breadcrumbs)
 }
 
+/// Associate the most recent successful upload from this session with a project
+///
+/// Title and thumbnail come from the Sprout upload response recorded in the
+/// upload log. Whichever is missing (the thumbnail usually is, while Sprout is
+/// still processing) is fetched from Sprout; if that fails the title falls back
+/// to the uploaded file name.
+#[tauri::command]
+pub async fn baker_associate_last_upload(
+    project_path: String,
+    title: Option<String>,
+    api_key: String,
+    state: State<'_, UploadState>,
+) -> Result<BreadcrumbsFile, String> {
+    let entry = {
+        let log = state.log.lock().map_err(|_| "Failed to acquire lock")?;
+        log.iter()
+            .rev()
+            .find(|entry| entry.status == "completed" && entry.video_id.is_some())
+            .cloned()
+            .ok_or("No completed uploads found this session")?
+    };
+
+    let video_id = entry.video_id.unwrap_or_default();
+
+    let mut entry_title = entry.title;
+    let mut thumbnail_url = entry.thumbnail_url;
+    if entry_title.is_none() || thumbnail_url.is_none() {
+        match fetch_sprout_video_details(video_id.clone(), api_key).await {
+            Ok(details) => {
+                entry_title = entry_title.or(Some(details.title));
+                thumbnail_url = thumbnail_url.or(details.assets.poster_frames.first().cloned());
+            }
+            Err(e) => {
+                println!("[Baker] Using logged details for video {}: {}", video_id, e)
+            }
+        }
+    }
+
+    let title = title
+        .filter(|title| !title.trim().is_empty())
+        .or(entry_title)
+        .unwrap_or_else(|| {
+            Path::new(&entry.file_name)
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_else(|| entry.file_name.clone())
+        });
+
+    let video_link = VideoLink {
+        url: format!("https://sproutvideo.com/videos/{}", video_id),
+        sprout_video_id: Some(video_id),
+        title,
+        thumbnail_url,
+        upload_date: entry.finished_at,
+        source_render_file: Some(entry.file_name),
+    };
+
+    baker_associate_video_link(project_path, video_link).await
+}
+
+/// Helper: Read (project path, title, url) rows from a video link CSV
+/// A header row is detected by its third column not being a URL; when present,
+/// columns are matched by name so the order in the spreadsheet doesn't matter
+fn read_video_link_csv(csv_path: &str) -> Result<Vec<(String, String, String)>, String> {
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_path(csv_path)
+        .map_err(|e| format!("Failed to open CSV file: {}", e))?;
+
+    let records = reader
+        .records()
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to parse CSV file: {}", e))?;
+
+    let Some(first) = records.first() else {
+        return Ok(Vec::new());
+    };
+
+    let has_header = !first
+        .get(2)
+        .is_some_and(|value| value.to_lowercase().starts_with("http"));
+
+    let (mut path_col, mut title_col, mut url_col) = (0, 1, 2);
+    if has_header {
+        for (index, header) in first.iter().enumerate() {
+            let header = header.to_lowercase();
+            if header.contains("path") || header.contains("folder") {
+                path_col = index;
+            } else if header.contains("title") || header == "name" {
+                title_col = index;
+            } else if header.contains("url") || header.contains("link") {
+                url_col = index;
+            }
+        }
+    }
+
+    let rows = records
+        .iter()
+        .skip(if has_header { 1 } else { 0 })
+        .map(|record| {
+            (
+                record.get(path_col).unwrap_or_default().to_string(),
+                record.get(title_col).unwrap_or_default().to_string(),
+                record.get(url_col).unwrap_or_default().to_string(),
+            )
+        })
+        .filter(|(path, _, url)| !path.is_empty() || !url.is_empty())
+        .collect();
+
+    Ok(rows)
+}
+
+/// Bulk-import video links from a CSV of project path, video title and video URL
+///
+/// Rows are grouped by project and appended to each project's breadcrumbs in a
+/// single write. Rows with an invalid URL or a link already present (same URL or
+/// Sprout ID) are skipped, and rows beyond the per-project video cap are reported
+/// as failures.
+#[tauri::command]
+pub async fn baker_import_video_links_csv(csv_path: String) -> Result<ImportResult, String> {
+    let rows = read_video_link_csv(&csv_path)?;
+
+    // Group rows by project path, keeping first-seen order
+    let mut grouped: Vec<(String, Vec<(String, String)>)> = Vec::new();
+    for (project_path, title, url) in rows {
+        match grouped.iter_mut().find(|(path, _)| *path == project_path) {
+            Some((_, links)) => links.push((title, url)),
+            None => grouped.push((project_path, vec![(title, url)])),
+        }
+    }
+
+    let mut result = ImportResult {
+        projects: Vec::new(),
+        total_imported: 0,
+        total_skipped: 0,
+        total_failed: 0,
+    };
+
+    for (project_path, links) in grouped {
+        let mut project_result = ProjectImportResult {
+            project_path: project_path.clone(),
+            imported: 0,
+            skipped: 0,
+            failed: 0,
+            errors: Vec::new(),
+        };
+
+        let breadcrumbs = match baker_read_breadcrumbs(project_path.clone()).await {
+            Ok(Some(breadcrumbs)) => Some(breadcrumbs),
+            Ok(None) => {
+                project_result
+                    .errors
+                    .push("No breadcrumbs file found".to_string());
+                None
+            }
+            Err(e) => {
+                project_result.errors.push(e);
+                None
+            }
+        };
+
+        let Some(mut breadcrumbs) = breadcrumbs else {
+            project_result.failed = links.len();
+            result.total_failed += project_result.failed;
+            result.projects.push(project_result);
+            continue;
+        };
+
+        let videos = breadcrumbs.video_links.get_or_insert_with(Vec::new);
+
+        for (title, url) in links {
+            let mut video_link = VideoLink {
+                url,
+                sprout_video_id: None,
+                title,
+                thumbnail_url: None,
+                upload_date: None,
+                source_render_file: None,
+            };
+
+            if let Err(e) = validate_video_link(&mut video_link) {
+                project_result.skipped += 1;
+                project_result
+                    .errors
+                    .push(format!("Skipped {}: {}", video_link.url, e));
+                continue;
+            }
+
+            let already_linked = videos.iter().any(|video| {
+                is_same_video_url(&video.url, &video_link.url)
+                    || (video_link.sprout_video_id.is_some()
+                        && video.sprout_video_id == video_link.sprout_video_id)
+            });
+            if already_linked {
+                project_result.skipped += 1;
+                continue;
+            }
+
+            if videos.len() >= MAX_VIDEO_LINKS {
+                project_result.failed += 1;
+                project_result.errors.push(format!(
+                    "Maximum of {} videos per project reached: {}",
+                    MAX_VIDEO_LINKS, video_link.url
+                ));
+                continue;
+            }
+
+            videos.push(video_link);
+            project_result.imported += 1;
+        }
+
+        if project_result.imported > 0 {
+            breadcrumbs.last_modified = Some(chrono::Utc::now().to_rfc3339());
+
+            if let Err(e) =
+                write_breadcrumbs_file(&project_path, &mut breadcrumbs, "video_links_imported")
+            {
+                project_result.errors.push(e);
+                project_result.failed += project_result.imported;
+                project_result.imported = 0;
+            }
+        }
+
+        result.total_imported += project_result.imported;
+        result.total_skipped += project_result.skipped;
+        result.total_failed += project_result.failed;
+        result.projects.push(project_result);
+    }
+
+    Ok(result)
+}
+
+/// Build a consolidated handoff manifest for external partners
+///
+/// Each project's video links are refreshed against the Sprout API so titles and
+/// thumbnails are current; if a lookup fails the stored values are used instead.
+/// Projects without video links, or whose breadcrumbs can't be read, are listed
+/// under "skipped" rather than failing the whole manifest.
+#[tauri::command]
+pub async fn baker_generate_handoff_manifest(
+    project_paths: Vec<String>,
+    api_key: String,
+) -> Result<serde_json::Value, String> {
+    if project_paths.is_empty() {
+        return Err("Project paths cannot be empty".to_string());
+    }
+
+    let mut projects = Vec::new();
+    let mut skipped = Vec::new();
+
+    for project_path in project_paths {
+        let breadcrumbs = match baker_read_breadcrumbs(project_path.clone()).await {
+            Ok(Some(breadcrumbs)) => breadcrumbs,
+            Ok(None) => {
+                skipped.push(serde_json::json!({
+                    "projectPath": project_path,
+                    "reason": "No breadcrumbs file found"
+                }));
+                continue;
+            }
+            Err(e) => {
+                skipped.push(serde_json::json!({
+                    "projectPath": project_path,
+                    "reason": e
+                }));
+                continue;
+            }
+        };
+
+        let video_links = breadcrumbs.video_links.clone().unwrap_or_default();
+        if video_links.is_empty() {
+            skipped.push(serde_json::json!({
+                "projectPath": project_path,
+                "reason": "No video links"
+            }));
+            continue;
+        }
+
+        let mut videos = Vec::new();
+        for link in video_links {
+            let mut title = link.title.clone();
+            let mut thumbnail_url = link.thumbnail_url.clone();
+
+            if let Some(video_id) = &link.sprout_video_id {
+                match fetch_sprout_video_details(video_id.clone(), api_key.clone()).await {
+                    Ok(details) => {
+                        title = details.title;
+                        if let Some(frame) = details.assets.poster_frames.first() {
+                            thumbnail_url = Some(frame.clone());
+                        }
+                    }
+                    Err(e) => {
+                        println!("[Baker] Using stored details for video {}: {}", video_id, e)
+                    }
+                }
+            }
+
+            videos.push(serde_json::json!({
+                "title": title,
+                "url": link.url,
+                "sproutVideoId": link.sprout_video_id,
+                "thumbnailUrl": thumbnail_url
+            }));
+        }
+
+        let trello_cards: Vec<serde_json::Value> = migrate_trello_card_url(&breadcrumbs)
+            .into_iter()
+            .map(|card| {
+                serde_json::json!({
+                    "title": card.title,
+                    "url": card.url,
+                    "cardId": card.card_id,
+                    "boardName": card.board_name
+                })
+            })
+            .collect();
+
+        projects.push(serde_json::json!({
+            "projectTitle": breadcrumbs.project_title,
+            "projectPath": project_path,
+            "videos": videos,
+            "trelloCards": trello_cards
+        }));
+    }
+
+    Ok(serde_json::json!({
+        "generatedAt": get_current_timestamp(),
+        "projects": projects,
+        "skipped": skipped
+    }))
+}
+
 #[tauri::command]
 pub async fn baker_remove_video_link(
     project_path: String,
@@ -1285,7 +3082,7 @@ pub async fn baker_remove_video_link(
     breadcrumbs.last_modified = Some(chrono::Utc::now().to_rfc3339());
 
     // Write to disk
-    write_breadcrumbs_file(&project_path, &breadcrumbs)?;
+    write_breadcrumbs_file(&project_path, &mut breadcrumbs, "video_link_removed")?;
 
     Ok(breadcrumbs)
 }
@@ -1312,7 +3109,7 @@ pub async fn baker_update_video_link(
     breadcrumbs.last_modified = Some(chrono::Utc::now().to_rfc3339());
 
     // Write to disk
-    write_breadcrumbs_file(&project_path, &breadcrumbs)?;
+    write_breadcrumbs_file(&project_path, &mut breadcrumbs, "video_link_updated")?;
 
     Ok(breadcrumbs)
 }
@@ -1340,7 +3137,75 @@ pub async fn baker_reorder_video_links(
     breadcrumbs.last_modified = Some(chrono::Utc::now().to_rfc3339());
 
     // Write to disk
-    write_breadcrumbs_file(&project_path, &breadcrumbs)?;
+    write_breadcrumbs_file(&project_path, &mut breadcrumbs, "video_links_reordered")?;
+
+    Ok(breadcrumbs)
+}
+
+/// Refresh stored video titles and thumbnails from Sprout
+///
+/// Links whose video can't be fetched (e.g. deleted on Sprout) keep their stored
+/// values and are reported in a `baker_video_sync_failures` event.
+#[tauri::command]
+pub async fn baker_sync_video_titles(
+    project_path: String,
+    api_key: String,
+    app_handle: AppHandle,
+) -> Result<BreadcrumbsFile, String> {
+    let mut breadcrumbs = baker_read_breadcrumbs(project_path.clone())
+        .await?
+        .ok_or("No breadcrumbs file found")?;
+
+    let mut changed = false;
+    let mut failures = Vec::new();
+
+    if let Some(videos) = breadcrumbs.video_links.as_mut() {
+        for link in videos.iter_mut() {
+            let Some(video_id) = link.sprout_video_id.clone() else {
+                continue;
+            };
+
+            match fetch_sprout_video_details(video_id.clone(), api_key.clone()).await {
+                Ok(details) => {
+                    let thumbnail_url = details
+                        .assets
+                        .poster_frames
+                        .first()
+                        .cloned()
+                        .or_else(|| link.thumbnail_url.clone());
+
+                    if link.title != details.title || link.thumbnail_url != thumbnail_url {
+                        link.title = details.title;
+                        link.thumbnail_url = thumbnail_url;
+                        changed = true;
+                    }
+                }
+                Err(e) => {
+                    println!("[Baker] Could not sync video {}: {}", video_id, e);
+                    failures.push(serde_json::json!({
+                        "sproutVideoId": video_id,
+                        "url": link.url,
+                        "error": e
+                    }));
+                }
+            }
+        }
+    }
+
+    if !failures.is_empty() {
+        let _ = app_handle.emit(
+            "baker_video_sync_failures",
+            serde_json::json!({
+                "projectPath": project_path,
+                "failures": failures
+            }),
+        );
+    }
+
+    if changed {
+        breadcrumbs.last_modified = Some(chrono::Utc::now().to_rfc3339());
+        write_breadcrumbs_file(&project_path, &mut breadcrumbs, "video_titles_synced")?;
+    }
 
     Ok(breadcrumbs)
 }
@@ -1358,6 +3223,47 @@ pub async fn baker_get_trello_cards(project_path: String) -> Result<Vec<TrelloCa
     }
 }
 
+/// Persist the legacy trelloCardUrl migration to disk
+///
+/// baker_get_trello_cards only migrates in memory; this writes the trelloCards
+/// array back so the project is stored in the new format. The deprecated
+/// trelloCardUrl is kept in sync with the first card for older readers.
+/// Projects with nothing to migrate are returned as-is without being rewritten.
+#[tauri::command]
+pub async fn baker_migrate_trello_format(project_path: String) -> Result<BreadcrumbsFile, String> {
+    let mut breadcrumbs = baker_read_breadcrumbs(project_path.clone())
+        .await?
+        .ok_or("No breadcrumbs file found")?;
+
+    let Some(legacy_url) = breadcrumbs.trello_card_url.as_ref() else {
+        return Ok(breadcrumbs);
+    };
+
+    let cards = migrate_trello_card_url(&breadcrumbs);
+    let already_migrated = breadcrumbs
+        .trello_cards
+        .as_ref()
+        .and_then(|cards| cards.first())
+        .is_some_and(|card| card.url == *legacy_url);
+    // Already in the new format, or the legacy URL isn't a Trello card
+    if cards.is_empty() || already_migrated {
+        return Ok(breadcrumbs);
+    }
+
+    breadcrumbs.trello_cards = Some(cards);
+
+    // Update backward-compatible field
+    ensure_backward_compatible_write(&mut breadcrumbs);
+
+    // Update last_modified timestamp
+    breadcrumbs.last_modified = Some(chrono::Utc::now().to_rfc3339());
+
+    // Write to disk
+    write_breadcrumbs_file(&project_path, &mut breadcrumbs, "trello_format_migrated")?;
+
+    Ok(breadcrumbs)
+}
+
 #[tauri::command]
 pub async fn baker_associate_trello_card(
     project_path: String,
@@ -1394,11 +3300,45 @@ pub async fn baker_associate_trello_card(
     breadcrumbs.last_modified = Some(chrono::Utc::now().to_rfc3339());
 
     // Write to disk
-    write_breadcrumbs_file(&project_path, &breadcrumbs)?;
+    write_breadcrumbs_file(&project_path, &mut breadcrumbs, "trello_card_added")?;
 
     Ok(breadcrumbs)
 }
 
+/// Associate one Trello card with several projects in a single call
+///
+/// Each project is handled independently, so a full or already-linked project
+/// is reported as a failure without stopping the rest of the batch.
+#[tauri::command]
+pub async fn baker_associate_trello_card_bulk(
+    project_paths: Vec<String>,
+    trello_card: TrelloCard,
+) -> Result<BatchUpdateResult, String> {
+    let mut result = BatchUpdateResult {
+        successful: Vec::new(),
+        failed: Vec::new(),
+        created: Vec::new(),
+        updated: Vec::new(),
+    };
+
+    for project_path in project_paths {
+        match baker_associate_trello_card(project_path.clone(), trello_card.clone()).await {
+            Ok(_) => {
+                result.successful.push(project_path.clone());
+                result.updated.push(project_path);
+            }
+            Err(e) => {
+                result.failed.push(FailedUpdate {
+                    path: project_path,
+                    error: e,
+                });
+            }
+        }
+    }
+
+    Ok(result)
+}
+
 #[tauri::command]
 pub async fn baker_remove_trello_card(
     project_path: String,
@@ -1423,32 +3363,138 @@ pub async fn baker_remove_trello_card(
     breadcrumbs.last_modified = Some(chrono::Utc::now().to_rfc3339());
 
     // Write to disk
-    write_breadcrumbs_file(&project_path, &breadcrumbs)?;
+    write_breadcrumbs_file(&project_path, &mut breadcrumbs, "trello_card_removed")?;
+
+    Ok(breadcrumbs)
+}
+
+/// Replace the card at `card_index` in place, e.g. after refreshing its details
+/// Editing the first card also updates the legacy trelloCardUrl
+#[tauri::command]
+pub async fn baker_update_trello_card(
+    project_path: String,
+    card_index: usize,
+    updated_card: TrelloCard,
+) -> Result<BreadcrumbsFile, String> {
+    let mut breadcrumbs = baker_read_breadcrumbs(project_path.clone())
+        .await?
+        .ok_or("No breadcrumbs file found")?;
+
+    let cards = breadcrumbs.trello_cards.as_mut().ok_or("No cards found")?;
+
+    if card_index >= cards.len() {
+        return Err("Card index out of bounds".to_string());
+    }
+
+    cards[card_index] = updated_card;
+
+    // Update backward-compatible field
+    ensure_backward_compatible_write(&mut breadcrumbs);
+
+    // Update last_modified timestamp
+    breadcrumbs.last_modified = Some(chrono::Utc::now().to_rfc3339());
+
+    // Write to disk
+    write_breadcrumbs_file(&project_path, &mut breadcrumbs, "trello_card_updated")?;
 
     Ok(breadcrumbs)
 }
 
+/// Move a card; whichever card ends up first becomes the legacy trelloCardUrl
+#[tauri::command]
+pub async fn baker_reorder_trello_cards(
+    project_path: String,
+    from_index: usize,
+    to_index: usize,
+) -> Result<BreadcrumbsFile, String> {
+    let mut breadcrumbs = baker_read_breadcrumbs(project_path.clone())
+        .await?
+        .ok_or("No breadcrumbs file found")?;
+
+    let cards = breadcrumbs.trello_cards.as_mut().ok_or("No cards found")?;
+
+    if from_index >= cards.len() || to_index >= cards.len() {
+        return Err("Index out of bounds".to_string());
+    }
+
+    let card = cards.remove(from_index);
+    cards.insert(to_index, card);
+
+    // Update backward-compatible field
+    ensure_backward_compatible_write(&mut breadcrumbs);
+
+    // Update last_modified timestamp
+    breadcrumbs.last_modified = Some(chrono::Utc::now().to_rfc3339());
+
+    // Write to disk
+    write_breadcrumbs_file(&project_path, &mut breadcrumbs, "trello_cards_reordered")?;
+
+    Ok(breadcrumbs)
+}
+
+const TRELLO_DEFAULT_MAX_ATTEMPTS: u32 = 3;
+const TRELLO_RETRY_BASE_DELAY_MS: u64 = 500;
+
+/// GET a Trello API URL, retrying network errors and 5xx responses with exponential backoff
+///
+/// Other responses (including 401/404) are returned straight away for the caller to
+/// handle. If every attempt gets a 5xx, the last response is returned.
+async fn trello_get_with_retry(
+    client: &reqwest::Client,
+    url: &str,
+    max_attempts: u32,
+) -> Result<reqwest::Response, String> {
+    let mut attempt = 1;
+    loop {
+        let retryable_error = match client.get(url).send().await {
+            Ok(response) if response.status().is_server_error() => {
+                if attempt >= max_attempts {
+                    return Ok(response);
+                }
+                format!("API error: {}", response.status())
+            }
+            Ok(response) => return Ok(response),
+            Err(e) => {
+                if attempt >= max_attempts {
+                    return Err(format!("Network error: {}", e));
+                }
+                format!("Network error: {}", e)
+            }
+        };
+
+        let delay = TRELLO_RETRY_BASE_DELAY_MS * 2u64.pow(attempt - 1);
+        println!(
+            "[Baker] Trello request failed ({}), retrying in {}ms (attempt {}/{})",
+            retryable_error, delay, attempt, max_attempts
+        );
+        tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
+        attempt += 1;
+    }
+}
+
 #[tauri::command]
 pub async fn baker_fetch_trello_card_details(
     card_url: String,
     api_key: String,
     api_token: String,
+    max_attempts: Option<u32>,
 ) -> Result<TrelloCard, String> {
+    let max_attempts = max_attempts.unwrap_or(TRELLO_DEFAULT_MAX_ATTEMPTS);
+    if max_attempts == 0 {
+        return Err("max_attempts must be at least 1".to_string());
+    }
+
     // Extract cardId from URL
     let card_id = extract_trello_card_id(&card_url).ok_or("Invalid Trello card URL format")?;
 
     // Make API request
     let client = reqwest::Client::new();
     let url = format!(
-        "https://api.trello.com/1/cards/{}?key={}&token={}",
+        "https://api.trello.com/1/cards/{}?key={}&token={}&fields=name,due,labels,idBoard",
         card_id, api_key, api_token
     );
 
-    let response = client
-        .get(&url)
-        .send()
-        .await
-        .map_err(|e| format!("Network error: {}", e))?;
+    let response = trello_get_with_retry(&client, &url, max_attempts).await?;
 
     if response.status() == 401 {
         return Err("Unauthorized: Invalid API credentials".to_string());
@@ -1474,7 +3520,7 @@ pub async fn baker_fetch_trello_card_details(
             board_id, api_key, api_token
         );
 
-        match client.get(&board_url).send().await {
+        match trello_get_with_retry(&client, &board_url, max_attempts).await {
             Ok(board_response) if board_response.status().is_success() => board_response
                 .json::<serde_json::Value>()
                 .await
@@ -1486,12 +3532,30 @@ pub async fn baker_fetch_trello_card_details(
         None
     };
 
+    let labels = data["labels"]
+        .as_array()
+        .map(|labels| {
+            labels
+                .iter()
+                .filter_map(|label| {
+                    label["name"]
+                        .as_str()
+                        .filter(|name| !name.is_empty())
+                        .or_else(|| label["color"].as_str())
+                        .map(|name| name.to_string())
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
     Ok(TrelloCard {
         url: card_url,
         card_id,
         title: data["name"].as_str().unwrap_or("Unknown").to_string(),
         board_name,
         last_fetched: Some(chrono::Utc::now().to_rfc3339()),
+        due: data["due"].as_str().map(|due| due.to_string()),
+        labels,
     })
 }
 
@@ -1528,3 +3592,7 @@ pub async fn fetch_trello_boards(
 
     Ok(boards)
 }
+
+#[cfg(test)]
+#[path = "tests/baker_test.rs"]
+mod baker_test;
@@ -128,3 +128,123 @@ pub async fn validate_provider_with_auth(
         }
     }
 }
+
+// ============================================================================
+// Embeddings
+// ============================================================================
+
+/// Embedding API flavours, told apart by endpoint path
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum EmbeddingApi {
+    Ollama,
+    OpenAi,
+}
+
+/// Resolve the embeddings endpoint and API flavour from a provider URL
+///
+/// Full endpoint URLs are used as-is. A bare base URL is treated as OpenAI-style
+/// when it ends in /v1, and as Ollama otherwise.
+fn resolve_embedding_endpoint(provider_url: &str) -> (String, EmbeddingApi) {
+    let url = provider_url.trim_end_matches('/');
+
+    if url.ends_with("/api/embeddings") || url.ends_with("/api/embed") {
+        (url.to_string(), EmbeddingApi::Ollama)
+    } else if url.ends_with("/embeddings") {
+        (url.to_string(), EmbeddingApi::OpenAi)
+    } else if url.ends_with("/v1") {
+        (format!("{}/embeddings", url), EmbeddingApi::OpenAi)
+    } else {
+        (format!("{}/api/embeddings", url), EmbeddingApi::Ollama)
+    }
+}
+
+/// Pull the vector out of either an Ollama or an OpenAI embeddings response
+fn parse_embedding_response(body: &serde_json::Value) -> Option<Vec<f32>> {
+    let vector = body
+        .get("embedding")
+        .or_else(|| body.get("embeddings").and_then(|e| e.get(0)))
+        .or_else(|| {
+            body.get("data")
+                .and_then(|d| d.get(0))
+                .and_then(|d| d.get("embedding"))
+        })?;
+
+    vector
+        .as_array()?
+        .iter()
+        .map(|value| value.as_f64().map(|v| v as f32))
+        .collect()
+}
+
+/// Request an embedding for `text` from the provider (shared by the RAG commands)
+pub(crate) async fn request_embedding(
+    text: &str,
+    provider_url: &str,
+    model: &str,
+    auth_header: Option<&str>,
+) -> Result<Vec<f32>, String> {
+    let (endpoint, api) = resolve_embedding_endpoint(provider_url);
+
+    let body = match api {
+        EmbeddingApi::Ollama => serde_json::json!({ "model": model, "prompt": text }),
+        EmbeddingApi::OpenAi => serde_json::json!({ "model": model, "input": text }),
+    };
+
+    let client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(60))
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    let mut request = client.post(&endpoint).json(&body);
+    if let Some(auth) = auth_header {
+        request = request.header("Authorization", auth);
+    }
+
+    let response = request.send().await.map_err(|e| {
+        if e.is_connect() {
+            "Failed to connect to provider. Is the service running?".to_string()
+        } else {
+            format!("Embedding request failed: {}", e)
+        }
+    })?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let detail = response.text().await.unwrap_or_default();
+        return Err(format!(
+            "Embedding request failed: HTTP {} {}",
+            status,
+            detail.trim()
+        ));
+    }
+
+    let json: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse embedding response: {}", e))?;
+
+    let embedding = parse_embedding_response(&json)
+        .ok_or_else(|| "Provider response did not contain an embedding".to_string())?;
+
+    crate::commands::rag::validate_embedding_dimensions(&embedding)?;
+
+    Ok(embedding)
+}
+
+/**
+ * Generate an embedding via an Ollama or OpenAI-compatible provider
+ * Keeps embedding generation next to the RAG commands instead of in the frontend
+ */
+#[command]
+pub async fn generate_embedding(
+    text: String,
+    provider_url: String,
+    model: String,
+    auth_header: Option<String>,
+) -> Result<Vec<f32>, String> {
+    if text.trim().is_empty() {
+        return Err("Text cannot be empty".to_string());
+    }
+
+    request_embedding(&text, &provider_url, &model, auth_header.as_deref()).await
+}
@@ -0,0 +1,538 @@
+/**
+ * Footage Archive Commands
+ * Purpose: Move finished projects' Footage folders to cold storage as .tar.zst
+ * archives, leaving breadcrumbs behind as a pointer to the archive
+ */
+
+use crate::baker::{baker_read_breadcrumbs, write_breadcrumbs_file};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Component, Path, PathBuf};
+use std::time::{Duration, Instant};
+use tauri::{command, AppHandle, Emitter};
+
+/// zstd level 3 is the library default: fast, and footage barely compresses anyway
+const ZSTD_LEVEL: i32 = 3;
+
+/// Minimum time between progress events while streaming a file
+const ARCHIVE_PROGRESS_INTERVAL: Duration = Duration::from_millis(250);
+
+// ============================================================================
+// Type Definitions
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveResult {
+    pub archive_path: String,
+    pub files_archived: usize,
+    pub bytes_archived: u64,
+    pub archive_size_bytes: u64,
+    pub verified: bool,
+}
+
+/// Progress payload for the baker_archive_progress event
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveProgressEvent {
+    pub project_path: String,
+    pub bytes_processed: u64,
+    pub total_bytes: u64,
+    pub current_file: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SizeMismatch {
+    pub path: String,
+    pub expected_bytes: u64,
+    pub archived_bytes: u64,
+}
+
+/// Result of checking an archive against a project's breadcrumbs manifest
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifyResult {
+    pub archive_path: String,
+    pub files_checked: usize,
+    pub missing: Vec<String>,
+    pub size_mismatched: Vec<SizeMismatch>,
+    pub is_complete: bool,
+}
+
+/// A file under Footage/, with its archive entry name ("Footage/Camera 1/clip.mp4")
+struct FootageFile {
+    absolute_path: PathBuf,
+    entry_name: String,
+    size: u64,
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Reader that reports the running byte count through a callback
+struct CountingReader<'a, R> {
+    inner: R,
+    on_read: &'a mut dyn FnMut(u64),
+}
+
+impl<R: Read> Read for CountingReader<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        (self.on_read)(n as u64);
+        Ok(n)
+    }
+}
+
+/// Collect every non-hidden file under the project's Footage folder, sorted by name
+fn collect_footage_files(project_root: &Path) -> Result<Vec<FootageFile>, String> {
+    fn visit(dir: &Path, prefix: &str, files: &mut Vec<FootageFile>) -> io::Result<()> {
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let name = entry.file_name().to_string_lossy().to_string();
+
+            // Skip hidden files (starting with .) like .DS_Store
+            if name.starts_with('.') {
+                continue;
+            }
+
+            let entry_name = format!("{}/{}", prefix, name);
+            let file_type = entry.file_type()?;
+
+            if file_type.is_dir() {
+                visit(&entry.path(), &entry_name, files)?;
+            } else if file_type.is_file() {
+                files.push(FootageFile {
+                    absolute_path: entry.path(),
+                    entry_name,
+                    size: entry.metadata()?.len(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    let footage_path = project_root.join("Footage");
+    if !footage_path.is_dir() {
+        return Err("Project has no Footage folder".to_string());
+    }
+
+    let mut files = Vec::new();
+    visit(&footage_path, "Footage", &mut files)
+        .map_err(|e| format!("Failed to read Footage folder: {}", e))?;
+    files.sort_by(|a, b| a.entry_name.cmp(&b.entry_name));
+
+    Ok(files)
+}
+
+/// List the regular-file entries of a .tar.zst archive with their sizes
+///
+/// Reading every entry also decompresses the whole stream, so a truncated or
+/// corrupt archive fails here rather than on restore.
+pub(crate) fn read_archive_entries(archive_path: &Path) -> Result<HashMap<String, u64>, String> {
+    let mut archive = open_archive(archive_path)?;
+
+    let mut entries = HashMap::new();
+    for entry in archive
+        .entries()
+        .map_err(|e| format!("Failed to read archive entries: {}", e))?
+    {
+        let entry = entry.map_err(|e| format!("Corrupt archive entry: {}", e))?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+
+        let path = entry
+            .path()
+            .map_err(|e| format!("Invalid path in archive: {}", e))?
+            .to_string_lossy()
+            .to_string();
+        entries.insert(path, entry.size());
+    }
+
+    Ok(entries)
+}
+
+/// Map an archive entry path onto the project, rejecting anything outside Footage/
+///
+/// Only plain path components are allowed, so entries like "../x" or absolute
+/// paths can't write outside the project (zip-slip).
+fn restore_destination(project_root: &Path, entry_path: &Path) -> Result<PathBuf, String> {
+    let mut components = entry_path.components();
+
+    let under_footage = matches!(
+        components.next(),
+        Some(Component::Normal(first)) if first == "Footage"
+    );
+    let all_normal = components.all(|component| matches!(component, Component::Normal(_)));
+
+    if !under_footage || !all_normal {
+        return Err(format!(
+            "Refusing to restore unsafe archive entry: {}",
+            entry_path.display()
+        ));
+    }
+
+    Ok(project_root.join(entry_path))
+}
+
+/// Open a .tar.zst archive for sequential reading
+fn open_archive(
+    archive_path: &Path,
+) -> Result<tar::Archive<zstd::Decoder<'static, io::BufReader<fs::File>>>, String> {
+    let file = fs::File::open(archive_path)
+        .map_err(|e| format!("Failed to open archive {}: {}", archive_path.display(), e))?;
+    let decoder = zstd::Decoder::new(file)
+        .map_err(|e| format!("Failed to read archive {}: {}", archive_path.display(), e))?;
+
+    Ok(tar::Archive::new(decoder))
+}
+
+/// Stream the footage files into a zstd-compressed tar at `output_path`
+fn write_footage_archive(
+    files: &[FootageFile],
+    output_path: &Path,
+    on_progress: &mut dyn FnMut(u64, &str),
+) -> Result<(), String> {
+    let file = fs::File::create(output_path)
+        .map_err(|e| format!("Failed to create archive {}: {}", output_path.display(), e))?;
+    let encoder = zstd::Encoder::new(file, ZSTD_LEVEL)
+        .map_err(|e| format!("Failed to start compression: {}", e))?;
+    let mut builder = tar::Builder::new(encoder);
+
+    let mut bytes_processed = 0u64;
+
+    for footage_file in files {
+        let source = fs::File::open(&footage_file.absolute_path).map_err(|e| {
+            format!(
+                "Failed to open {}: {}",
+                footage_file.absolute_path.display(),
+                e
+            )
+        })?;
+        let metadata = source
+            .metadata()
+            .map_err(|e| format!("Failed to read metadata: {}", e))?;
+
+        let mut header = tar::Header::new_gnu();
+        header.set_metadata(&metadata);
+
+        let mut on_read = |n: u64| {
+            bytes_processed += n;
+            on_progress(bytes_processed, &footage_file.entry_name);
+        };
+        let reader = CountingReader {
+            inner: source,
+            on_read: &mut on_read,
+        };
+
+        builder
+            .append_data(&mut header, &footage_file.entry_name, reader)
+            .map_err(|e| format!("Failed to archive {}: {}", footage_file.entry_name, e))?;
+    }
+
+    builder
+        .into_inner()
+        .and_then(|encoder| encoder.finish())
+        .and_then(|file| file.sync_all())
+        .map_err(|e| format!("Failed to finish archive: {}", e))
+}
+
+// ============================================================================
+// Commands
+// ============================================================================
+
+/// Archive a project's Footage folder to a .tar.zst file for cold storage
+///
+/// The archive is written next to `output_path` first and only moved into place
+/// once every file has been read back with a matching size. The original
+/// footage is never deleted here; that is left to the user once the archive
+/// has been stored safely.
+#[command]
+pub async fn baker_archive_footage(
+    project_path: String,
+    output_path: String,
+    app_handle: AppHandle,
+) -> Result<ArchiveResult, String> {
+    let mut breadcrumbs = baker_read_breadcrumbs(project_path.clone())
+        .await?
+        .ok_or("No breadcrumbs file found")?;
+
+    let output = PathBuf::from(&output_path);
+    if output.exists() {
+        return Err(format!("Archive already exists: {}", output_path));
+    }
+
+    let project = project_path.clone();
+    let result = tokio::task::spawn_blocking(move || {
+        let files = collect_footage_files(Path::new(&project))?;
+        if files.is_empty() {
+            return Err("No footage files to archive".to_string());
+        }
+
+        let total_bytes: u64 = files.iter().map(|file| file.size).sum();
+        let partial_path = PathBuf::from(format!("{}.partial", output.display()));
+
+        let mut last_emit = Instant::now();
+        let mut on_progress = |bytes_processed: u64, current_file: &str| {
+            if last_emit.elapsed() < ARCHIVE_PROGRESS_INTERVAL && bytes_processed < total_bytes {
+                return;
+            }
+            last_emit = Instant::now();
+
+            let _ = app_handle.emit(
+                "baker_archive_progress",
+                ArchiveProgressEvent {
+                    project_path: project.clone(),
+                    bytes_processed,
+                    total_bytes,
+                    current_file: current_file.to_string(),
+                },
+            );
+        };
+
+        let written = write_footage_archive(&files, &partial_path, &mut on_progress)
+            .and_then(|_| verify_written_archive(&files, &partial_path));
+        if let Err(e) = written {
+            let _ = fs::remove_file(&partial_path);
+            return Err(e);
+        }
+
+        fs::rename(&partial_path, &output).map_err(|e| {
+            let _ = fs::remove_file(&partial_path);
+            format!("Failed to move archive into place: {}", e)
+        })?;
+
+        let archive_size_bytes = fs::metadata(&output).map(|m| m.len()).unwrap_or(0);
+
+        println!(
+            "[Baker] Archived {} files ({} bytes) from {} to {}",
+            files.len(),
+            total_bytes,
+            project,
+            output.display()
+        );
+
+        Ok(ArchiveResult {
+            archive_path: output.to_string_lossy().to_string(),
+            files_archived: files.len(),
+            bytes_archived: total_bytes,
+            archive_size_bytes,
+            verified: true,
+        })
+    })
+    .await
+    .map_err(|e| format!("Archive task failed: {}", e))??;
+
+    breadcrumbs.archived_to = Some(result.archive_path.clone());
+    breadcrumbs.last_modified = Some(chrono::Utc::now().to_rfc3339());
+    write_breadcrumbs_file(&project_path, &mut breadcrumbs, "footage_archived")?;
+
+    Ok(result)
+}
+
+/// Check an archive holds every file listed in the project's breadcrumbs
+///
+/// Sizes are compared against the originals while they are still on disk; once
+/// they have been removed only presence in the archive can be checked.
+#[command]
+pub async fn baker_verify_archive(
+    archive_path: String,
+    project_path: String,
+) -> Result<VerifyResult, String> {
+    let breadcrumbs = baker_read_breadcrumbs(project_path.clone())
+        .await?
+        .ok_or("No breadcrumbs file found")?;
+
+    tokio::task::spawn_blocking(move || {
+        let entries = read_archive_entries(Path::new(&archive_path))?;
+        let project_root = Path::new(&project_path);
+
+        let mut missing = Vec::new();
+        let mut size_mismatched = Vec::new();
+
+        for file in &breadcrumbs.files {
+            let Some(archived_bytes) = entries.get(&file.path) else {
+                missing.push(file.path.clone());
+                continue;
+            };
+
+            if let Ok(metadata) = fs::metadata(project_root.join(&file.path)) {
+                if metadata.len() != *archived_bytes {
+                    size_mismatched.push(SizeMismatch {
+                        path: file.path.clone(),
+                        expected_bytes: metadata.len(),
+                        archived_bytes: *archived_bytes,
+                    });
+                }
+            }
+        }
+
+        Ok(VerifyResult {
+            is_complete: missing.is_empty() && size_mismatched.is_empty(),
+            archive_path,
+            files_checked: breadcrumbs.files.len(),
+            missing,
+            size_mismatched,
+        })
+    })
+    .await
+    .map_err(|e| format!("Verify task failed: {}", e))?
+}
+
+/// Check that a freshly written archive holds every source file at full size
+fn verify_written_archive(files: &[FootageFile], archive_path: &Path) -> Result<(), String> {
+    let entries = read_archive_entries(archive_path)?;
+
+    for file in files {
+        match entries.get(&file.entry_name) {
+            Some(size) if *size == file.size => {}
+            Some(size) => {
+                return Err(format!(
+                "Archive verification failed: {} is {} bytes in the archive but {} bytes on disk",
+                file.entry_name, size, file.size
+            ))
+            }
+            None => {
+                return Err(format!(
+                    "Archive verification failed: {} is missing from the archive",
+                    file.entry_name
+                ))
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Extract a footage archive back into the project's Footage folder
+///
+/// Files that already exist are handled by `conflict_strategy`: "abort" (the
+/// default) fails before anything is written, "skip" keeps the existing file
+/// and "overwrite" replaces it. Clears archivedTo in breadcrumbs afterwards.
+#[command]
+pub async fn baker_restore_footage(
+    archive_path: String,
+    project_path: String,
+    conflict_strategy: Option<String>,
+    app_handle: AppHandle,
+) -> Result<(), String> {
+    let strategy = conflict_strategy.unwrap_or_else(|| "abort".to_string());
+    if !["abort", "skip", "overwrite"].contains(&strategy.as_str()) {
+        return Err(format!(
+            "Invalid conflict strategy '{}'. Must be one of: abort, skip, overwrite",
+            strategy
+        ));
+    }
+
+    let project = project_path.clone();
+    tokio::task::spawn_blocking(move || {
+        let project_root = Path::new(&project);
+        let archive_file = Path::new(&archive_path);
+
+        // First pass: validate every path and look for conflicts before writing anything
+        let entries = read_archive_entries(archive_file)?;
+        let mut conflicts = Vec::new();
+        for entry_path in entries.keys() {
+            let destination = restore_destination(project_root, Path::new(entry_path))?;
+            if destination.exists() {
+                conflicts.push(entry_path.clone());
+            }
+        }
+
+        if strategy == "abort" && !conflicts.is_empty() {
+            conflicts.sort();
+            return Err(format!(
+                "{} file(s) already exist in the project: {}",
+                conflicts.len(),
+                conflicts.join(", ")
+            ));
+        }
+
+        let total_bytes: u64 = entries.values().sum();
+        let mut bytes_processed = 0u64;
+        let mut last_emit = Instant::now();
+
+        // Second pass: extract
+        let mut archive = open_archive(archive_file)?;
+        for entry in archive
+            .entries()
+            .map_err(|e| format!("Failed to read archive entries: {}", e))?
+        {
+            let entry = entry.map_err(|e| format!("Corrupt archive entry: {}", e))?;
+            if !entry.header().entry_type().is_file() {
+                continue;
+            }
+
+            let entry_path = entry
+                .path()
+                .map_err(|e| format!("Invalid path in archive: {}", e))?
+                .into_owned();
+            let entry_name = entry_path.to_string_lossy().to_string();
+            let destination = restore_destination(project_root, &entry_path)?;
+
+            if destination.exists() && strategy == "skip" {
+                bytes_processed += entry.size();
+                continue;
+            }
+
+            if let Some(parent) = destination.parent() {
+                fs::create_dir_all(parent)
+                    .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+            }
+
+            let mut on_read = |n: u64| {
+                bytes_processed += n;
+                if last_emit.elapsed() < ARCHIVE_PROGRESS_INTERVAL && bytes_processed < total_bytes
+                {
+                    return;
+                }
+                last_emit = Instant::now();
+
+                let _ = app_handle.emit(
+                    "baker_restore_progress",
+                    ArchiveProgressEvent {
+                        project_path: project.clone(),
+                        bytes_processed,
+                        total_bytes,
+                        current_file: entry_name.clone(),
+                    },
+                );
+            };
+            let mut reader = CountingReader {
+                inner: entry,
+                on_read: &mut on_read,
+            };
+
+            let mut output = fs::File::create(&destination)
+                .map_err(|e| format!("Failed to create {}: {}", destination.display(), e))?;
+            io::copy(&mut reader, &mut output)
+                .map_err(|e| format!("Failed to restore {}: {}", entry_name, e))?;
+        }
+
+        println!(
+            "[Baker] Restored {} files ({} bytes) from {} into {}",
+            entries.len(),
+            total_bytes,
+            archive_path,
+            project
+        );
+
+        Ok::<(), String>(())
+    })
+    .await
+    .map_err(|e| format!("Restore task failed: {}", e))??;
+
+    if let Some(mut breadcrumbs) = baker_read_breadcrumbs(project_path.clone()).await? {
+        if breadcrumbs.archived_to.is_some() {
+            breadcrumbs.archived_to = None;
+            breadcrumbs.last_modified = Some(chrono::Utc::now().to_rfc3339());
+            write_breadcrumbs_file(&project_path, &mut breadcrumbs, "footage_restored")?;
+        }
+    }
+
+    Ok(())
+}
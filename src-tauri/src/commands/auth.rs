@@ -1,18 +1,98 @@
-use crate::state::AuthState;
+use crate::state::{
+    default_token_service, hash_token, mask_token, AuthState, AuthStatus, MaskedToken, TokenEntry,
+};
 use tauri::State;
 
+/// Check a token against the store; a store that can't be read counts as unauthorized
+///
+/// Expired tokens are rejected but kept until prune_expired_tokens runs.
 #[tauri::command]
-pub fn check_auth(token: String, state: State<AuthState>) -> String {
-    let tokens = state.tokens.lock().unwrap();
-    if tokens.contains(&token) {
-        "authenticated".to_string()
+pub fn check_auth(token: String, state: State<AuthState>) -> AuthStatus {
+    if state.is_valid(&token).unwrap_or(false) {
+        AuthStatus::Authenticated
     } else {
-        "unauthorized".to_string()
+        AuthStatus::Unauthorized
     }
 }
 
+/// Store a token so it is still accepted after a restart
+///
+/// `service` defaults to "app" and `expires_at` must be an RFC 3339
+/// timestamp. Adding a token that is already stored updates its service and
+/// expiry.
 #[tauri::command]
-pub fn add_token(token: String, state: State<AuthState>) {
-    let mut tokens = state.tokens.lock().unwrap();
-    tokens.push(token);
+pub fn add_token(
+    token: String,
+    service: Option<String>,
+    expires_at: Option<String>,
+    state: State<AuthState>,
+) -> Result<(), String> {
+    if let Some(expires_at) = &expires_at {
+        chrono::DateTime::parse_from_rfc3339(expires_at)
+            .map_err(|e| format!("Invalid expiry '{}': {}", expires_at, e))?;
+    }
+    let service = service.unwrap_or_else(default_token_service);
+    let hash = hash_token(&token);
+
+    {
+        let mut tokens = state.tokens.lock().map_err(|_| "Failed to acquire lock")?;
+        match tokens.iter_mut().find(|stored| stored.hash == hash) {
+            Some(existing) => {
+                existing.service = service;
+                existing.expires_at = expires_at;
+            }
+            None => tokens.push(TokenEntry {
+                id: uuid::Uuid::new_v4().to_string(),
+                hash,
+                masked: mask_token(&token),
+                service,
+                added_at: chrono::Utc::now().to_rfc3339(),
+                expires_at,
+            }),
+        }
+    }
+
+    state.save()
+}
+
+/// Remove a stored token by the id from list_tokens, returning whether it existed
+#[tauri::command]
+pub fn remove_token(token_id: String, state: State<AuthState>) -> Result<bool, String> {
+    let removed = {
+        let mut tokens = state.tokens.lock().map_err(|_| "Failed to acquire lock")?;
+        let before = tokens.len();
+        tokens.retain(|stored| stored.id != token_id);
+        tokens.len() != before
+    };
+
+    if removed {
+        state.save()?;
+    }
+
+    Ok(removed)
+}
+
+/// Delete every expired token, returning how many were removed
+#[tauri::command]
+pub fn prune_expired_tokens(state: State<AuthState>) -> Result<usize, String> {
+    let now = chrono::Utc::now();
+    let removed = {
+        let mut tokens = state.tokens.lock().map_err(|_| "Failed to acquire lock")?;
+        let before = tokens.len();
+        tokens.retain(|stored| !stored.is_expired(now));
+        before - tokens.len()
+    };
+
+    if removed > 0 {
+        state.save()?;
+    }
+
+    Ok(removed)
+}
+
+/// Stored tokens with all but their last few characters masked
+#[tauri::command]
+pub fn list_tokens(state: State<AuthState>) -> Result<Vec<MaskedToken>, String> {
+    let tokens = state.tokens.lock().map_err(|_| "Failed to acquire lock")?;
+    Ok(tokens.iter().map(MaskedToken::from).collect())
 }
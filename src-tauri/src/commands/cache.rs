@@ -0,0 +1,69 @@
+use crate::baker::calculate_folder_size;
+use serde::Serialize;
+use std::fs;
+use tauri::{command, AppHandle, Manager};
+
+/// Disk caches kept under the app cache directory, one subfolder each
+const CACHE_TYPES: &[&str] = &["thumbnails", "http"];
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheClearResult {
+    pub cleared: Vec<String>,
+    pub bytes_freed: u64,
+}
+
+/// Clear one cache by name, or every cache when `cache_type` is None
+#[command]
+pub async fn clear_cache(
+    app: AppHandle,
+    cache_type: Option<String>,
+) -> Result<CacheClearResult, String> {
+    let targets: Vec<&str> = match cache_type.as_deref() {
+        Some(requested) => {
+            let known = CACHE_TYPES
+                .iter()
+                .find(|name| **name == requested)
+                .ok_or_else(|| {
+                    format!(
+                        "Unknown cache type '{}'. Must be one of: {}",
+                        requested,
+                        CACHE_TYPES.join(", ")
+                    )
+                })?;
+            vec![*known]
+        }
+        None => CACHE_TYPES.to_vec(),
+    };
+
+    let cache_dir = app
+        .path()
+        .app_cache_dir()
+        .map_err(|e| format!("Failed to get app cache dir: {}", e))?;
+
+    let mut result = CacheClearResult {
+        cleared: Vec::new(),
+        bytes_freed: 0,
+    };
+
+    for name in targets {
+        let dir = cache_dir.join(name);
+        if !dir.exists() {
+            continue;
+        }
+
+        let size = calculate_folder_size(&dir)
+            .map_err(|e| format!("Failed to measure {} cache: {}", name, e))?;
+        fs::remove_dir_all(&dir).map_err(|e| format!("Failed to clear {} cache: {}", name, e))?;
+
+        result.bytes_freed += size;
+        result.cleared.push(name.to_string());
+    }
+
+    println!(
+        "[Cache] Cleared {:?}, freed {} bytes",
+        result.cleared, result.bytes_freed
+    );
+
+    Ok(result)
+}
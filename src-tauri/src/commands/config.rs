@@ -0,0 +1,53 @@
+use crate::baker::set_camera_folder_pattern;
+use crate::state::{AppConfig, ConfigState};
+use tauri::{command, State};
+
+/// Validate a config, returning every problem found rather than just the first
+#[command]
+pub fn validate_config(config: AppConfig) -> Result<(), Vec<String>> {
+    let errors = config.validate();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+#[command]
+pub fn get_app_config(state: State<'_, ConfigState>) -> Result<AppConfig, String> {
+    let config = state.config.lock().map_err(|_| "Failed to acquire lock")?;
+    Ok(config.clone())
+}
+
+/// Warnings for settings that were reset to defaults when the app started
+#[command]
+pub fn get_config_warnings(state: State<'_, ConfigState>) -> Result<Vec<String>, String> {
+    let warnings = state
+        .warnings
+        .lock()
+        .map_err(|_| "Failed to acquire lock")?;
+    Ok(warnings.clone())
+}
+
+#[command]
+pub fn update_app_config(
+    config: AppConfig,
+    state: State<'_, ConfigState>,
+) -> Result<AppConfig, String> {
+    let errors = config.validate();
+    if !errors.is_empty() {
+        return Err(errors.join("; "));
+    }
+
+    set_camera_folder_pattern(config.camera_folder_pattern.as_deref())?;
+
+    {
+        let mut current = state.config.lock().map_err(|_| "Failed to acquire lock")?;
+        *current = config.clone();
+    }
+
+    state.save()?;
+
+    Ok(config)
+}
@@ -0,0 +1,145 @@
+/**
+ * Contact Sheet Commands
+ * Purpose: Visual preview of a project, built from one frame of each camera's
+ * first clip via FFmpeg, for QC without opening Premiere
+ */
+
+use crate::baker::{baker_scan_current_files, FileInfo};
+use crate::commands::media_probe::find_ffmpeg_tool;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tauri::command;
+use uuid::Uuid;
+
+/// Size of each cell in the contact sheet grid; frames are letterboxed to fit
+const CELL_WIDTH: u32 = 480;
+const CELL_HEIGHT: u32 = 270;
+
+/// Extract the first frame of a clip as a letterboxed PNG cell
+fn extract_frame(ffmpeg: &Path, clip_path: &Path, output_path: &Path) -> Result<(), String> {
+    let scale = format!(
+        "scale={w}:{h}:force_original_aspect_ratio=decrease,pad={w}:{h}:(ow-iw)/2:(oh-ih)/2",
+        w = CELL_WIDTH,
+        h = CELL_HEIGHT
+    );
+
+    let output = Command::new(ffmpeg)
+        .args(["-v", "error", "-y", "-i"])
+        .arg(clip_path)
+        .args(["-frames:v", "1", "-vf", &scale])
+        .arg(output_path)
+        .output()
+        .map_err(|e| format!("Failed to run ffmpeg: {}", e))?;
+
+    if !output.status.success() || !output_path.exists() {
+        return Err(format!(
+            "ffmpeg could not extract a frame from {}: {}",
+            clip_path.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    Ok(())
+}
+
+/// Extract one frame per camera into `work_dir`, then tile them into `output_path`
+fn build_contact_sheet(
+    ffmpeg: &Path,
+    project_root: &Path,
+    files: &[FileInfo],
+    work_dir: &Path,
+    output_path: &Path,
+) -> Result<(), String> {
+    let mut cameras: Vec<i32> = files.iter().map(|file| file.camera).collect();
+    cameras.dedup();
+
+    let mut frame_count: usize = 0;
+    for camera in cameras {
+        // Audio or sidecar files can't give a frame, so fall through to the next clip
+        let extracted = files
+            .iter()
+            .filter(|file| file.camera == camera)
+            .any(|file| {
+                let frame_path = work_dir.join(format!("frame_{:03}.png", frame_count));
+                match extract_frame(ffmpeg, &project_root.join(&file.path), &frame_path) {
+                    Ok(()) => true,
+                    Err(e) => {
+                        println!("[Baker] Skipping {} for contact sheet: {}", file.path, e);
+                        false
+                    }
+                }
+            });
+
+        if extracted {
+            frame_count += 1;
+        }
+    }
+
+    if frame_count == 0 {
+        return Err("No video frames could be extracted from this project's footage".to_string());
+    }
+
+    let columns = (frame_count as f64).sqrt().ceil() as usize;
+    let rows = frame_count.div_ceil(columns);
+
+    let output = Command::new(ffmpeg)
+        .args(["-v", "error", "-y", "-i"])
+        .arg(work_dir.join("frame_%03d.png"))
+        .args([
+            "-vf",
+            &format!("tile={}x{}", columns, rows),
+            "-frames:v",
+            "1",
+        ])
+        .arg(output_path)
+        .output()
+        .map_err(|e| format!("Failed to run ffmpeg: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "ffmpeg could not build the contact sheet: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    Ok(())
+}
+
+/// Composite the first frame of each camera's first clip into a single image grid
+///
+/// The image format follows the extension of `output_path` (e.g. .jpg or .png).
+#[command]
+pub async fn baker_generate_contact_sheet(
+    project_path: String,
+    output_path: String,
+) -> Result<(), String> {
+    let files = baker_scan_current_files(project_path.clone()).await?;
+
+    if files.is_empty() {
+        return Err("No footage files found in project".to_string());
+    }
+
+    tokio::task::spawn_blocking(move || {
+        let ffmpeg = find_ffmpeg_tool("ffmpeg")?;
+
+        let work_dir: PathBuf =
+            std::env::temp_dir().join(format!("baker_contact_sheet_{}", Uuid::new_v4()));
+        fs::create_dir_all(&work_dir)
+            .map_err(|e| format!("Failed to create temporary directory: {}", e))?;
+
+        let result = build_contact_sheet(
+            &ffmpeg,
+            Path::new(&project_path),
+            &files,
+            &work_dir,
+            Path::new(&output_path),
+        );
+
+        let _ = fs::remove_dir_all(&work_dir);
+
+        result
+    })
+    .await
+    .map_err(|e| format!("Contact sheet task failed: {}", e))?
+}
@@ -4,10 +4,16 @@
  * Purpose: Tauri commands for .docx file parsing and generation
  */
 
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
-use tauri::command;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use tauri::{command, AppHandle, Manager};
+use zip::write::FileOptions;
+use zip::{ZipArchive, ZipWriter};
 
 // ============================================================================
 // Type Definitions
@@ -20,7 +26,7 @@ pub struct ParseResult {
     pub formatting_metadata: FormattingMetadata,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct FormattingMetadata {
     pub bold_ranges: Vec<Range>,
     pub italic_ranges: Vec<Range>,
@@ -71,11 +77,11 @@ pub struct DownloadPath {
 /**
  * Parse a .docx file and extract content with formatting metadata
  * FR-003: File format validation
- * FR-005: 1GB file size limit
+ * FR-005: File size limit (max_size_mb, default DEFAULT_DOCX_SIZE_LIMIT_MB)
  * FR-006: Content validation
  */
 #[command]
-pub fn parse_docx_file(file_path: String) -> Result<ParseResult, String> {
+pub fn parse_docx_file(file_path: String, max_size_mb: Option<u64>) -> Result<ParseResult, String> {
     // Validate file exists
     let path = Path::new(&file_path);
     if !path.exists() {
@@ -90,50 +96,844 @@ pub fn parse_docx_file(file_path: String) -> Result<ParseResult, String> {
         return Err("File must be a .docx document".to_string());
     }
 
-    // FR-005: Check file size (1GB limit)
-    let metadata = fs::metadata(path).map_err(|e| format!("Failed to read file metadata: {}", e))?;
+    // FR-005: Check file size
+    check_file_size(path, max_size_mb)?;
+
+    let file = fs::File::open(path).map_err(|e| format!("Failed to open file: {}", e))?;
+    let mut archive = ZipArchive::new(file).map_err(|_| INVALID_DOCX_ERROR.to_string())?;
+
+    let document_xml =
+        read_archive_entry(&mut archive, "word/document.xml")?.ok_or(INVALID_DOCX_ERROR)?;
+    let numbering = match read_archive_entry(&mut archive, "word/numbering.xml")? {
+        Some(numbering_xml) => parse_numbering(&numbering_xml)?,
+        None => NumberingFormats::default(),
+    };
+
+    parse_document_xml(&document_xml, &numbering)
+}
+
+// ============================================================================
+// Document Parsing
+// ============================================================================
+
+/// Scripts bigger than this are almost always corrupt or full of embedded media
+pub const DEFAULT_DOCX_SIZE_LIMIT_MB: u64 = 100;
 
-    const ONE_GB: u64 = 1024 * 1024 * 1024;
-    if metadata.len() > ONE_GB {
+/// Check the file against the size limit, returning its metadata
+///
+/// The error names the limit so the UI can show which cap was exceeded.
+fn check_file_size(path: &Path, max_size_mb: Option<u64>) -> Result<fs::Metadata, String> {
+    let metadata =
+        fs::metadata(path).map_err(|e| format!("Failed to read file metadata: {}", e))?;
+
+    let limit_mb = max_size_mb.unwrap_or(DEFAULT_DOCX_SIZE_LIMIT_MB);
+    if metadata.len() > limit_mb.saturating_mul(1024 * 1024) {
         return Err(format!(
-            "File size ({} bytes) exceeds 1GB limit",
-            metadata.len()
+            "File size ({} bytes) exceeds the {} MB limit",
+            metadata.len(),
+            limit_mb
         ));
     }
 
-    // NOTE: Actual parsing is done in frontend using mammoth.js
-    // This command just validates the file and returns metadata
-    // The frontend will handle the actual parsing
-
-    Ok(ParseResult {
-        text_content: String::new(),
-        html_content: String::new(),
-        formatting_metadata: FormattingMetadata {
-            bold_ranges: vec![],
-            italic_ranges: vec![],
-            underline_ranges: vec![],
-            headings: vec![],
-            lists: vec![],
-            paragraphs: vec![],
-        },
-    })
+    Ok(metadata)
+}
+
+const INVALID_DOCX_ERROR: &str = "Not a valid Word document (corrupt or wrong format)";
+
+/// Confirm the file is a ZIP archive with the parts every Word document has
+///
+/// Only the archive's central directory is read, so this stays cheap for large files.
+fn check_ooxml_container(path: &Path) -> Result<(), String> {
+    let file = fs::File::open(path).map_err(|e| format!("Failed to open file: {}", e))?;
+    let archive = ZipArchive::new(file).map_err(|_| INVALID_DOCX_ERROR.to_string())?;
+
+    let mut has_content_types = false;
+    let mut has_document = false;
+    for name in archive.file_names() {
+        match name {
+            "[Content_Types].xml" => has_content_types = true,
+            "word/document.xml" => has_document = true,
+            _ => {}
+        }
+    }
+
+    if has_content_types && has_document {
+        Ok(())
+    } else {
+        Err(INVALID_DOCX_ERROR.to_string())
+    }
+}
+
+/// Read a text entry from the .docx archive, or None if it isn't present
+fn read_archive_entry(
+    archive: &mut ZipArchive<fs::File>,
+    name: &str,
+) -> Result<Option<String>, String> {
+    let mut entry = match archive.by_name(name) {
+        Ok(entry) => entry,
+        Err(zip::result::ZipError::FileNotFound) => return Ok(None),
+        Err(e) => return Err(format!("Failed to read {}: {}", name, e)),
+    };
+
+    let mut content = String::new();
+    entry
+        .read_to_string(&mut content)
+        .map_err(|e| format!("Failed to read {}: {}", name, e))?;
+    Ok(Some(content))
+}
+
+/// Value of an attribute such as `w:val`, unescaped
+fn attribute_value(element: &BytesStart, name: &str) -> Option<String> {
+    element
+        .try_get_attribute(name)
+        .ok()
+        .flatten()
+        .and_then(|attr| attr.unescape_value().ok().map(|value| value.into_owned()))
+}
+
+/// Whether a toggle property like `<w:b/>` is switched on (`w:val="0"` turns it off)
+fn toggle_enabled(element: &BytesStart) -> bool {
+    !matches!(
+        attribute_value(element, "w:val").as_deref(),
+        Some("0") | Some("false") | Some("off") | Some("none")
+    )
+}
+
+/// Which list levels are bulleted, from word/numbering.xml
+#[derive(Default)]
+struct NumberingFormats {
+    /// numId -> abstractNumId
+    abstract_ids: HashMap<String, String>,
+    /// (abstractNumId, ilvl) -> numFmt is "bullet"
+    bullet_levels: HashMap<(String, u8), bool>,
+}
+
+impl NumberingFormats {
+    fn is_ordered(&self, num_id: &str, level: u8) -> bool {
+        self.abstract_ids
+            .get(num_id)
+            .and_then(|abstract_id| self.bullet_levels.get(&(abstract_id.clone(), level)))
+            .map(|is_bullet| !is_bullet)
+            .unwrap_or(false)
+    }
+}
+
+fn parse_numbering(xml: &str) -> Result<NumberingFormats, String> {
+    let mut reader = Reader::from_str(xml);
+    let mut formats = NumberingFormats::default();
+    let mut abstract_id: Option<String> = None;
+    let mut level: Option<u8> = None;
+    let mut num_id: Option<String> = None;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) | Ok(Event::Empty(e)) => match e.name().as_ref() {
+                b"w:abstractNum" => abstract_id = attribute_value(&e, "w:abstractNumId"),
+                b"w:lvl" => level = attribute_value(&e, "w:ilvl").and_then(|v| v.parse().ok()),
+                b"w:numFmt" => {
+                    if let (Some(abstract_id), Some(level)) = (&abstract_id, level) {
+                        let is_bullet = attribute_value(&e, "w:val").as_deref() == Some("bullet");
+                        formats
+                            .bullet_levels
+                            .insert((abstract_id.clone(), level), is_bullet);
+                    }
+                }
+                b"w:num" => num_id = attribute_value(&e, "w:numId"),
+                b"w:abstractNumId" => {
+                    if let (Some(num_id), Some(value)) = (&num_id, attribute_value(&e, "w:val")) {
+                        formats.abstract_ids.insert(num_id.clone(), value);
+                    }
+                }
+                _ => {}
+            },
+            Ok(Event::End(e)) => match e.name().as_ref() {
+                b"w:abstractNum" => abstract_id = None,
+                b"w:lvl" => level = None,
+                b"w:num" => num_id = None,
+                _ => {}
+            },
+            Ok(Event::Eof) => break,
+            Err(e) => return Err(format!("Failed to parse numbering.xml: {}", e)),
+            _ => {}
+        }
+    }
+
+    Ok(formats)
+}
+
+#[derive(Default, Clone, Copy, PartialEq)]
+struct RunFormat {
+    bold: bool,
+    italic: bool,
+    underline: bool,
+}
+
+#[derive(Default)]
+struct ParagraphState {
+    runs: Vec<(String, RunFormat)>,
+    heading_level: Option<u8>,
+    num_id: Option<String>,
+    list_level: u8,
+}
+
+/// Builds the plain text, HTML and formatting metadata as paragraphs are completed
+///
+/// Offsets are character (not byte) offsets into text_content, with paragraphs
+/// separated by a single newline. Empty paragraphs are dropped.
+#[derive(Default)]
+struct DocumentBuilder {
+    text_content: String,
+    char_count: usize,
+    html_content: String,
+    open_list: Option<bool>,
+    metadata: FormattingMetadata,
+}
+
+impl DocumentBuilder {
+    fn push_paragraph(&mut self, paragraph: ParagraphState, numbering: &NumberingFormats) {
+        let text: String = paragraph
+            .runs
+            .iter()
+            .map(|(text, _)| text.as_str())
+            .collect();
+        if text.trim().is_empty() {
+            return;
+        }
+
+        if !self.text_content.is_empty() {
+            self.text_content.push('\n');
+            self.char_count += 1;
+        }
+        let start = self.char_count;
+
+        let mut inline_html = String::new();
+        let mut offset = start;
+        for (run_text, format) in &paragraph.runs {
+            let run_end = offset + run_text.chars().count();
+            if format.bold {
+                push_range(&mut self.metadata.bold_ranges, offset, run_end, run_text);
+            }
+            if format.italic {
+                push_range(&mut self.metadata.italic_ranges, offset, run_end, run_text);
+            }
+            if format.underline {
+                push_range(
+                    &mut self.metadata.underline_ranges,
+                    offset,
+                    run_end,
+                    run_text,
+                );
+            }
+            inline_html.push_str(&run_html(run_text, *format));
+            offset = run_end;
+        }
+
+        self.text_content.push_str(&text);
+        self.char_count = offset;
+        self.metadata.paragraphs.push(Paragraph {
+            text: text.clone(),
+            start,
+            end: offset,
+        });
+
+        let list_item = paragraph
+            .num_id
+            .as_deref()
+            .filter(|num_id| *num_id != "0")
+            .map(|num_id| numbering.is_ordered(num_id, paragraph.list_level));
+
+        if self.open_list.is_some() && self.open_list != list_item {
+            self.close_list();
+        }
+
+        if let Some(ordered) = list_item {
+            if self.open_list.is_none() {
+                self.html_content
+                    .push_str(if ordered { "<ol>" } else { "<ul>" });
+                self.open_list = Some(ordered);
+            }
+            self.html_content
+                .push_str(&format!("<li>{}</li>", inline_html));
+            self.metadata.lists.push(ListItem {
+                item_type: if ordered { "ordered" } else { "unordered" }.to_string(),
+                text,
+                level: paragraph.list_level + 1,
+                position: start,
+            });
+        } else if let Some(level) = paragraph.heading_level {
+            self.html_content
+                .push_str(&format!("<h{0}>{1}</h{0}>", level, inline_html));
+            self.metadata.headings.push(Heading {
+                level,
+                text,
+                position: start,
+            });
+        } else {
+            self.html_content
+                .push_str(&format!("<p>{}</p>", inline_html));
+        }
+    }
+
+    fn close_list(&mut self) {
+        if let Some(ordered) = self.open_list.take() {
+            self.html_content
+                .push_str(if ordered { "</ol>" } else { "</ul>" });
+        }
+    }
+
+    fn finish(mut self) -> ParseResult {
+        self.close_list();
+        ParseResult {
+            text_content: self.text_content,
+            html_content: self.html_content,
+            formatting_metadata: self.metadata,
+        }
+    }
+}
+
+/// Add a range, extending the previous one when contiguous (e.g. bold, then bold italic)
+fn push_range(ranges: &mut Vec<Range>, start: usize, end: usize, text: &str) {
+    if let Some(last) = ranges.last_mut() {
+        if last.end == start {
+            last.end = end;
+            last.text.push_str(text);
+            return;
+        }
+    }
+    ranges.push(Range {
+        start,
+        end,
+        text: text.to_string(),
+    });
+}
+
+fn run_html(text: &str, format: RunFormat) -> String {
+    let mut html = text
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;");
+    if format.underline {
+        html = format!("<u>{}</u>", html);
+    }
+    if format.italic {
+        html = format!("<em>{}</em>", html);
+    }
+    if format.bold {
+        html = format!("<strong>{}</strong>", html);
+    }
+    html
+}
+
+/// Heading level for a paragraph style ID such as "Heading2"
+fn heading_level(style_id: &str) -> Option<u8> {
+    style_id
+        .strip_prefix("Heading")
+        .and_then(|level| level.parse::<u8>().ok())
+        .filter(|level| (1..=6).contains(level))
+}
+
+fn parse_document_xml(xml: &str, numbering: &NumberingFormats) -> Result<ParseResult, String> {
+    let mut reader = Reader::from_str(xml);
+    let mut builder = DocumentBuilder::default();
+    let mut paragraph: Option<ParagraphState> = None;
+    let mut run: Option<(String, RunFormat)> = None;
+    let mut in_paragraph_properties = false;
+    let mut in_text = false;
+
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|e| format!("Failed to parse document.xml: {}", e))?;
+
+        match event {
+            Event::Start(ref e) | Event::Empty(ref e) => {
+                let is_start = matches!(event, Event::Start(_));
+                match e.name().as_ref() {
+                    b"w:p" if is_start => paragraph = Some(ParagraphState::default()),
+                    b"w:pPr" if is_start => in_paragraph_properties = true,
+                    b"w:r" if is_start => run = Some((String::new(), RunFormat::default())),
+                    b"w:t" if is_start => in_text = true,
+                    b"w:pStyle" if in_paragraph_properties => {
+                        if let Some(paragraph) = paragraph.as_mut() {
+                            paragraph.heading_level = attribute_value(e, "w:val")
+                                .as_deref()
+                                .and_then(heading_level);
+                        }
+                    }
+                    b"w:numId" if in_paragraph_properties => {
+                        if let Some(paragraph) = paragraph.as_mut() {
+                            paragraph.num_id = attribute_value(e, "w:val");
+                        }
+                    }
+                    b"w:ilvl" if in_paragraph_properties => {
+                        if let Some(paragraph) = paragraph.as_mut() {
+                            paragraph.list_level = attribute_value(e, "w:val")
+                                .and_then(|v| v.parse().ok())
+                                .unwrap_or(0);
+                        }
+                    }
+                    // Run properties; the paragraph mark's own w:rPr (inside w:pPr) is ignored
+                    b"w:b" if !in_paragraph_properties => {
+                        if let Some((_, format)) = run.as_mut() {
+                            format.bold = toggle_enabled(e);
+                        }
+                    }
+                    b"w:i" if !in_paragraph_properties => {
+                        if let Some((_, format)) = run.as_mut() {
+                            format.italic = toggle_enabled(e);
+                        }
+                    }
+                    b"w:u" if !in_paragraph_properties => {
+                        if let Some((_, format)) = run.as_mut() {
+                            format.underline = toggle_enabled(e);
+                        }
+                    }
+                    b"w:tab" if !in_paragraph_properties => {
+                        if let Some((text, _)) = run.as_mut() {
+                            text.push('\t');
+                        }
+                    }
+                    b"w:br" | b"w:cr" => {
+                        if let Some((text, _)) = run.as_mut() {
+                            text.push('\n');
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Event::Text(ref e) if in_text => {
+                if let Some((text, _)) = run.as_mut() {
+                    let content = e
+                        .unescape()
+                        .map_err(|e| format!("Failed to parse document.xml: {}", e))?;
+                    text.push_str(&content);
+                }
+            }
+            Event::End(ref e) => match e.name().as_ref() {
+                b"w:t" => in_text = false,
+                b"w:pPr" => in_paragraph_properties = false,
+                b"w:r" => {
+                    if let (Some((text, format)), Some(paragraph)) =
+                        (run.take(), paragraph.as_mut())
+                    {
+                        // Word often splits one phrase across runs with identical formatting
+                        if !text.is_empty() {
+                            match paragraph.runs.last_mut() {
+                                Some((last_text, last_format)) if *last_format == format => {
+                                    last_text.push_str(&text)
+                                }
+                                _ => paragraph.runs.push((text, format)),
+                            }
+                        }
+                    }
+                }
+                b"w:p" => {
+                    if let Some(paragraph) = paragraph.take() {
+                        builder.push_paragraph(paragraph, numbering);
+                    }
+                }
+                _ => {}
+            },
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    Ok(builder.finish())
+}
+
+// ============================================================================
+// Document Generation
+// ============================================================================
+
+/// A paragraph to write: optional heading level or list (ordered, nesting level)
+#[derive(Default)]
+struct DocxBlock {
+    heading_level: Option<u8>,
+    list: Option<(bool, u8)>,
+    runs: Vec<(String, RunFormat)>,
+}
+
+impl DocxBlock {
+    fn push_text(&mut self, text: &str, format: RunFormat) {
+        match self.runs.last_mut() {
+            Some((last_text, last_format)) if *last_format == format => last_text.push_str(text),
+            _ => self.runs.push((text.to_string(), format)),
+        }
+    }
+
+    /// Trim whitespace at the block edges, returning None if nothing is left
+    fn finish(mut self) -> Option<Self> {
+        if let Some((text, _)) = self.runs.first_mut() {
+            *text = text.trim_start().to_string();
+        }
+        if let Some((text, _)) = self.runs.last_mut() {
+            *text = text.trim_end().to_string();
+        }
+        self.runs.retain(|(text, _)| !text.is_empty());
+        if self.runs.is_empty() {
+            None
+        } else {
+            Some(self)
+        }
+    }
+}
+
+fn decode_html_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(amp) = rest.find('&') {
+        decoded.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+
+        let entity = rest
+            .find(';')
+            .filter(|end| *end <= 10)
+            .map(|end| &rest[1..end]);
+        let character = entity.and_then(|entity| match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => entity
+                .strip_prefix("#x")
+                .or_else(|| entity.strip_prefix("#X"))
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                .and_then(char::from_u32),
+        });
+
+        match (entity, character) {
+            (Some(entity), Some(character)) => {
+                decoded.push(character);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Convert the formatter's HTML into paragraphs
+///
+/// Handles p/div, h1-h6, ul/ol/li, strong/b, em/i, u and br; other tags are
+/// dropped but their text kept. Content without any tags is treated as plain
+/// text with one paragraph per line.
+fn html_to_blocks(content: &str) -> Vec<DocxBlock> {
+    if !content.contains('<') {
+        return content
+            .lines()
+            .filter_map(|line| {
+                let mut block = DocxBlock::default();
+                block.push_text(&decode_html_entities(line), RunFormat::default());
+                block.finish()
+            })
+            .collect();
+    }
+
+    let mut blocks = Vec::new();
+    let mut current = DocxBlock::default();
+    let mut lists: Vec<bool> = Vec::new();
+    let (mut bold, mut italic, mut underline) = (0u32, 0u32, 0u32);
+    let mut rest = content;
+
+    while !rest.is_empty() {
+        let tag_start = rest.find('<').unwrap_or(rest.len());
+        if tag_start > 0 {
+            // Collapse whitespace the way a browser would, including across tags
+            let mut text = String::new();
+            for c in rest[..tag_start].chars() {
+                if !c.is_whitespace() {
+                    text.push(c);
+                } else if !text.ends_with(' ') {
+                    text.push(' ');
+                }
+            }
+            let after_space = current
+                .runs
+                .last()
+                .is_none_or(|(last, _)| last.ends_with(' ') || last.ends_with('\n'));
+            if after_space && text.starts_with(' ') {
+                text.remove(0);
+            }
+            if !text.is_empty() {
+                current.push_text(
+                    &decode_html_entities(&text),
+                    RunFormat {
+                        bold: bold > 0,
+                        italic: italic > 0,
+                        underline: underline > 0,
+                    },
+                );
+            }
+        }
+        rest = &rest[tag_start..];
+        if rest.is_empty() {
+            break;
+        }
+
+        let tag_end = match rest.find('>') {
+            Some(end) => end,
+            None => break,
+        };
+        let tag = &rest[1..tag_end];
+        rest = &rest[tag_end + 1..];
+
+        let closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or("")
+            .to_ascii_lowercase();
+
+        let counter = match name.as_str() {
+            "strong" | "b" => Some(&mut bold),
+            "em" | "i" => Some(&mut italic),
+            "u" => Some(&mut underline),
+            _ => None,
+        };
+        if let Some(counter) = counter {
+            *counter = if closing {
+                counter.saturating_sub(1)
+            } else {
+                *counter + 1
+            };
+            continue;
+        }
+
+        match name.as_str() {
+            "br" => current.push_text("\n", RunFormat::default()),
+            "ul" | "ol" if closing => {
+                lists.pop();
+            }
+            "ul" | "ol" => lists.push(name == "ol"),
+            "p" | "div" | "li" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                blocks.extend(std::mem::take(&mut current).finish());
+                if !closing {
+                    current.heading_level =
+                        name.strip_prefix('h').and_then(|level| level.parse().ok());
+                    if name == "li" {
+                        current.list = lists
+                            .last()
+                            .map(|ordered| (*ordered, lists.len() as u8 - 1));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    blocks.extend(current.finish());
+    blocks
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn document_xml(blocks: &[DocxBlock]) -> String {
+    let mut body = String::new();
+
+    for block in blocks {
+        body.push_str("<w:p>");
+        if let Some(level) = block.heading_level {
+            body.push_str(&format!(
+                r#"<w:pPr><w:pStyle w:val="Heading{}"/></w:pPr>"#,
+                level
+            ));
+        } else if let Some((ordered, level)) = block.list {
+            body.push_str(&format!(
+                r#"<w:pPr><w:numPr><w:ilvl w:val="{}"/><w:numId w:val="{}"/></w:numPr></w:pPr>"#,
+                level,
+                if ordered { 2 } else { 1 }
+            ));
+        }
+
+        for (text, format) in &block.runs {
+            let mut properties = String::new();
+            if format.bold {
+                properties.push_str("<w:b/>");
+            }
+            if format.italic {
+                properties.push_str("<w:i/>");
+            }
+            if format.underline {
+                properties.push_str(r#"<w:u w:val="single"/>"#);
+            }
+
+            body.push_str("<w:r>");
+            if !properties.is_empty() {
+                body.push_str(&format!("<w:rPr>{}</w:rPr>", properties));
+            }
+            for (i, line) in text.split('\n').enumerate() {
+                if i > 0 {
+                    body.push_str("<w:br/>");
+                }
+                if !line.is_empty() {
+                    body.push_str(&format!(
+                        r#"<w:t xml:space="preserve">{}</w:t>"#,
+                        escape_xml(line)
+                    ));
+                }
+            }
+            body.push_str("</w:r>");
+        }
+        body.push_str("</w:p>");
+    }
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><w:document xmlns:w="{}"><w:body>{}<w:sectPr/></w:body></w:document>"#,
+        WORDML_NAMESPACE, body
+    )
+}
+
+const WORDML_NAMESPACE: &str = "http://schemas.openxmlformats.org/wordprocessingml/2006/main";
+
+const CONTENT_TYPES_XML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/word/document.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"/><Override PartName="/word/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.styles+xml"/><Override PartName="/word/numbering.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.numbering+xml"/></Types>"#;
+
+const PACKAGE_RELS_XML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="word/document.xml"/></Relationships>"#;
+
+const DOCUMENT_RELS_XML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles" Target="styles.xml"/><Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/numbering" Target="numbering.xml"/></Relationships>"#;
+
+/// Normal plus Heading1-6, largest first
+fn styles_xml() -> String {
+    let headings: String = (1..=6u8)
+        .map(|level| {
+            format!(
+                r#"<w:style w:type="paragraph" w:styleId="Heading{0}"><w:name w:val="heading {0}"/><w:basedOn w:val="Normal"/><w:next w:val="Normal"/><w:pPr><w:keepNext/><w:outlineLvl w:val="{1}"/></w:pPr><w:rPr><w:b/><w:sz w:val="{2}"/></w:rPr></w:style>"#,
+                level,
+                level - 1,
+                40 - (level as u32 - 1) * 4
+            )
+        })
+        .collect();
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><w:styles xmlns:w="{}"><w:style w:type="paragraph" w:default="1" w:styleId="Normal"><w:name w:val="Normal"/><w:rPr><w:sz w:val="24"/></w:rPr></w:style>{}</w:styles>"#,
+        WORDML_NAMESPACE, headings
+    )
+}
+
+/// numId 1 is a bulleted list and numId 2 a numbered one, nine levels each
+fn numbering_xml() -> String {
+    let levels = |bullet: bool| -> String {
+        (0..9u32)
+            .map(|level| {
+                let (format, text) = if bullet {
+                    ("bullet", "•".to_string())
+                } else {
+                    ("decimal", format!("%{}.", level + 1))
+                };
+                format!(
+                    r#"<w:lvl w:ilvl="{0}"><w:start w:val="1"/><w:numFmt w:val="{1}"/><w:lvlText w:val="{2}"/><w:pPr><w:ind w:left="{3}" w:hanging="360"/></w:pPr></w:lvl>"#,
+                    level,
+                    format,
+                    text,
+                    720 * (level + 1)
+                )
+            })
+            .collect()
+    };
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><w:numbering xmlns:w="{}"><w:abstractNum w:abstractNumId="0">{}</w:abstractNum><w:abstractNum w:abstractNumId="1">{}</w:abstractNum><w:num w:numId="1"><w:abstractNumId w:val="0"/></w:num><w:num w:numId="2"><w:abstractNumId w:val="1"/></w:num></w:numbering>"#,
+        WORDML_NAMESPACE,
+        levels(true),
+        levels(false)
+    )
+}
+
+/// Write HTML (or plain text) content to a new .docx at output_path
+fn write_docx(content: &str, output_path: &Path) -> Result<(), String> {
+    let blocks = html_to_blocks(content);
+
+    let file = fs::File::create(output_path)
+        .map_err(|e| format!("Failed to create {}: {}", output_path.display(), e))?;
+    let mut zip = ZipWriter::new(file);
+
+    let parts = [
+        ("[Content_Types].xml", CONTENT_TYPES_XML.to_string()),
+        ("_rels/.rels", PACKAGE_RELS_XML.to_string()),
+        (
+            "word/_rels/document.xml.rels",
+            DOCUMENT_RELS_XML.to_string(),
+        ),
+        ("word/document.xml", document_xml(&blocks)),
+        ("word/styles.xml", styles_xml()),
+        ("word/numbering.xml", numbering_xml()),
+    ];
+
+    for (name, xml) in parts {
+        zip.start_file(name, FileOptions::default())
+            .map_err(|e| format!("Failed to write {}: {}", name, e))?;
+        zip.write_all(xml.as_bytes())
+            .map_err(|e| format!("Failed to write {}: {}", name, e))?;
+    }
+
+    zip.finish()
+        .map_err(|e| format!("Failed to finish .docx file: {}", e))?;
+    Ok(())
+}
+
+/// Pick a .docx path in output_dir that doesn't overwrite an existing file
+fn resolve_output_path(output_dir: &Path, default_filename: &str) -> PathBuf {
+    let stem = Path::new(default_filename)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .filter(|stem| !stem.trim().is_empty())
+        .unwrap_or_else(|| "script".to_string());
+
+    let mut path = output_dir.join(format!("{}.docx", stem));
+    let mut copy = 1;
+    while path.exists() {
+        path = output_dir.join(format!("{} ({}).docx", stem, copy));
+        copy += 1;
+    }
+    path
 }
 
 /**
  * Generate and save a .docx file from content
  * FR-020: Download formatted script
+ *
+ * Content is the formatter's HTML (or plain text). The file is written to
+ * output_dir when given, otherwise to a "scripts" folder in the app data dir.
  */
 #[command]
 pub fn generate_docx_file(
-    _content: String,
+    app: AppHandle,
+    content: String,
     default_filename: String,
+    output_dir: Option<String>,
 ) -> Result<DownloadPath, String> {
-    // NOTE: Actual .docx generation is done in frontend using docx npm package
-    // This command is a placeholder for future backend generation if needed
+    let output_dir = match output_dir {
+        Some(dir) => PathBuf::from(dir),
+        None => app
+            .path()
+            .app_data_dir()
+            .map_err(|e| format!("Failed to get app data dir: {}", e))?
+            .join("scripts"),
+    };
+    fs::create_dir_all(&output_dir)
+        .map_err(|e| format!("Failed to create output directory: {}", e))?;
+
+    let output_path = resolve_output_path(&output_dir, &default_filename);
+    write_docx(&content, &output_path)?;
 
-    // For now, just return a path suggestion
     Ok(DownloadPath {
-        path: default_filename,
+        path: output_path.to_string_lossy().to_string(),
     })
 }
 
@@ -141,7 +941,7 @@ pub fn generate_docx_file(
  * Validate .docx file structure and readability
  */
 #[command]
-pub fn validate_docx_file(file_path: String) -> Result<bool, String> {
+pub fn validate_docx_file(file_path: String, max_size_mb: Option<u64>) -> Result<bool, String> {
     let path = Path::new(&file_path);
 
     if !path.exists() {
@@ -156,21 +956,19 @@ pub fn validate_docx_file(file_path: String) -> Result<bool, String> {
     }
 
     // Check file size
-    let metadata =
-        fs::metadata(path).map_err(|e| format!("Failed to read file metadata: {}", e))?;
-
-    const ONE_GB: u64 = 1024 * 1024 * 1024;
-    if metadata.len() > ONE_GB {
-        return Err(format!(
-            "File exceeds 1GB size limit ({} bytes)",
-            metadata.len()
-        ));
-    }
+    let metadata = check_file_size(path, max_size_mb)?;
 
     // Check if file is readable
     if metadata.permissions().readonly() {
         return Err("File is read-only and cannot be accessed".to_string());
     }
 
+    // A .docx is a ZIP (OOXML) container; a renamed text file fails here
+    check_ooxml_container(path)?;
+
     Ok(true)
 }
+
+#[cfg(test)]
+#[path = "../tests/docx_test.rs"]
+mod docx_test;
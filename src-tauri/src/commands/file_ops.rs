@@ -1,22 +1,81 @@
 use crate::utils::file_copy::copy_file_with_overall_progress;
+use serde::Serialize;
 use std::fs;
 use std::path::Path;
 use std::sync::Arc;
 use std::thread;
 use tauri::{command, AppHandle, Emitter};
 
+/// A file move_files couldn't copy, or whose original it couldn't delete
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveFailure {
+    pub source: String,
+    pub error: String,
+}
+
+/// Payload of the `move_result` and `move_complete` events
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveReport {
+    /// Destination paths of every file that was copied
+    pub copied_files: Vec<String>,
+    /// Sources removed after their copy was verified
+    pub deleted_sources: Vec<String>,
+    /// Sources left in place because copying, verifying or deleting them failed
+    pub retained_sources: Vec<String>,
+    pub failures: Vec<MoveFailure>,
+}
+
+/// Delete a source file once its copy is confirmed to be the same size
+pub(crate) fn remove_verified_source(source: &Path, copy: &Path) -> Result<(), String> {
+    let source_size = fs::metadata(source)
+        .map_err(|e| format!("Failed to read {}: {}", source.display(), e))?
+        .len();
+    let copy_size = fs::metadata(copy)
+        .map_err(|e| format!("Failed to read {}: {}", copy.display(), e))?
+        .len();
+
+    if source_size != copy_size {
+        return Err(format!(
+            "Copy of {} is {} bytes but the original is {} bytes",
+            source.display(),
+            copy_size,
+            source_size
+        ));
+    }
+
+    fs::remove_file(source).map_err(|e| format!("Failed to delete {}: {}", source.display(), e))
+}
+
+/// Copy footage into camera folders under `base_dest`
+///
+/// With `delete_source_after_copy`, each original is deleted once its copy
+/// is verified, and a `move_complete` event reports which sources were
+/// deleted or kept. `copy_complete` is emitted either way, followed by a
+/// `move_result` event listing every file that failed. Copies are always
+/// synced to disk before their original is deleted. `verify` checks each
+/// copy's size and checksum before it counts as copied, at the cost of
+/// reading it back.
 #[command]
 pub fn move_files(
     files: Vec<(String, u32)>,
     base_dest: String,
+    delete_source_after_copy: Option<bool>,
+    verify: Option<bool>,
     app_handle: AppHandle,
 ) -> Result<(), String> {
     let app_handle = Arc::new(app_handle); // Allow sharing across threads
     let base_dest = Arc::new(base_dest); // Shared reference
+    let delete_sources = delete_source_after_copy.unwrap_or(false);
+    let verify = verify.unwrap_or(false);
 
     // Run file moving in a separate thread
     thread::spawn(move || {
         let mut moved_files = Vec::new();
+        let mut deleted_sources = Vec::new();
+        let mut retained_sources = Vec::new();
+        let mut failures = Vec::new();
         let total_files = files.len();
 
         for (index, (file_path, camera_number)) in files.iter().enumerate() {
@@ -28,6 +87,11 @@ pub fn move_files(
             if !camera_folder.exists() {
                 if let Err(e) = fs::create_dir_all(&camera_folder) {
                     eprintln!("Failed to create camera folder {}: {}", camera_number, e);
+                    retained_sources.push(file_path.clone());
+                    failures.push(MoveFailure {
+                        source: file_path.clone(),
+                        error: format!("Failed to create camera folder {}: {}", camera_number, e),
+                    });
                     continue;
                 }
             }
@@ -41,16 +105,49 @@ pub fn move_files(
                 &app_handle,
                 index,
                 total_files,
+                delete_sources,
+                verify,
             ) {
                 eprintln!("Failed to copy file {}: {}", file_path, e);
+                retained_sources.push(file_path.clone());
+                failures.push(MoveFailure {
+                    source: file_path.clone(),
+                    error: format!("Failed to copy file: {}", e),
+                });
                 continue;
             }
 
             moved_files.push(dest_file_path.to_string_lossy().to_string());
+
+            if delete_sources {
+                match remove_verified_source(src_path, &dest_file_path) {
+                    Ok(()) => deleted_sources.push(file_path.clone()),
+                    Err(e) => {
+                        eprintln!("Kept original {}: {}", file_path, e);
+                        retained_sources.push(file_path.clone());
+                        failures.push(MoveFailure {
+                            source: file_path.clone(),
+                            error: e,
+                        });
+                    }
+                }
+            }
         }
 
         // Emit completion event when done
-        let _ = app_handle.emit("copy_complete", moved_files);
+        let _ = app_handle.emit("copy_complete", moved_files.clone());
+
+        let report = MoveReport {
+            copied_files: moved_files,
+            deleted_sources,
+            retained_sources,
+            failures,
+        };
+
+        if delete_sources {
+            let _ = app_handle.emit("move_complete", report.clone());
+        }
+        let _ = app_handle.emit("move_result", report);
     });
 
     Ok(()) // Return immediately so UI remains responsive
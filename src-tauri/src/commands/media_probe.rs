@@ -0,0 +1,342 @@
+/**
+ * Media Probe Commands
+ * Purpose: Technical metadata (resolution, codec, frame rate, duration) for footage
+ * files via FFmpeg's ffprobe, for QC without opening Premiere
+ */
+
+use crate::baker::{baker_scan_current_files, FileInfo};
+use futures_util::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tauri::{command, AppHandle, Emitter};
+
+/// Number of ffprobe processes allowed to run at once when probing a project
+const PROBE_CONCURRENCY: usize = 4;
+
+/// Frame rates closer than this are treated as the same (e.g. 29.97 vs 29.970)
+const FPS_TOLERANCE: f64 = 0.01;
+
+// ============================================================================
+// Type Definitions
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaInfo {
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub codec: Option<String>,
+    pub fps: Option<f64>,
+    pub duration_seconds: Option<f64>,
+    pub audio_channels: Option<u32>,
+}
+
+/// A frame-rate inconsistency found in a project's footage
+///
+/// `kind` is "within_camera" when a clip differs from the rest of its camera
+/// folder, or "across_cameras" when a camera's dominant rate differs from the
+/// project's (the case that causes multicam sync drift).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FramerateWarning {
+    pub kind: String,
+    pub camera: i32,
+    pub dominant_fps: f64,
+    pub fps: f64,
+    pub file_path: Option<String>,
+    pub message: String,
+}
+
+/// Progress payload for the baker_probe_progress event
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProbeProgressEvent {
+    pub project_path: String,
+    pub files_probed: usize,
+    pub total_files: usize,
+    pub current_file: String,
+}
+
+// ============================================================================
+// FFmpeg Tool Discovery
+// ============================================================================
+
+/// Locations checked when a tool is not on PATH. GUI apps on macOS don't
+/// inherit the shell PATH, so Homebrew installs would otherwise be missed.
+const FFMPEG_FALLBACK_DIRS: &[&str] = &["/opt/homebrew/bin", "/usr/local/bin", "/usr/bin"];
+
+/// Find an FFmpeg tool (`ffprobe` or `ffmpeg`) that can actually be executed
+pub(crate) fn find_ffmpeg_tool(tool: &str) -> Result<PathBuf, String> {
+    let runs = |candidate: &Path| {
+        Command::new(candidate)
+            .arg("-version")
+            .output()
+            .map(|output| output.status.success())
+            .unwrap_or(false)
+    };
+
+    let on_path = PathBuf::from(tool);
+    if runs(on_path.as_path()) {
+        return Ok(on_path);
+    }
+
+    FFMPEG_FALLBACK_DIRS
+        .iter()
+        .map(|dir| Path::new(dir).join(tool))
+        .find(|candidate| candidate.exists() && runs(candidate.as_path()))
+        .ok_or_else(|| {
+            format!(
+                "{} not found. Install FFmpeg (e.g. `brew install ffmpeg`) and make sure {} is on your PATH",
+                tool, tool
+            )
+        })
+}
+
+// ============================================================================
+// Probing
+// ============================================================================
+
+/// Parse an ffprobe rational such as "30000/1001" into frames per second
+fn parse_frame_rate(rate: &str) -> Option<f64> {
+    let (num, den) = rate.split_once('/').unwrap_or((rate, "1"));
+    let num: f64 = num.trim().parse().ok()?;
+    let den: f64 = den.trim().parse().ok()?;
+
+    if num <= 0.0 || den <= 0.0 {
+        return None;
+    }
+
+    Some(num / den)
+}
+
+/// Run ffprobe against a single file and map its JSON output into MediaInfo
+pub(crate) fn probe_media_file(ffprobe: &Path, file_path: &Path) -> Result<MediaInfo, String> {
+    let output = Command::new(ffprobe)
+        .args([
+            "-v",
+            "error",
+            "-print_format",
+            "json",
+            "-show_streams",
+            "-show_format",
+        ])
+        .arg(file_path)
+        .output()
+        .map_err(|e| format!("Failed to run ffprobe: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "ffprobe could not read {}: {}",
+            file_path.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    let probe: Value = serde_json::from_slice(&output.stdout)
+        .map_err(|e| format!("Failed to parse ffprobe output: {}", e))?;
+
+    let streams = probe["streams"].as_array().cloned().unwrap_or_default();
+    let video = streams
+        .iter()
+        .find(|stream| stream["codec_type"].as_str() == Some("video"));
+    let audio = streams
+        .iter()
+        .find(|stream| stream["codec_type"].as_str() == Some("audio"));
+
+    let fps = video.and_then(|stream| {
+        stream["avg_frame_rate"]
+            .as_str()
+            .and_then(parse_frame_rate)
+            .or_else(|| stream["r_frame_rate"].as_str().and_then(parse_frame_rate))
+    });
+
+    let duration_seconds = probe["format"]["duration"]
+        .as_str()
+        .and_then(|d| d.parse::<f64>().ok());
+
+    Ok(MediaInfo {
+        width: video.and_then(|s| s["width"].as_u64()).map(|w| w as u32),
+        height: video.and_then(|s| s["height"].as_u64()).map(|h| h as u32),
+        codec: video
+            .or(audio)
+            .and_then(|s| s["codec_name"].as_str())
+            .map(|c| c.to_string()),
+        fps,
+        duration_seconds,
+        audio_channels: audio.and_then(|s| s["channels"].as_u64()).map(|c| c as u32),
+    })
+}
+
+// ============================================================================
+// Commands
+// ============================================================================
+
+/// Probe a single footage file for its technical metadata
+#[command]
+pub async fn baker_probe_media(file_path: String) -> Result<MediaInfo, String> {
+    let path = PathBuf::from(&file_path);
+
+    if !path.is_file() {
+        return Err(format!("File does not exist: {}", file_path));
+    }
+
+    tokio::task::spawn_blocking(move || {
+        let ffprobe = find_ffmpeg_tool("ffprobe")?;
+        probe_media_file(&ffprobe, &path)
+    })
+    .await
+    .map_err(|e| format!("Probe task failed: {}", e))?
+}
+
+/// Probe every camera file in a project, a few files at a time
+///
+/// Results keep the camera/name order from baker_scan_current_files. Files ffprobe
+/// can't read (sidecars, proxies in odd formats) are logged and left out.
+#[command]
+pub async fn baker_probe_project_media(
+    project_path: String,
+    app_handle: AppHandle,
+) -> Result<Vec<(FileInfo, MediaInfo)>, String> {
+    let files = baker_scan_current_files(project_path.clone()).await?;
+
+    let ffprobe = tokio::task::spawn_blocking(|| find_ffmpeg_tool("ffprobe"))
+        .await
+        .map_err(|e| format!("Probe task failed: {}", e))??;
+
+    let project_root = PathBuf::from(&project_path);
+    let total_files = files.len();
+
+    let mut probes = stream::iter(files.into_iter().map(|file| {
+        let ffprobe = ffprobe.clone();
+        let full_path = project_root.join(&file.path);
+
+        async move {
+            let result =
+                tokio::task::spawn_blocking(move || probe_media_file(&ffprobe, &full_path))
+                    .await
+                    .map_err(|e| format!("Probe task failed: {}", e))
+                    .and_then(|probe| probe);
+            (file, result)
+        }
+    }))
+    .buffered(PROBE_CONCURRENCY);
+
+    let mut results = Vec::with_capacity(total_files);
+    let mut files_probed = 0;
+
+    while let Some((file, result)) = probes.next().await {
+        files_probed += 1;
+
+        let _ = app_handle.emit(
+            "baker_probe_progress",
+            ProbeProgressEvent {
+                project_path: project_path.clone(),
+                files_probed,
+                total_files,
+                current_file: file.path.clone(),
+            },
+        );
+
+        match result {
+            Ok(info) => results.push((file, info)),
+            Err(e) => println!("[Baker] Skipping {} during probe: {}", file.path, e),
+        }
+    }
+
+    Ok(results)
+}
+
+/// Most common frame rate in a set, grouping rates within FPS_TOLERANCE
+fn dominant_fps(rates: &[f64]) -> Option<f64> {
+    let mut buckets: Vec<(f64, usize)> = Vec::new();
+
+    for &rate in rates {
+        match buckets
+            .iter_mut()
+            .find(|(bucket, _)| (*bucket - rate).abs() < FPS_TOLERANCE)
+        {
+            Some((_, count)) => *count += 1,
+            None => buckets.push((rate, 1)),
+        }
+    }
+
+    buckets
+        .into_iter()
+        .max_by_key(|(_, count)| *count)
+        .map(|(rate, _)| rate)
+}
+
+/// Flag clips whose frame rate differs from their camera, and cameras whose
+/// dominant frame rate differs from the rest of the project
+#[command]
+pub async fn baker_check_framerate_consistency(
+    project_path: String,
+    app_handle: AppHandle,
+) -> Result<Vec<FramerateWarning>, String> {
+    let probed = baker_probe_project_media(project_path, app_handle).await?;
+
+    // Group frame rates by camera, ignoring audio-only files
+    let mut cameras: Vec<(i32, Vec<(String, f64)>)> = Vec::new();
+    for (file, info) in probed {
+        let Some(fps) = info.fps else { continue };
+
+        match cameras
+            .iter_mut()
+            .find(|(camera, _)| *camera == file.camera)
+        {
+            Some((_, clips)) => clips.push((file.path, fps)),
+            None => cameras.push((file.camera, vec![(file.path, fps)])),
+        }
+    }
+    cameras.sort_by_key(|(camera, _)| *camera);
+
+    let mut warnings = Vec::new();
+    let mut camera_rates = Vec::new();
+
+    for (camera, clips) in &cameras {
+        let rates: Vec<f64> = clips.iter().map(|(_, fps)| *fps).collect();
+        let Some(camera_fps) = dominant_fps(&rates) else {
+            continue;
+        };
+        camera_rates.push((*camera, camera_fps));
+
+        for (file_path, fps) in clips {
+            if (fps - camera_fps).abs() >= FPS_TOLERANCE {
+                warnings.push(FramerateWarning {
+                    kind: "within_camera".to_string(),
+                    camera: *camera,
+                    dominant_fps: camera_fps,
+                    fps: *fps,
+                    file_path: Some(file_path.clone()),
+                    message: format!(
+                        "{} is {:.3}fps but Camera {} is mostly {:.3}fps",
+                        file_path, fps, camera, camera_fps
+                    ),
+                });
+            }
+        }
+    }
+
+    let all_camera_rates: Vec<f64> = camera_rates.iter().map(|(_, fps)| *fps).collect();
+    if let Some(project_fps) = dominant_fps(&all_camera_rates) {
+        for (camera, camera_fps) in &camera_rates {
+            if (camera_fps - project_fps).abs() >= FPS_TOLERANCE {
+                warnings.push(FramerateWarning {
+                    kind: "across_cameras".to_string(),
+                    camera: *camera,
+                    dominant_fps: project_fps,
+                    fps: *camera_fps,
+                    file_path: None,
+                    message: format!(
+                        "Camera {} was shot at {:.3}fps but the other cameras are {:.3}fps",
+                        camera, camera_fps, project_fps
+                    ),
+                });
+            }
+        }
+    }
+
+    Ok(warnings)
+}
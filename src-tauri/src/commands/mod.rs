@@ -1,7 +1,12 @@
 pub mod ai_provider;
+pub mod archive;
 pub mod auth;
+pub mod cache;
+pub mod config;
+pub mod contact_sheet;
 pub mod docx;
 pub mod file_ops;
+pub mod media_probe;
 pub mod plugins;
 pub mod premiere;
 pub mod rag;
@@ -9,9 +14,14 @@ pub mod sprout_upload;
 pub mod system;
 
 pub use ai_provider::*;
+pub use archive::*;
 pub use auth::*;
+pub use cache::*;
+pub use config::*;
+pub use contact_sheet::*;
 pub use docx::*;
 pub use file_ops::*;
+pub use media_probe::*;
 pub use plugins::*;
 pub use premiere::*;
 pub use rag::*;
@@ -7,14 +7,22 @@
 
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use tauri::{AppHandle, Manager};
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use tauri::{AppHandle, Manager, State};
 use zip::ZipArchive;
 
+use crate::state::ConfigState;
+
 #[cfg(any(target_os = "macos", target_os = "windows"))]
 use std::process::Command;
 
+/// CSXS versions used by the Premiere Pro releases we support
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+const CSXS_VERSIONS: std::ops::RangeInclusive<u32> = 9..=12;
+
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct PluginInfo {
@@ -36,6 +44,36 @@ pub struct InstallResult {
     pub message: String,
     pub plugin_name: String,
     pub installed_path: String,
+    /// Where the previous installation was moved to, if one existed
+    pub backup_path: Option<String>,
+    /// Older backups deleted to stay within the retention limit
+    pub removed_backups: Vec<String>,
+}
+
+/// An installed copy of a plugin, read from its CSXS/manifest.xml
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InstalledPluginInfo {
+    pub plugin_name: String,
+    /// ExtensionBundleVersion from the manifest; None if it couldn't be read
+    pub version: Option<String>,
+    pub installed_path: String,
+    /// "user" or "system" CEP extensions directory
+    pub location: String,
+}
+
+/// Any extension found in a CEP extensions directory, bundled or third-party
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InstalledPlugin {
+    /// ExtensionBundleName from the manifest, or the folder name if it has none
+    pub name: String,
+    pub directory_name: String,
+    pub bundle_id: Option<String>,
+    pub version: Option<String>,
+    pub installed_path: String,
+    /// "user" or "system" CEP extensions directory
+    pub location: String,
 }
 
 /// Get CEP extensions directory path
@@ -129,12 +167,9 @@ fn check_plugin_installed_internal(plugin_name: &str) -> Result<bool, String> {
     }
 }
 
-/// Get list of available plugins from assets
-///
-/// Returns hardcoded list of bundled plugins with their metadata
-#[tauri::command]
-pub async fn get_available_plugins() -> Result<Vec<PluginInfo>, String> {
-    let plugins = vec![
+/// Hardcoded list of bundled plugins with their metadata
+fn bundled_plugins() -> Vec<PluginInfo> {
+    vec![
         PluginInfo {
             name: "BreadcrumbsPremiere".to_string(),
             display_name: "Breadcrumbs Premiere".to_string(),
@@ -166,11 +201,341 @@ pub async fn get_available_plugins() -> Result<Vec<PluginInfo>, String> {
             ],
             icon: "/icons/plugins/logo.svg".to_string(),
         },
-    ];
+    ]
+}
+
+/// CEP extensions directories Premiere loads from, user-level first
+fn cep_extension_locations() -> Result<Vec<(&'static str, PathBuf)>, String> {
+    #[cfg(target_os = "macos")]
+    {
+        let mut locations = Vec::new();
+        if let Some(home) = dirs::home_dir() {
+            locations.push((
+                "user",
+                home.join("Library/Application Support/Adobe/CEP/extensions"),
+            ));
+        }
+        locations.push((
+            "system",
+            PathBuf::from("/Library/Application Support/Adobe/CEP/extensions"),
+        ));
+        Ok(locations)
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        let mut locations = Vec::new();
+        if let Some(data_dir) = dirs::data_dir() {
+            locations.push(("user", data_dir.join("Adobe/CEP/extensions")));
+        }
+        locations.push((
+            "system",
+            PathBuf::from("C:/Program Files/Common Files/Adobe/CEP/extensions"),
+        ));
+        Ok(locations)
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    {
+        Err("Unsupported operating system".to_string())
+    }
+}
+
+/// Identifying details from a CEP manifest
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct ManifestDetails {
+    pub bundle_id: Option<String>,
+    pub bundle_name: Option<String>,
+    pub version: Option<String>,
+}
+
+/// Read the bundle id, name and version from a CEP manifest
+///
+/// Uses the ExtensionBundle* attributes on the root element, falling back to
+/// the Id and Version of the first Extension entry.
+pub(crate) fn read_manifest_details(manifest_xml: &str) -> ManifestDetails {
+    let mut reader = Reader::from_str(manifest_xml);
+    let mut details = ManifestDetails::default();
+    let mut extension_id = None;
+    let mut extension_version = None;
+    let mut seen_extension = false;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) | Ok(Event::Empty(e)) => {
+                let attribute = |name: &str| {
+                    e.try_get_attribute(name)
+                        .ok()
+                        .flatten()
+                        .and_then(|attr| attr.unescape_value().ok().map(|v| v.into_owned()))
+                };
+                match e.name().as_ref() {
+                    b"ExtensionManifest" => {
+                        details.bundle_id = attribute("ExtensionBundleId");
+                        details.bundle_name = attribute("ExtensionBundleName");
+                        details.version = attribute("ExtensionBundleVersion");
+                    }
+                    b"Extension" if !seen_extension => {
+                        seen_extension = true;
+                        extension_id = attribute("Id");
+                        extension_version = attribute("Version");
+                    }
+                    _ => {}
+                }
+            }
+            Ok(Event::Eof) | Err(_) => break,
+            _ => {}
+        }
+    }
+
+    details.bundle_id = details.bundle_id.or(extension_id);
+    details.version = details.version.or(extension_version);
+    details
+}
+
+/// Read the bundle version from a CEP manifest
+pub(crate) fn read_manifest_version(manifest_xml: &str) -> Option<String> {
+    read_manifest_details(manifest_xml).version
+}
+
+/// Get version, path and location of an installed plugin
+///
+/// Returns None when the plugin isn't installed. If it's in both directories,
+/// the user-level copy (the one install_plugin manages) is reported.
+#[tauri::command]
+pub async fn get_installed_plugin_info(
+    plugin_name: String,
+) -> Result<Option<InstalledPluginInfo>, String> {
+    for (location, dir) in cep_extension_locations()? {
+        let plugin_dir = dir.join(&plugin_name);
+        let manifest_path = plugin_dir.join("CSXS/manifest.xml");
+        if !manifest_path.exists() {
+            continue;
+        }
+
+        let version = fs::read_to_string(&manifest_path)
+            .ok()
+            .and_then(|xml| read_manifest_version(&xml));
+
+        return Ok(Some(InstalledPluginInfo {
+            plugin_name,
+            version,
+            installed_path: plugin_dir.to_string_lossy().to_string(),
+            location: location.to_string(),
+        }));
+    }
+
+    Ok(None)
+}
+
+/// List every extension in the user and system CEP directories
+///
+/// Folders without a CSXS/manifest.xml aren't extensions and are skipped.
+/// Results are grouped by location (user first) and sorted by folder name.
+#[tauri::command]
+pub async fn list_installed_plugins() -> Result<Vec<InstalledPlugin>, String> {
+    let mut plugins = Vec::new();
+
+    for (location, dir) in cep_extension_locations()? {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+
+        let mut found: Vec<InstalledPlugin> = entries
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                let path = entry.path();
+                let manifest = fs::read_to_string(path.join("CSXS/manifest.xml")).ok()?;
+                let details = read_manifest_details(&manifest);
+                let directory_name = entry.file_name().to_string_lossy().to_string();
+
+                Some(InstalledPlugin {
+                    name: details
+                        .bundle_name
+                        .unwrap_or_else(|| directory_name.clone()),
+                    directory_name,
+                    bundle_id: details.bundle_id,
+                    version: details.version,
+                    installed_path: path.to_string_lossy().to_string(),
+                    location: location.to_string(),
+                })
+            })
+            .collect();
+
+        found.sort_by(|a, b| a.directory_name.cmp(&b.directory_name));
+        plugins.extend(found);
+    }
 
     Ok(plugins)
 }
 
+/// Get list of available plugins from assets
+///
+/// Returns hardcoded list of bundled plugins with their metadata
+#[tauri::command]
+pub async fn get_available_plugins() -> Result<Vec<PluginInfo>, String> {
+    Ok(bundled_plugins())
+}
+
+/// Check a ZXP before anything is extracted
+///
+/// The file must match the expected size (when known) and be a ZIP archive
+/// containing CSXS/manifest.xml with no entries that point outside the plugin
+/// folder, so a corrupt, truncated or malicious bundle fails here instead of
+/// leaving a half-extracted plugin in the CEP directory.
+pub(crate) fn verify_plugin_archive(path: &Path, expected_size: Option<u64>) -> Result<(), String> {
+    let actual_size = fs::metadata(path)
+        .map_err(|e| format!("Failed to read plugin file: {}", e))?
+        .len();
+
+    if let Some(expected_size) = expected_size {
+        if actual_size != expected_size {
+            return Err(format!(
+                "Plugin file is corrupt or incomplete: expected {} bytes but found {}",
+                expected_size, actual_size
+            ));
+        }
+    }
+
+    let file = fs::File::open(path).map_err(|e| format!("Failed to open plugin file: {}", e))?;
+    let mut archive = ZipArchive::new(file)
+        .map_err(|e| format!("Plugin file is not a valid ZXP archive: {}", e))?;
+
+    if !archive.file_names().any(|name| name == "CSXS/manifest.xml") {
+        return Err("Invalid plugin structure: the archive has no CSXS/manifest.xml".to_string());
+    }
+
+    for index in 0..archive.len() {
+        let entry = archive
+            .by_index(index)
+            .map_err(|e| format!("Failed to read plugin archive: {}", e))?;
+        if entry.enclosed_name().is_none() {
+            return Err(format!(
+                "Plugin archive contains an unsafe path: {}",
+                entry.name()
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Extract a ZXP into target_dir, refusing any entry that would land outside it
+///
+/// Entry names with `..` or absolute paths are rejected, and each destination's
+/// parent is canonicalized so a symlinked folder can't redirect writes either.
+pub(crate) fn extract_plugin_archive(archive_path: &Path, target_dir: &Path) -> Result<(), String> {
+    let file =
+        fs::File::open(archive_path).map_err(|e| format!("Failed to open plugin file: {}", e))?;
+    let mut archive =
+        ZipArchive::new(file).map_err(|e| format!("Failed to read plugin archive: {}", e))?;
+
+    let canonical_target = target_dir
+        .canonicalize()
+        .map_err(|e| format!("Failed to resolve plugin directory: {}", e))?;
+
+    for index in 0..archive.len() {
+        let mut entry = archive
+            .by_index(index)
+            .map_err(|e| format!("Failed to read plugin archive: {}", e))?;
+
+        let relative_path = entry
+            .enclosed_name()
+            .map(Path::to_path_buf)
+            .ok_or_else(|| format!("Plugin archive contains an unsafe path: {}", entry.name()))?;
+        let destination = canonical_target.join(&relative_path);
+
+        let parent = if entry.is_dir() {
+            destination.as_path()
+        } else {
+            destination.parent().unwrap_or(&canonical_target)
+        };
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+
+        let canonical_parent = parent
+            .canonicalize()
+            .map_err(|e| format!("Failed to resolve {}: {}", parent.display(), e))?;
+        if !canonical_parent.starts_with(&canonical_target) {
+            return Err(format!(
+                "Plugin archive entry escapes the plugin directory: {}",
+                entry.name()
+            ));
+        }
+
+        if entry.is_dir() {
+            continue;
+        }
+
+        let mut output = fs::File::create(&destination)
+            .map_err(|e| format!("Failed to create {}: {}", destination.display(), e))?;
+        std::io::copy(&mut entry, &mut output)
+            .map_err(|e| format!("Failed to extract {}: {}", entry.name(), e))?;
+
+        // Keep executable bits for any helper binaries in the bundle
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            if let Some(mode) = entry.unix_mode() {
+                let _ = fs::set_permissions(&destination, fs::Permissions::from_mode(mode));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Backups of a plugin in the CEP directory, oldest first
+///
+/// Matches the `{plugin_name}_{YYYYmmdd_HHMMSS}` names install_plugin creates,
+/// so the timestamp suffix sorts chronologically.
+pub(crate) fn list_plugin_backups(cep_dir: &Path, plugin_name: &str) -> Vec<PathBuf> {
+    let prefix = format!("{}_", plugin_name);
+    let Ok(entries) = fs::read_dir(cep_dir) else {
+        return Vec::new();
+    };
+
+    let mut backups: Vec<(String, PathBuf)> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let timestamp = name.strip_prefix(&prefix)?.to_string();
+            chrono::NaiveDateTime::parse_from_str(&timestamp, "%Y%m%d_%H%M%S")
+                .ok()
+                .map(|_| (timestamp, entry.path()))
+        })
+        .collect();
+
+    backups.sort();
+    backups.into_iter().map(|(_, path)| path).collect()
+}
+
+/// Delete all but the `keep` most recent backups of a plugin
+///
+/// Returns the paths that were removed; a backup that can't be deleted is
+/// logged and left in place.
+pub(crate) fn prune_plugin_backups(cep_dir: &Path, plugin_name: &str, keep: usize) -> Vec<String> {
+    let backups = list_plugin_backups(cep_dir, plugin_name);
+    let excess = backups.len().saturating_sub(keep);
+
+    backups
+        .into_iter()
+        .take(excess)
+        .filter_map(|backup| match fs::remove_dir_all(&backup) {
+            Ok(()) => Some(backup.to_string_lossy().to_string()),
+            Err(e) => {
+                println!(
+                    "[INSTALL] Could not remove old backup {}: {}",
+                    backup.display(),
+                    e
+                );
+                None
+            }
+        })
+        .collect()
+}
+
 /// Install a plugin by extracting its ZXP file to the CEP extensions directory
 ///
 /// # Arguments
@@ -179,18 +544,25 @@ pub async fn get_available_plugins() -> Result<Vec<PluginInfo>, String> {
 /// * `plugin_name` - Name of the plugin (used for directory name)
 ///
 /// # Process
-/// 1. Locate ZXP file in bundled assets
+/// 1. Locate ZXP file in bundled assets and verify its size and structure
 /// 2. Get/create CEP extensions directory
 /// 3. Backup existing installation if present
 /// 4. Extract ZXP contents (it's a ZIP archive)
 /// 5. Remove macOS quarantine attribute
 /// 6. Verify installation
+/// 7. Delete backups beyond the configured retention limit
 #[tauri::command]
 pub async fn install_plugin(
     app_handle: AppHandle,
     plugin_filename: String,
     plugin_name: String,
+    config: State<'_, ConfigState>,
 ) -> Result<InstallResult, String> {
+    let backups_to_keep = config
+        .config
+        .lock()
+        .map_err(|_| "Failed to acquire lock")?
+        .plugin_backups_to_keep;
 
     // Get ZXP file from assets
     // In dev mode: src-tauri/target/debug/resources/plugins/
@@ -208,6 +580,13 @@ pub async fn install_plugin(
         return Err(err_msg);
     }
 
+    // Verify the bundle before touching the CEP directory
+    let expected_size = bundled_plugins()
+        .into_iter()
+        .find(|plugin| plugin.filename == plugin_filename)
+        .map(|plugin| plugin.size);
+    verify_plugin_archive(&resource_path, expected_size)?;
+
     // Get CEP directory
     let cep_dir = get_cep_extensions_dir()?;
 
@@ -222,7 +601,7 @@ pub async fn install_plugin(
 
 
     // Backup existing installation
-    if target_dir.exists() {
+    let backup_path = if target_dir.exists() {
         let backup_name = format!(
             "{}_{}",
             plugin_name,
@@ -235,7 +614,11 @@ pub async fn install_plugin(
                 let err_msg = format!("Failed to backup existing plugin: {}", e);
                 err_msg
             })?;
-    }
+
+        Some(backup_dir.to_string_lossy().to_string())
+    } else {
+        None
+    };
 
     // Create target directory
     fs::create_dir_all(&target_dir)
@@ -245,25 +628,15 @@ pub async fn install_plugin(
         })?;
 
 
-    // Extract ZXP (it's a ZIP file)
-    let file = fs::File::open(&resource_path)
-        .map_err(|e| {
-            let err_msg = format!("Failed to open plugin file: {}", e);
-            err_msg
-        })?;
-
-    let mut archive =
-        ZipArchive::new(file).map_err(|e| {
-            let err_msg = format!("Failed to read plugin archive: {}", e);
-            err_msg
-        })?;
-
-    archive
-        .extract(&target_dir)
-        .map_err(|e| {
-            let err_msg = format!("Failed to extract plugin: {}", e);
-            err_msg
-        })?;
+    // Extract ZXP (it's a ZIP file), removing the partial install and
+    // restoring any backup if anything goes wrong
+    if let Err(e) = extract_plugin_archive(&resource_path, &target_dir) {
+        let _ = fs::remove_dir_all(&target_dir);
+        if let Some(backup) = &backup_path {
+            let _ = fs::rename(backup, &target_dir);
+        }
+        return Err(format!("Failed to extract plugin: {}", e));
+    }
 
 
     // macOS: Remove quarantine attribute
@@ -295,15 +668,28 @@ pub async fn install_plugin(
         return Err(err_msg);
     }
 
+    // Only prune once the new install is known to be good
+    let removed_backups = prune_plugin_backups(&cep_dir, &plugin_name, backups_to_keep);
 
     Ok(InstallResult {
         success: true,
         message: format!("Successfully installed {} - restart Premiere Pro to use", plugin_name),
         plugin_name: plugin_name.clone(),
         installed_path: target_dir.to_string_lossy().to_string(),
+        backup_path,
+        removed_backups,
     })
 }
 
+/// Delete every backup of a plugin left behind by previous installs
+///
+/// Returns the paths that were removed.
+#[tauri::command]
+pub async fn clear_plugin_backups(plugin_name: String) -> Result<Vec<String>, String> {
+    let cep_dir = get_cep_extensions_dir()?;
+    Ok(prune_plugin_backups(&cep_dir, &plugin_name, 0))
+}
+
 /// Check if a specific plugin is installed
 #[tauri::command]
 pub async fn check_plugin_installed(plugin_name: String) -> Result<bool, String> {
@@ -316,25 +702,101 @@ pub async fn get_cep_directory() -> Result<String, String> {
     get_cep_extensions_dir().map(|p| p.to_string_lossy().to_string())
 }
 
-/// Enable CEP debug mode (macOS only, for self-signed extensions)
+/// Check whether the CEP extensions directory can be written to
+///
+/// Writes and removes a probe file in the directory, or in its nearest
+/// existing ancestor if it hasn't been created yet, so nothing is left on
+/// disk. Returns `Ok(false)` when the probe fails so the UI can explain that
+/// admin rights are required before the user attempts an install.
+#[tauri::command]
+pub async fn check_cep_writable() -> Result<bool, String> {
+    let cep_dir = get_cep_extensions_dir()?;
+
+    let Some(probe_dir) = cep_dir.ancestors().find(|dir| dir.is_dir()) else {
+        return Ok(false);
+    };
+
+    let probe_path = probe_dir.join(format!(".bucket_write_test_{}", uuid::Uuid::new_v4()));
+
+    match fs::write(&probe_path, b"") {
+        Ok(()) => {
+            let _ = fs::remove_file(&probe_path);
+            Ok(true)
+        }
+        Err(e) => {
+            println!(
+                "[INSTALL] CEP directory is not writable: {} ({})",
+                probe_dir.display(),
+                e
+            );
+            Ok(false)
+        }
+    }
+}
+
+/// Enable CEP debug mode for every CSXS version Premiere may use
 ///
 /// This allows self-signed CEP extensions to load without certificate warnings.
-/// Must be run before launching Premiere Pro.
+/// Must be run before launching Premiere Pro. Sets PlayerDebugMode via
+/// `defaults` on macOS and under HKCU\Software\Adobe on Windows, and returns
+/// the CSXS versions that were set.
 #[tauri::command]
-pub async fn enable_cep_debug_mode() -> Result<(), String> {
-    #[cfg(target_os = "macos")]
+pub async fn enable_cep_debug_mode() -> Result<Vec<u32>, String> {
+    #[cfg(any(target_os = "macos", target_os = "windows"))]
     {
-        Command::new("defaults")
-            .args(["write", "com.adobe.CSXS.11", "PlayerDebugMode", "1"])
-            .output()
-            .map_err(|e| format!("Failed to enable debug mode: {}", e))?;
+        let mut enabled = Vec::new();
+        let mut errors = Vec::new();
+
+        for version in CSXS_VERSIONS {
+            #[cfg(target_os = "macos")]
+            let output = Command::new("defaults")
+                .args([
+                    "write",
+                    &format!("com.adobe.CSXS.{}", version),
+                    "PlayerDebugMode",
+                    "1",
+                ])
+                .output();
+
+            #[cfg(target_os = "windows")]
+            let output = Command::new("reg")
+                .args([
+                    "add",
+                    &format!("HKCU\\Software\\Adobe\\CSXS.{}", version),
+                    "/v",
+                    "PlayerDebugMode",
+                    "/t",
+                    "REG_SZ",
+                    "/d",
+                    "1",
+                    "/f",
+                ])
+                .output();
+
+            match output {
+                Ok(output) if output.status.success() => enabled.push(version),
+                Ok(output) => errors.push(format!(
+                    "CSXS.{}: {}",
+                    version,
+                    String::from_utf8_lossy(&output.stderr).trim()
+                )),
+                Err(e) => errors.push(format!("CSXS.{}: {}", version, e)),
+            }
+        }
 
-        Ok(())
+        if enabled.is_empty() {
+            return Err(format!(
+                "Failed to enable debug mode: {}",
+                errors.join("; ")
+            ));
+        }
+
+        Ok(enabled)
     }
 
-    #[cfg(not(target_os = "macos"))]
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
     {
-        Ok(()) // No-op on Windows
+        Err("Unsupported operating system".to_string())
     }
 }
 
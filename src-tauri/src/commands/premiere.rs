@@ -1,8 +1,8 @@
+use crate::utils::open;
 use std::env;
 use std::fs;
 use std::io::Write; // For writing bytes to a file
 use std::path::{Path, PathBuf};
-use std::process::Command;
 use tauri::{command, AppHandle, Manager};
 use tauri_plugin_dialog::{DialogExt, MessageDialogButtons};
 
@@ -40,6 +40,8 @@ pub fn open_resource_file(handle: AppHandle, relative_file_path: &str) -> Result
 /// # Arguments
 /// * `destination_folder` - The path to the destination folder where the file should be copied.
 /// * `new_title` - The new name for the copied file (without the extension).
+/// * `overwrite` - Replace an existing project, first moving it to `{name}.prproj.bak`.
+///   Defaults to false, which fails if the project already exists.
 ///
 /// # Returns
 /// * `Ok(())` if the operation is successful.
@@ -49,6 +51,7 @@ pub fn copy_premiere_project(
     handle: AppHandle,
     destination_folder: String,
     new_title: String,
+    overwrite: Option<bool>,
 ) -> Result<(), String> {
     // Print the current working directory
     match env::current_dir() {
@@ -79,12 +82,16 @@ pub fn copy_premiere_project(
 
     // Check if the destination file already exists
     if destination_path.exists() {
-        let error_msg = format!(
-            "Error: A file with the name '{}' already exists in the destination folder.",
-            destination_path.display()
-        );
-        eprintln!("{}", error_msg);
-        return Err(error_msg);
+        if !overwrite.unwrap_or(false) {
+            let error_msg = format!(
+                "Error: A file with the name '{}' already exists in the destination folder.",
+                destination_path.display()
+            );
+            eprintln!("{}", error_msg);
+            return Err(error_msg);
+        }
+
+        backup_existing_project(&destination_path)?;
     }
 
     // Write the file data to the destination path
@@ -118,6 +125,45 @@ pub fn copy_premiere_project(
     Ok(())
 }
 
+/// Moves an existing project to `{name}.prproj.bak`, replacing any older backup.
+///
+/// # Arguments
+/// * `project_path` - The project file about to be overwritten.
+///
+/// # Returns
+/// * `Ok(PathBuf)` with the backup path if successful.
+/// * `Err(String)` if the project could not be moved.
+pub(crate) fn backup_existing_project(project_path: &Path) -> Result<PathBuf, String> {
+    let mut backup_name = project_path.as_os_str().to_owned();
+    backup_name.push(".bak");
+    let backup_path = PathBuf::from(backup_name);
+
+    // Windows won't rename over an existing file
+    if backup_path.exists() {
+        fs::remove_file(&backup_path).map_err(|e| {
+            format!(
+                "Failed to remove old backup '{}': {} ({:?})",
+                backup_path.display(),
+                e,
+                e.kind()
+            )
+        })?;
+    }
+
+    fs::rename(project_path, &backup_path).map_err(|e| {
+        format!(
+            "Failed to back up '{}' to '{}': {} ({:?})",
+            project_path.display(),
+            backup_path.display(),
+            e,
+            e.kind()
+        )
+    })?;
+
+    println!("Backed up existing project to {:?}", backup_path);
+    Ok(backup_path)
+}
+
 /// Displays a confirmation dialog with Yes/No options and opens Finder/Explorer if Yes is selected.
 ///
 /// # Arguments
@@ -145,49 +191,13 @@ pub fn show_confirmation_dialog(
 
     // If the user selects "Yes", open the Finder/File Explorer
     if answer {
-        open_folder_internal(destination)
+        open::open_folder(&destination)
     } else {
         println!("User selected No, no action taken.");
         Ok(())
     }
 }
 
-/// Opens Finder or File Explorer at the specified destination.
-///
-/// # Arguments
-/// * `destination` - The folder path to open.
-///
-/// # Returns
-/// * `Ok(())` if successful.
-/// * `Err(String)` if an error occurs.
-fn open_folder_internal(destination: String) -> Result<(), String> {
-    let path = Path::new(&destination);
-
-    if !path.exists() {
-        return Err(format!(
-            "Error: The destination path does not exist: {}",
-            destination
-        ));
-    }
-
-    #[cfg(target_os = "macos")]
-    let result = Command::new("open").arg(&destination).spawn();
-
-    #[cfg(target_os = "windows")]
-    let result = Command::new("explorer").arg(&destination).spawn();
-
-    #[cfg(target_os = "linux")]
-    let result = Command::new("xdg-open").arg(&destination).spawn();
-
-    match result {
-        Ok(_) => {
-            println!("Opened folder: {}", destination);
-            Ok(())
-        }
-        Err(e) => Err(format!("Failed to open folder: {}", e)),
-    }
-}
-
 #[cfg(test)]
 #[path = "tests/premiere_test.rs"]
 mod premiere_test;
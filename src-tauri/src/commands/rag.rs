@@ -4,11 +4,15 @@
  * Purpose: Vector similarity search for autocue script examples
  */
 
+use crate::commands::ai_provider::request_embedding;
 use rusqlite::{params, Connection, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
-use std::path::PathBuf;
-use tauri::Manager;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use tauri::{Emitter, Manager};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct SimilarExample {
@@ -18,6 +22,8 @@ pub struct SimilarExample {
     pub before_text: String,
     pub after_text: String,
     pub similarity: f32,
+    pub tags: Vec<String>,
+    pub source: String, // "bundled" or "user-uploaded"
 }
 
 /// Example with full metadata (extends SimilarExample)
@@ -70,6 +76,10 @@ pub struct ReplaceExampleRequest {
 // Database Initialization & Migration
 // ============================================================================
 
+/// Schema version of the examples database this build reads and writes
+/// Stored under the `schema_version` key in db_metadata
+pub(crate) const CURRENT_SCHEMA_VERSION: i64 = 1;
+
 /// Get the database path in app data directory (persists across app updates)
 /// If database doesn't exist, copies bundled version from resources
 fn get_or_initialize_database(app: &tauri::AppHandle) -> Result<PathBuf, String> {
@@ -87,7 +97,8 @@ fn get_or_initialize_database(app: &tauri::AppHandle) -> Result<PathBuf, String>
     let db_path = embeddings_dir.join("examples.db");
 
     // If database doesn't exist, copy from bundled resources
-    if !db_path.exists() {
+    let newly_initialized = !db_path.exists();
+    if newly_initialized {
         println!("[RAG] Database not found in app data dir, initializing from bundled resources");
 
         // Get bundled database from resources
@@ -119,7 +130,15 @@ fn get_or_initialize_database(app: &tauri::AppHandle) -> Result<PathBuf, String>
             "[RAG] Database initialized at: {}",
             db_path.display()
         );
-    } else {
+        invalidate_embedding_index();
+    }
+
+    // Bring databases created by older versions up to the current schema
+    let conn = Connection::open(&db_path).map_err(|e| format!("Failed to open database: {}", e))?;
+    run_migrations(&conn)?;
+    drop(conn);
+
+    if !newly_initialized {
         println!("[RAG] Using existing database at: {}", db_path.display());
 
         // Merge new bundled examples if available
@@ -132,6 +151,156 @@ fn get_or_initialize_database(app: &tauri::AppHandle) -> Result<PathBuf, String>
     Ok(db_path)
 }
 
+/// Columns added to example_scripts after the first release, with their definitions
+/// (ALTER TABLE can't use a non-constant default, so created_at is backfilled instead)
+const EXAMPLE_SCRIPT_COLUMNS: &[(&str, &str)] = &[
+    ("tags", "TEXT"),
+    ("word_count", "INTEGER"),
+    ("quality_score", "INTEGER"),
+    ("source", "TEXT DEFAULT 'bundled'"),
+    ("created_at", "TEXT"),
+];
+
+/// Names of the columns currently in a table
+fn table_columns(conn: &Connection, table: &str) -> Result<Vec<String>, String> {
+    let mut stmt = conn
+        .prepare(&format!("PRAGMA table_info({})", table))
+        .map_err(|e| format!("Failed to read {} schema: {}", table, e))?;
+
+    let columns = stmt
+        .query_map([], |row| row.get::<_, String>(1))
+        .map_err(|e| format!("Failed to read {} schema: {}", table, e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to read {} schema: {}", table, e))?;
+
+    Ok(columns)
+}
+
+/// Idempotently bring a database up to the current schema: tables, columns and indexes
+/// Safe to run on every open; each step is skipped when already applied
+pub(crate) fn run_migrations(conn: &Connection) -> Result<(), String> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS example_scripts (
+            id TEXT PRIMARY KEY,
+            title TEXT NOT NULL,
+            category TEXT NOT NULL,
+            before_text TEXT NOT NULL,
+            after_text TEXT NOT NULL,
+            tags TEXT,
+            word_count INTEGER,
+            quality_score INTEGER,
+            source TEXT DEFAULT 'bundled',
+            created_at TEXT DEFAULT CURRENT_TIMESTAMP
+        );
+        CREATE TABLE IF NOT EXISTS embeddings (
+            script_id TEXT PRIMARY KEY,
+            embedding BLOB NOT NULL,
+            dimension INTEGER NOT NULL,
+            FOREIGN KEY(script_id) REFERENCES example_scripts(id)
+        );
+        CREATE TABLE IF NOT EXISTS db_metadata (
+            key TEXT PRIMARY KEY,
+            value TEXT NOT NULL,
+            updated_at TEXT DEFAULT CURRENT_TIMESTAMP
+        );",
+    )
+    .map_err(|e| format!("Failed to create tables: {}", e))?;
+
+    let existing = table_columns(conn, "example_scripts")?;
+    for (column, definition) in EXAMPLE_SCRIPT_COLUMNS {
+        if existing.iter().any(|name| name == column) {
+            continue;
+        }
+
+        println!("[RAG] Migrating: adding example_scripts.{}", column);
+        conn.execute(
+            &format!(
+                "ALTER TABLE example_scripts ADD COLUMN {} {}",
+                column, definition
+            ),
+            [],
+        )
+        .map_err(|e| format!("Failed to add column {}: {}", column, e))?;
+
+        if *column == "created_at" {
+            conn.execute(
+                "UPDATE example_scripts SET created_at = CURRENT_TIMESTAMP WHERE created_at IS NULL",
+                [],
+            )
+            .map_err(|e| format!("Failed to backfill created_at: {}", e))?;
+        }
+    }
+
+    if !table_columns(conn, "embeddings")?
+        .iter()
+        .any(|name| name == "dimension")
+    {
+        println!("[RAG] Migrating: adding embeddings.dimension");
+        // Embeddings are stored as little-endian f32, 4 bytes per dimension
+        conn.execute_batch(
+            "ALTER TABLE embeddings ADD COLUMN dimension INTEGER NOT NULL DEFAULT 0;
+             UPDATE embeddings SET dimension = length(embedding) / 4;",
+        )
+        .map_err(|e| format!("Failed to add column dimension: {}", e))?;
+    }
+
+    conn.execute_batch(
+        "CREATE INDEX IF NOT EXISTS idx_category ON example_scripts(category);
+         CREATE INDEX IF NOT EXISTS idx_quality ON example_scripts(quality_score);
+         CREATE INDEX IF NOT EXISTS idx_source ON example_scripts(source);",
+    )
+    .map_err(|e| format!("Failed to create indexes: {}", e))?;
+
+    // Keyword search index, kept in sync with example_scripts by triggers
+    let fts_exists: bool = conn
+        .query_row(
+            "SELECT COUNT(*) > 0 FROM sqlite_master WHERE type = 'table' AND name = 'example_scripts_fts'",
+            [],
+            |row| row.get(0),
+        )
+        .map_err(|e| format!("Failed to check for search index: {}", e))?;
+
+    if !fts_exists {
+        println!("[RAG] Migrating: building keyword search index");
+        conn.execute_batch(
+            "CREATE VIRTUAL TABLE example_scripts_fts USING fts5(
+                id UNINDEXED,
+                title,
+                before_text,
+                after_text
+            );
+            INSERT INTO example_scripts_fts (id, title, before_text, after_text)
+                SELECT id, title, before_text, after_text FROM example_scripts;",
+        )
+        .map_err(|e| format!("Failed to create search index: {}", e))?;
+    }
+
+    conn.execute_batch(
+        "CREATE TRIGGER IF NOT EXISTS example_scripts_fts_insert AFTER INSERT ON example_scripts BEGIN
+            INSERT INTO example_scripts_fts (id, title, before_text, after_text)
+                VALUES (new.id, new.title, new.before_text, new.after_text);
+         END;
+         CREATE TRIGGER IF NOT EXISTS example_scripts_fts_delete AFTER DELETE ON example_scripts BEGIN
+            DELETE FROM example_scripts_fts WHERE id = old.id;
+         END;
+         CREATE TRIGGER IF NOT EXISTS example_scripts_fts_update AFTER UPDATE ON example_scripts BEGIN
+            DELETE FROM example_scripts_fts WHERE id = old.id;
+            INSERT INTO example_scripts_fts (id, title, before_text, after_text)
+                VALUES (new.id, new.title, new.before_text, new.after_text);
+         END;",
+    )
+    .map_err(|e| format!("Failed to create search index triggers: {}", e))?;
+
+    // Databases without a schema_version predate versioning and use schema 1
+    conn.execute(
+        "INSERT OR IGNORE INTO db_metadata (key, value) VALUES ('schema_version', ?)",
+        params![CURRENT_SCHEMA_VERSION.to_string()],
+    )
+    .map_err(|e| format!("Failed to record schema version: {}", e))?;
+
+    Ok(())
+}
+
 /// Merge new bundled examples into the active database
 /// This runs on app startup after updates to add new bundled examples
 fn merge_bundled_examples(app: &tauri::AppHandle, active_db_path: &PathBuf) -> Result<(), String> {
@@ -146,23 +315,42 @@ fn merge_bundled_examples(app: &tauri::AppHandle, active_db_path: &PathBuf) -> R
         return Ok(()); // No bundled database, nothing to merge
     }
 
+    merge_bundled_database(&bundled_db_path, active_db_path)
+}
+
+/// Merge examples from a bundled database file into the active database
+/// Skips the merge when the bundled schema is newer than this build understands
+pub(crate) fn merge_bundled_database(
+    bundled_db_path: &Path,
+    active_db_path: &Path,
+) -> Result<(), String> {
     // Open both databases
-    let bundled_conn = Connection::open(&bundled_db_path)
+    let bundled_conn = Connection::open(bundled_db_path)
         .map_err(|e| format!("Failed to open bundled database: {}", e))?;
     let active_conn = Connection::open(active_db_path)
         .map_err(|e| format!("Failed to open active database: {}", e))?;
 
-    // Ensure db_metadata table exists in active database (migration for old databases)
-    active_conn
-        .execute(
-            "CREATE TABLE IF NOT EXISTS db_metadata (
-                key TEXT PRIMARY KEY,
-                value TEXT NOT NULL,
-                updated_at TEXT DEFAULT CURRENT_TIMESTAMP
-            )",
+    // Databases without a schema_version predate versioning and use schema 1
+    let bundled_schema_version: i64 = bundled_conn
+        .query_row(
+            "SELECT value FROM db_metadata WHERE key = 'schema_version'",
             [],
+            |row| row.get::<_, String>(0),
         )
-        .map_err(|e| format!("Failed to create db_metadata table: {}", e))?;
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(1);
+
+    if bundled_schema_version > CURRENT_SCHEMA_VERSION {
+        println!(
+            "[RAG] Warning: Bundled database schema version {} is newer than supported version {}, skipping merge",
+            bundled_schema_version, CURRENT_SCHEMA_VERSION
+        );
+        return Ok(());
+    }
+
+    // Ensure db_metadata and the other tables exist in active database (migration for old databases)
+    run_migrations(&active_conn)?;
 
     // Get bundled version from bundled database
     let bundled_version: Option<String> = bundled_conn
@@ -274,6 +462,8 @@ fn merge_bundled_examples(app: &tauri::AppHandle, active_db_path: &PathBuf) -> R
                             |row| row.get(0),
                         )
                         .map_err(|e| format!("Failed to get bundled embedding: {}", e))?;
+                    let embedding_blob =
+                        vec_f32_to_blob(&normalize_embedding(&blob_to_vec_f32(&embedding_blob)));
 
                     active_conn
                         .execute(
@@ -318,6 +508,8 @@ fn merge_bundled_examples(app: &tauri::AppHandle, active_db_path: &PathBuf) -> R
                     )
                     .map_err(|e| format!("Failed to get bundled embedding: {}", e))?
                     .0;
+                let embedding_blob =
+                    vec_f32_to_blob(&normalize_embedding(&blob_to_vec_f32(&embedding_blob)));
 
                 let dimension = bundled_conn
                     .query_row(
@@ -367,6 +559,10 @@ fn merge_bundled_examples(app: &tauri::AppHandle, active_db_path: &PathBuf) -> R
         added_count, updated_count, skipped_count
     );
 
+    if added_count + updated_count > 0 {
+        invalidate_embedding_index();
+    }
+
     Ok(())
 }
 
@@ -391,6 +587,17 @@ fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
     dot_product / (magnitude_a * magnitude_b)
 }
 
+/// Split the comma-separated tags column into trimmed, non-empty tags
+fn parse_tags(tags: Option<String>) -> Vec<String> {
+    tags.map(|s| {
+        s.split(',')
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty())
+            .collect()
+    })
+    .unwrap_or_default()
+}
+
 /// Convert BLOB to Vec<f32>
 fn blob_to_vec_f32(blob: &[u8]) -> Vec<f32> {
     blob.chunks_exact(4)
@@ -398,96 +605,283 @@ fn blob_to_vec_f32(blob: &[u8]) -> Vec<f32> {
         .collect()
 }
 
-#[tauri::command]
-pub async fn search_similar_scripts(
-    app: tauri::AppHandle,
-    query_embedding: Vec<f32>,
-    top_k: usize,
-    min_similarity: Option<f32>,
-) -> Result<Vec<SimilarExample>, String> {
-    // Get or initialize database (persists across updates)
-    let db_path = get_or_initialize_database(&app)?;
+/// Convert Vec<f32> to a little-endian BLOB
+fn vec_f32_to_blob(embedding: &[f32]) -> Vec<u8> {
+    embedding.iter().flat_map(|f| f.to_le_bytes()).collect()
+}
 
-    // Open database connection
-    let conn = Connection::open(&db_path)
-        .map_err(|e| format!("Failed to open database at {}: {}", db_path.display(), e))?;
+/// Scale an embedding to unit length; a zero vector is returned unchanged
+pub(crate) fn normalize_embedding(embedding: &[f32]) -> Vec<f32> {
+    let magnitude: f32 = embedding.iter().map(|x| x * x).sum::<f32>().sqrt();
 
-    println!("[RAG] Query embedding dimensions: {}", query_embedding.len());
-    println!("[RAG] Top K: {}, Min similarity: {:?}", top_k, min_similarity);
+    if magnitude == 0.0 {
+        return embedding.to_vec();
+    }
+
+    embedding.iter().map(|x| x / magnitude).collect()
+}
+
+/// Cosine similarity for vectors already normalized to unit length
+fn dot_product(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() {
+        return 0.0;
+    }
+
+    a.iter().zip(b.iter()).map(|(x, y)| x * y).sum()
+}
+
+/// db_metadata key set to "true" once every stored embedding is unit length
+pub(crate) const NORMALIZED_METADATA_KEY: &str = "normalized";
+
+/// Whether every stored embedding is known to be normalized
+fn embeddings_normalized(conn: &Connection) -> bool {
+    conn.query_row(
+        "SELECT value FROM db_metadata WHERE key = ?",
+        params![NORMALIZED_METADATA_KEY],
+        |row| row.get::<_, String>(0),
+    )
+    .map(|value| value == "true")
+    .unwrap_or(false)
+}
+
+// ============================================================================
+// Embedding Index
+// ============================================================================
+
+/// A searchable example with its embedding already decoded from the BLOB
+#[derive(Debug, Clone)]
+pub(crate) struct IndexedExample {
+    pub example: SimilarExample,
+    pub quality_score: Option<i32>,
+    pub dimension: usize,
+    pub embedding: Vec<f32>,
+}
+
+/// In-memory copy of the searchable embeddings for one database file
+pub(crate) struct EmbeddingIndex {
+    pub db_path: PathBuf,
+    pub examples: Vec<IndexedExample>,
+    /// Stored embeddings are unit length, so similarity is a plain dot product
+    pub normalized: bool,
+}
+
+/// Default minimum quality_score for search results
+pub const DEFAULT_MIN_QUALITY: i32 = 4;
+
+/// Quality assumed for examples without a score: average, so excluded by the default
+/// minimum but included once a user lowers it to 3
+pub const UNRATED_QUALITY_SCORE: i32 = 3;
 
-    // Fetch all examples with embeddings
+/// Index shared by every search until the examples table changes
+static EMBEDDING_INDEX: RwLock<Option<Arc<EmbeddingIndex>>> = RwLock::new(None);
+
+/// Bumped on every invalidation so a search that raced a write doesn't cache stale rows
+static EMBEDDING_INDEX_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Drop the cached index; call after anything that writes to example_scripts or embeddings
+pub(crate) fn invalidate_embedding_index() {
+    EMBEDDING_INDEX_GENERATION.fetch_add(1, Ordering::SeqCst);
+    if let Ok(mut index) = EMBEDDING_INDEX.write() {
+        *index = None;
+    }
+}
+
+/// Read every example with an embedding and decode it; quality filtering happens per query
+pub(crate) fn load_embedding_index(
+    conn: &Connection,
+    db_path: &Path,
+) -> Result<EmbeddingIndex, String> {
     let mut stmt = conn
         .prepare(
-            "SELECT e.script_id, s.title, s.category, s.before_text, s.after_text, e.embedding, e.dimension
+            "SELECT e.script_id, s.title, s.category, s.before_text, s.after_text, e.embedding, s.quality_score, s.tags, s.source, e.dimension
              FROM embeddings e
              JOIN example_scripts s ON e.script_id = s.id
-             WHERE s.quality_score >= 4
              ORDER BY s.quality_score DESC",
         )
         .map_err(|e| format!("Failed to prepare query: {}", e))?;
 
-    let mut results: Vec<SimilarExample> = Vec::new();
-
-    let rows = stmt
+    let examples = stmt
         .query_map([], |row| {
-            Ok((
-                row.get::<_, String>(0)?,   // id
-                row.get::<_, String>(1)?,   // title
-                row.get::<_, String>(2)?,   // category
-                row.get::<_, String>(3)?,   // before_text
-                row.get::<_, String>(4)?,   // after_text
-                row.get::<_, Vec<u8>>(5)?,  // embedding
-                row.get::<_, i32>(6)?,      // dimension
-            ))
+            Ok(IndexedExample {
+                example: SimilarExample {
+                    id: row.get(0)?,
+                    title: row.get(1)?,
+                    category: row.get(2)?,
+                    before_text: row.get(3)?,
+                    after_text: row.get(4)?,
+                    similarity: 0.0,
+                    tags: parse_tags(row.get(7)?),
+                    source: row.get(8)?,
+                },
+                quality_score: row.get(6)?,
+                dimension: row.get(9)?,
+                embedding: blob_to_vec_f32(&row.get::<_, Vec<u8>>(5)?),
+            })
         })
-        .map_err(|e| format!("Failed to query database: {}", e))?;
-
-    let mut total_examples = 0;
-    let mut skipped_by_threshold = 0;
+        .map_err(|e| format!("Failed to query database: {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to read row: {}", e))?;
 
-    for row_result in rows {
-        let (id, title, category, before_text, after_text, embedding_blob, stored_dimension) =
-            row_result.map_err(|e| format!("Failed to read row: {}", e))?;
+    Ok(EmbeddingIndex {
+        db_path: db_path.to_path_buf(),
+        examples,
+        normalized: embeddings_normalized(conn),
+    })
+}
 
-        total_examples += 1;
+/// Return the cached index for db_path, loading it from the database if needed
+pub(crate) fn get_embedding_index(db_path: &Path) -> Result<Arc<EmbeddingIndex>, String> {
+    if let Ok(cached) = EMBEDDING_INDEX.read() {
+        if let Some(index) = cached.as_ref().filter(|index| index.db_path == db_path) {
+            return Ok(Arc::clone(index));
+        }
+    }
 
-        // Convert blob to vector
-        let embedding = blob_to_vec_f32(&embedding_blob);
+    let generation = EMBEDDING_INDEX_GENERATION.load(Ordering::SeqCst);
 
-        println!("[RAG] Comparing with example '{}' (stored dim: {}, actual dim: {})",
-                 title, stored_dimension, embedding.len());
+    let conn = Connection::open(db_path)
+        .map_err(|e| format!("Failed to open database at {}: {}", db_path.display(), e))?;
+    let index = Arc::new(load_embedding_index(&conn, db_path)?);
 
-        // Calculate similarity
-        let similarity = cosine_similarity(&query_embedding, &embedding);
-        println!("[RAG]   Similarity score: {:.4}", similarity);
+    println!(
+        "[RAG] Loaded {} embeddings into the search index",
+        index.examples.len()
+    );
 
-        // Apply minimum similarity threshold
-        if let Some(min_sim) = min_similarity {
-            if similarity < min_sim {
-                skipped_by_threshold += 1;
-                println!("[RAG]   Skipped (below threshold {:.2})", min_sim);
-                continue;
-            }
+    if let Ok(mut cached) = EMBEDDING_INDEX.write() {
+        if EMBEDDING_INDEX_GENERATION.load(Ordering::SeqCst) == generation {
+            *cached = Some(Arc::clone(&index));
         }
+    }
 
-        results.push(SimilarExample {
-            id,
-            title,
-            category,
-            before_text,
-            after_text,
-            similarity,
-        });
+    Ok(index)
+}
+
+/// Make sure the query was embedded with the same model as the stored examples
+///
+/// cosine_similarity scores mismatched lengths as 0.0, which would otherwise show up
+/// as every result silently scoring zero.
+pub(crate) fn check_query_dimension(
+    index: &EmbeddingIndex,
+    query_embedding: &[f32],
+) -> Result<(), String> {
+    let mut stored_dimensions: Vec<usize> = index
+        .examples
+        .iter()
+        .map(|indexed| indexed.dimension)
+        .collect();
+    stored_dimensions.sort_unstable();
+    stored_dimensions.dedup();
+
+    if stored_dimensions.is_empty() || stored_dimensions.contains(&query_embedding.len()) {
+        return Ok(());
     }
 
+    let stored = stored_dimensions
+        .iter()
+        .map(|dimension| dimension.to_string())
+        .collect::<Vec<_>>()
+        .join("/");
+    Err(format!(
+        "Embedding model mismatch: the query embedding has {} dimensions but the examples database stores {}-dimension embeddings. Use the same embedding model as the database, or re-embed the examples with the current model.",
+        query_embedding.len(),
+        stored
+    ))
+}
+
+/// Score every example at or above min_quality against the query, best first
+///
+/// Examples with no quality score count as UNRATED_QUALITY_SCORE. When categories
+/// is given (and not empty), only examples in those categories are considered.
+/// Examples embedded with a different dimension than the query are skipped.
+/// When the index is normalized, the query is normalized once and scored by dot product.
+pub(crate) fn rank_examples(
+    index: &EmbeddingIndex,
+    query_embedding: &[f32],
+    top_k: usize,
+    min_similarity: Option<f32>,
+    min_quality: i32,
+    categories: Option<&[String]>,
+) -> Vec<SimilarExample> {
+    let categories = categories.filter(|categories| !categories.is_empty());
+    let normalized_query = index
+        .normalized
+        .then(|| normalize_embedding(query_embedding));
+
+    let mut scored: Vec<(f32, &IndexedExample)> = index
+        .examples
+        .iter()
+        .filter(|indexed| indexed.embedding.len() == query_embedding.len())
+        .filter(|indexed| indexed.quality_score.unwrap_or(UNRATED_QUALITY_SCORE) >= min_quality)
+        .filter(|indexed| {
+            categories.is_none_or(|categories| categories.contains(&indexed.example.category))
+        })
+        .map(|indexed| {
+            let similarity = match &normalized_query {
+                Some(query) => dot_product(query, &indexed.embedding),
+                None => cosine_similarity(query_embedding, &indexed.embedding),
+            };
+            (similarity, indexed)
+        })
+        .filter(|(similarity, _)| min_similarity.is_none_or(|min_sim| *similarity >= min_sim))
+        .collect();
+
     // Sort by similarity (descending)
-    results.sort_by(|a, b| b.similarity.partial_cmp(&a.similarity).unwrap());
+    scored.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+    scored.truncate(top_k);
 
-    println!("[RAG] Summary: {} total examples, {} passed threshold, returning top {} results",
-             total_examples, total_examples - skipped_by_threshold, top_k.min(results.len()));
+    scored
+        .into_iter()
+        .map(|(similarity, indexed)| SimilarExample {
+            similarity,
+            ..indexed.example.clone()
+        })
+        .collect()
+}
 
-    // Return top K results
-    results.truncate(top_k);
+#[tauri::command]
+pub async fn search_similar_scripts(
+    app: tauri::AppHandle,
+    query_embedding: Vec<f32>,
+    top_k: usize,
+    min_similarity: Option<f32>,
+    min_quality: Option<i32>,
+    categories: Option<Vec<String>>,
+) -> Result<Vec<SimilarExample>, String> {
+    // Reject unknown categories rather than silently matching nothing
+    for category in categories.iter().flatten() {
+        validate_category(category)?;
+    }
+
+    // Get or initialize database (persists across updates)
+    let db_path = get_or_initialize_database(&app)?;
+
+    let min_quality = min_quality.unwrap_or(DEFAULT_MIN_QUALITY);
+
+    println!("[RAG] Query embedding dimensions: {}", query_embedding.len());
+    println!(
+        "[RAG] Top K: {}, Min similarity: {:?}, Min quality: {}, Categories: {:?}",
+        top_k, min_similarity, min_quality, categories
+    );
+
+    // Embeddings are decoded once and reused until an example is added, replaced or deleted
+    let index = get_embedding_index(&db_path)?;
+    check_query_dimension(&index, &query_embedding)?;
+
+    let results = rank_examples(
+        &index,
+        &query_embedding,
+        top_k,
+        min_similarity,
+        min_quality,
+        categories.as_deref(),
+    );
+
+    println!(
+        "[RAG] Summary: {} total examples, returning top {} results",
+        index.examples.len(),
+        results.len()
+    );
 
     Ok(results)
 }
@@ -504,7 +898,7 @@ pub async fn get_example_by_id(app: tauri::AppHandle, id: String) -> Result<Simi
     // Fetch specific example
     let mut stmt = conn
         .prepare(
-            "SELECT id, title, category, before_text, after_text
+            "SELECT id, title, category, before_text, after_text, tags, source
              FROM example_scripts
              WHERE id = ?",
         )
@@ -519,6 +913,8 @@ pub async fn get_example_by_id(app: tauri::AppHandle, id: String) -> Result<Simi
                 before_text: row.get(3)?,
                 after_text: row.get(4)?,
                 similarity: 1.0, // Perfect match when fetching by ID
+                tags: parse_tags(row.get(5)?),
+                source: row.get(6)?,
             })
         })
         .map_err(|e| format!("Example not found: {}", e))?;
@@ -538,7 +934,7 @@ pub async fn get_all_examples(app: tauri::AppHandle) -> Result<Vec<SimilarExampl
     // Fetch all examples
     let mut stmt = conn
         .prepare(
-            "SELECT id, title, category, before_text, after_text
+            "SELECT id, title, category, before_text, after_text, tags, source
              FROM example_scripts
              ORDER BY quality_score DESC, title ASC",
         )
@@ -553,6 +949,8 @@ pub async fn get_all_examples(app: tauri::AppHandle) -> Result<Vec<SimilarExampl
                 before_text: row.get(3)?,
                 after_text: row.get(4)?,
                 similarity: 1.0,
+                tags: parse_tags(row.get(5)?),
+                source: row.get(6)?,
             })
         })
         .map_err(|e| format!("Failed to query database: {}", e))?
@@ -585,23 +983,13 @@ pub async fn get_all_examples_with_metadata(
 
     let examples = stmt
         .query_map([], |row| {
-            let tags_str: Option<String> = row.get(5)?;
-            let tags: Vec<String> = tags_str
-                .map(|s| {
-                    s.split(',')
-                        .map(|t| t.trim().to_string())
-                        .filter(|t| !t.is_empty())
-                        .collect()
-                })
-                .unwrap_or_default();
-
             Ok(ExampleWithMetadata {
                 id: row.get(0)?,
                 title: row.get(1)?,
                 category: row.get(2)?,
                 before_text: row.get(3)?,
                 after_text: row.get(4)?,
-                tags,
+                tags: parse_tags(row.get(5)?),
                 word_count: row.get(6)?,
                 quality_score: row.get(7)?,
                 source: row.get(8)?,
@@ -615,6 +1003,107 @@ pub async fn get_all_examples_with_metadata(
     Ok(examples)
 }
 
+/// Keyword search hit, with relevance (higher is better) from FTS5's bm25 ranking
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExampleTextMatch {
+    #[serde(flatten)]
+    pub example: ExampleWithMetadata,
+    pub relevance: f64,
+}
+
+/// Turn user input into an FTS5 MATCH expression
+///
+/// Input wrapped in double quotes is matched as an exact phrase; otherwise every word
+/// must appear. Each term is quoted so FTS5 operators in the input are treated as text.
+fn fts_match_expression(query: &str) -> Option<String> {
+    let query = query.trim();
+
+    let terms: Vec<&str> = match query.strip_prefix('"').and_then(|q| q.strip_suffix('"')) {
+        Some(phrase) if !phrase.trim().is_empty() => vec![phrase.trim()],
+        _ => query.split_whitespace().collect(),
+    };
+    // Terms of pure punctuation tokenize to nothing, so leave them out
+    let terms: Vec<&str> = terms
+        .into_iter()
+        .filter(|term| term.chars().any(char::is_alphanumeric))
+        .collect();
+
+    if terms.is_empty() {
+        return None;
+    }
+
+    Some(
+        terms
+            .iter()
+            .map(|term| format!("\"{}\"", term.replace('"', "\"\"")))
+            .collect::<Vec<_>>()
+            .join(" "),
+    )
+}
+
+/// Keyword search over titles and before/after text, best matches first
+pub(crate) fn search_examples_fts(
+    conn: &Connection,
+    query: &str,
+    limit: usize,
+) -> Result<Vec<ExampleTextMatch>, String> {
+    let Some(expression) = fts_match_expression(query) else {
+        return Ok(Vec::new());
+    };
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT s.id, s.title, s.category, s.before_text, s.after_text, s.tags, s.word_count, s.quality_score, s.source, s.created_at, bm25(example_scripts_fts)
+             FROM example_scripts_fts
+             JOIN example_scripts s ON s.id = example_scripts_fts.id
+             WHERE example_scripts_fts MATCH ?
+             ORDER BY bm25(example_scripts_fts)
+             LIMIT ?",
+        )
+        .map_err(|e| format!("Failed to prepare query: {}", e))?;
+
+    let matches = stmt
+        .query_map(params![expression, limit as i64], |row| {
+            Ok(ExampleTextMatch {
+                example: ExampleWithMetadata {
+                    id: row.get(0)?,
+                    title: row.get(1)?,
+                    category: row.get(2)?,
+                    before_text: row.get(3)?,
+                    after_text: row.get(4)?,
+                    tags: parse_tags(row.get(5)?),
+                    word_count: row.get(6)?,
+                    quality_score: row.get(7)?,
+                    source: row.get(8)?,
+                    created_at: row.get(9)?,
+                },
+                // bm25 scores are negative, lower meaning more relevant
+                relevance: -row.get::<_, f64>(10)?,
+            })
+        })
+        .map_err(|e| format!("Failed to query database: {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to collect results: {}", e))?;
+
+    Ok(matches)
+}
+
+/// Keyword search complementing search_similar_scripts for exact words and phrases
+#[tauri::command]
+pub async fn search_examples_text(
+    app: tauri::AppHandle,
+    query: String,
+    limit: usize,
+) -> Result<Vec<ExampleTextMatch>, String> {
+    // Get or initialize database (persists across updates)
+    let db_path = get_or_initialize_database(&app)?;
+
+    let conn = Connection::open(&db_path).map_err(|e| format!("Failed to open database: {}", e))?;
+
+    search_examples_fts(&conn, &query, limit)
+}
+
 // ============================================================================
 // T022: Validation Helper Functions
 // ============================================================================
@@ -634,17 +1123,18 @@ fn validate_title(title: &str) -> Result<(), String> {
     Ok(())
 }
 
-/// Validate category enum
-fn validate_category(category: &str) -> Result<(), String> {
-    const VALID_CATEGORIES: &[&str] = &[
-        "educational",
-        "business",
-        "narrative",
-        "interview",
-        "documentary",
-        "user-custom",
-    ];
+/// Known example categories
+pub(crate) const VALID_CATEGORIES: &[&str] = &[
+    "educational",
+    "business",
+    "narrative",
+    "interview",
+    "documentary",
+    "user-custom",
+];
 
+/// Validate category enum
+pub(crate) fn validate_category(category: &str) -> Result<(), String> {
     if !VALID_CATEGORIES.contains(&category) {
         return Err(format!(
             "Invalid category: '{}'. Valid options: {}",
@@ -676,7 +1166,7 @@ fn validate_text_content(text: &str, field_name: &str) -> Result<(), String> {
 }
 
 /// Validate embedding dimensions
-fn validate_embedding_dimensions(embedding: &[f32]) -> Result<(), String> {
+pub(crate) fn validate_embedding_dimensions(embedding: &[f32]) -> Result<(), String> {
     // Support both all-MiniLM-L6-v2 (384) and nomic-embed-text (768)
     const VALID_DIMENSIONS: &[usize] = &[384, 768];
     if !VALID_DIMENSIONS.contains(&embedding.len()) {
@@ -699,39 +1189,33 @@ fn calculate_word_count(text: &str) -> i32 {
 // T023: Upload Example Command
 // ============================================================================
 
-#[tauri::command]
-pub async fn upload_example(
-    app: tauri::AppHandle,
-    request: UploadExampleRequest,
-) -> Result<String, String> {
-    // Validate inputs
+/// Check every field of an upload request before anything is written
+fn validate_upload_request(request: &UploadExampleRequest) -> Result<(), String> {
     validate_title(&request.metadata.title)?;
     validate_category(&request.metadata.category)?;
     validate_text_content(&request.before_content, "Before content")?;
     validate_text_content(&request.after_content, "After content")?;
     validate_embedding_dimensions(&request.embedding)?;
+    Ok(())
+}
 
+/// Insert one example and its embedding, returning the new id
+/// The caller owns the surrounding transaction
+pub(crate) fn insert_example(
+    conn: &Connection,
+    request: &UploadExampleRequest,
+) -> Result<String, String> {
     // Generate UUID for new example
     let new_id = uuid::Uuid::new_v4().to_string();
 
     // Calculate word count
     let word_count = calculate_word_count(&request.before_content);
 
-    // Get or initialize database (persists across updates)
-    let db_path = get_or_initialize_database(&app)?;
-
-    // Open database connection
-    let conn = Connection::open(&db_path)
-        .map_err(|e| format!("Failed to open database: {}", e))?;
-
-    // Begin transaction
-    conn.execute("BEGIN TRANSACTION", [])
-        .map_err(|e| format!("Failed to begin transaction: {}", e))?;
-
     // Insert into example_scripts
     let tags_str = request
         .metadata
         .tags
+        .as_ref()
         .map(|tags| tags.join(","))
         .unwrap_or_default();
 
@@ -752,12 +1236,8 @@ pub async fn upload_example(
     )
     .map_err(|e| format!("Failed to insert example: {}", e))?;
 
-    // Convert embedding to binary
-    let embedding_bytes: Vec<u8> = request
-        .embedding
-        .iter()
-        .flat_map(|f| f.to_le_bytes().to_vec())
-        .collect();
+    // Store unit-length embeddings so normalized databases stay normalized
+    let embedding_bytes = vec_f32_to_blob(&normalize_embedding(&request.embedding));
 
     // Insert embedding
     conn.execute(
@@ -766,13 +1246,109 @@ pub async fn upload_example(
     )
     .map_err(|e| format!("Failed to insert embedding: {}", e))?;
 
+    Ok(new_id)
+}
+
+/// Insert several examples in one transaction, rolling back all of them if any fails
+pub(crate) fn insert_examples(
+    conn: &Connection,
+    requests: &[UploadExampleRequest],
+) -> Result<Vec<String>, String> {
+    // Begin transaction
+    conn.execute("BEGIN TRANSACTION", [])
+        .map_err(|e| format!("Failed to begin transaction: {}", e))?;
+
+    let inserted = requests
+        .iter()
+        .enumerate()
+        .map(|(index, request)| {
+            insert_example(conn, request).map_err(|e| {
+                format!(
+                    "Example {} ('{}'): {}",
+                    index + 1,
+                    request.metadata.title,
+                    e
+                )
+            })
+        })
+        .collect::<Result<Vec<String>, String>>();
+
+    let ids = match inserted {
+        Ok(ids) => ids,
+        Err(e) => {
+            let _ = conn.execute("ROLLBACK", []);
+            return Err(e);
+        }
+    };
+
     // Commit transaction
     conn.execute("COMMIT", [])
         .map_err(|e| format!("Failed to commit transaction: {}", e))?;
 
+    Ok(ids)
+}
+
+#[tauri::command]
+pub async fn upload_example(
+    app: tauri::AppHandle,
+    request: UploadExampleRequest,
+) -> Result<String, String> {
+    // Validate inputs
+    validate_upload_request(&request)?;
+
+    // Get or initialize database (persists across updates)
+    let db_path = get_or_initialize_database(&app)?;
+
+    // Open database connection
+    let conn = Connection::open(&db_path)
+        .map_err(|e| format!("Failed to open database: {}", e))?;
+
+    let new_id = insert_examples(&conn, std::slice::from_ref(&request))?.remove(0);
+
+    invalidate_embedding_index();
+
     Ok(new_id)
 }
 
+/// Upload a pack of examples atomically: all are validated first, then inserted
+/// in a single transaction. Returns the new ids in the same order as the requests.
+#[tauri::command]
+pub async fn upload_examples(
+    app: tauri::AppHandle,
+    requests: Vec<UploadExampleRequest>,
+) -> Result<Vec<String>, String> {
+    // Validate every entry before writing anything
+    for (index, request) in requests.iter().enumerate() {
+        validate_upload_request(request).map_err(|e| {
+            format!(
+                "Example {} ('{}'): {}",
+                index + 1,
+                request.metadata.title,
+                e
+            )
+        })?;
+    }
+
+    if requests.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    // Get or initialize database (persists across updates)
+    let db_path = get_or_initialize_database(&app)?;
+
+    // Open database connection
+    let conn = Connection::open(&db_path)
+        .map_err(|e| format!("Failed to open database: {}", e))?;
+
+    let ids = insert_examples(&conn, &requests)?;
+
+    invalidate_embedding_index();
+
+    println!("[RAG] Uploaded {} examples", ids.len());
+
+    Ok(ids)
+}
+
 // ============================================================================
 // T024: Replace Example Command
 // ============================================================================
@@ -827,12 +1403,8 @@ pub async fn replace_example(
     )
     .map_err(|e| format!("Failed to update example: {}", e))?;
 
-    // Convert embedding to binary
-    let embedding_bytes: Vec<u8> = request
-        .embedding
-        .iter()
-        .flat_map(|f| f.to_le_bytes().to_vec())
-        .collect();
+    // Store unit-length embeddings so normalized databases stay normalized
+    let embedding_bytes = vec_f32_to_blob(&normalize_embedding(&request.embedding));
 
     // Update embedding
     conn.execute(
@@ -845,6 +1417,8 @@ pub async fn replace_example(
     conn.execute("COMMIT", [])
         .map_err(|e| format!("Failed to commit transaction: {}", e))?;
 
+    invalidate_embedding_index();
+
     Ok(())
 }
 
@@ -890,5 +1464,371 @@ pub async fn delete_example(app: tauri::AppHandle, id: String) -> Result<(), Str
     conn.execute("COMMIT", [])
         .map_err(|e| format!("Failed to commit transaction: {}", e))?;
 
+    invalidate_embedding_index();
+
     Ok(())
 }
+
+// ============================================================================
+// Database Maintenance
+// ============================================================================
+
+/// Result of compacting and re-analyzing the examples database
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DbOptimizeResult {
+    pub size_before_bytes: u64,
+    pub size_after_bytes: u64,
+    pub bytes_reclaimed: u64,
+}
+
+#[tauri::command]
+pub async fn optimize_examples_database(app: tauri::AppHandle) -> Result<DbOptimizeResult, String> {
+    // Get or initialize database (persists across updates)
+    let db_path = get_or_initialize_database(&app)?;
+
+    // VACUUM rewrites the whole file, so keep it off the async runtime
+    tokio::task::spawn_blocking(move || {
+        let size_before_bytes = fs::metadata(&db_path)
+            .map_err(|e| format!("Failed to read database size: {}", e))?
+            .len();
+
+        let conn = Connection::open(&db_path)
+            .map_err(|e| format!("Failed to open database: {}", e))?;
+
+        conn.execute_batch("REINDEX; ANALYZE; VACUUM;")
+            .map_err(|e| format!("Failed to optimize database: {}", e))?;
+
+        drop(conn);
+
+        let size_after_bytes = fs::metadata(&db_path)
+            .map_err(|e| format!("Failed to read database size: {}", e))?
+            .len();
+
+        println!(
+            "[RAG] Database optimized: {} bytes -> {} bytes",
+            size_before_bytes, size_after_bytes
+        );
+
+        Ok(DbOptimizeResult {
+            size_before_bytes,
+            size_after_bytes,
+            bytes_reclaimed: size_before_bytes.saturating_sub(size_after_bytes),
+        })
+    })
+    .await
+    .map_err(|e| format!("Optimize task failed: {}", e))?
+}
+
+/// Summary of the examples database for the library health panel
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DatabaseStats {
+    pub total_examples: i64,
+    /// Example counts keyed by source ("bundled", "user-uploaded")
+    pub by_source: BTreeMap<String, i64>,
+    pub by_category: BTreeMap<String, i64>,
+    /// Embedding counts keyed by dimension
+    pub by_dimension: BTreeMap<usize, i64>,
+    /// Quality stats cover rated examples only
+    pub min_quality: Option<i32>,
+    pub max_quality: Option<i32>,
+    pub avg_quality: Option<f64>,
+    pub unrated_examples: i64,
+    pub file_size_bytes: u64,
+}
+
+/// Count a column's values with GROUP BY
+fn count_by<K: rusqlite::types::FromSql + Ord>(
+    conn: &Connection,
+    sql: &str,
+) -> Result<BTreeMap<K, i64>, String> {
+    let mut stmt = conn
+        .prepare(sql)
+        .map_err(|e| format!("Failed to prepare query: {}", e))?;
+
+    let counts = stmt
+        .query_map([], |row| Ok((row.get::<_, K>(0)?, row.get::<_, i64>(1)?)))
+        .map_err(|e| format!("Failed to execute query: {}", e))?
+        .collect::<Result<BTreeMap<_, _>, _>>()
+        .map_err(|e| format!("Failed to read row: {}", e))?;
+
+    Ok(counts)
+}
+
+/// Gather stats for an open examples database stored at db_path
+pub(crate) fn collect_database_stats(
+    conn: &Connection,
+    db_path: &Path,
+) -> Result<DatabaseStats, String> {
+    let (total_examples, min_quality, max_quality, avg_quality, rated): (
+        i64,
+        Option<i32>,
+        Option<i32>,
+        Option<f64>,
+        i64,
+    ) = conn
+        .query_row(
+            "SELECT COUNT(*), MIN(quality_score), MAX(quality_score), AVG(quality_score),
+                    COUNT(quality_score)
+             FROM example_scripts",
+            [],
+            |row| {
+                Ok((
+                    row.get(0)?,
+                    row.get(1)?,
+                    row.get(2)?,
+                    row.get(3)?,
+                    row.get(4)?,
+                ))
+            },
+        )
+        .map_err(|e| format!("Failed to read example stats: {}", e))?;
+
+    let file_size_bytes = fs::metadata(db_path)
+        .map_err(|e| format!("Failed to read database size: {}", e))?
+        .len();
+
+    Ok(DatabaseStats {
+        total_examples,
+        by_source: count_by(
+            conn,
+            "SELECT COALESCE(source, 'bundled'), COUNT(*) FROM example_scripts GROUP BY 1",
+        )?,
+        by_category: count_by(
+            conn,
+            "SELECT category, COUNT(*) FROM example_scripts GROUP BY category",
+        )?,
+        by_dimension: count_by(
+            conn,
+            "SELECT dimension, COUNT(*) FROM embeddings GROUP BY dimension",
+        )?,
+        min_quality,
+        max_quality,
+        avg_quality,
+        unrated_examples: total_examples - rated,
+        file_size_bytes,
+    })
+}
+
+#[tauri::command]
+pub async fn get_database_stats(app: tauri::AppHandle) -> Result<DatabaseStats, String> {
+    // Get or initialize database (persists across updates)
+    let db_path = get_or_initialize_database(&app)?;
+
+    let conn = Connection::open(&db_path).map_err(|e| format!("Failed to open database: {}", e))?;
+
+    collect_database_stats(&conn, &db_path)
+}
+
+/// Rewrite every stored embedding at unit length and mark the database as normalized
+/// Returns the number of embeddings rewritten; a no-op once the flag is set
+pub(crate) fn normalize_stored_embeddings(conn: &Connection) -> Result<usize, String> {
+    if embeddings_normalized(conn) {
+        return Ok(0);
+    }
+
+    let mut stmt = conn
+        .prepare("SELECT script_id, embedding FROM embeddings")
+        .map_err(|e| format!("Failed to prepare query: {}", e))?;
+
+    let embeddings: Vec<(String, Vec<u8>)> = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+        .map_err(|e| format!("Failed to query database: {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to read row: {}", e))?;
+
+    // Begin transaction
+    conn.execute("BEGIN TRANSACTION", [])
+        .map_err(|e| format!("Failed to begin transaction: {}", e))?;
+
+    let rewritten = embeddings
+        .iter()
+        .try_for_each(|(id, blob)| {
+            let normalized = normalize_embedding(&blob_to_vec_f32(blob));
+            conn.execute(
+                "UPDATE embeddings SET embedding = ? WHERE script_id = ?",
+                params![vec_f32_to_blob(&normalized), id],
+            )
+            .map(|_| ())
+            .map_err(|e| format!("Failed to update embedding {}: {}", id, e))
+        })
+        .and_then(|_| {
+            conn.execute(
+                "INSERT OR REPLACE INTO db_metadata (key, value, updated_at)
+                 VALUES (?, 'true', CURRENT_TIMESTAMP)",
+                params![NORMALIZED_METADATA_KEY],
+            )
+            .map(|_| ())
+            .map_err(|e| format!("Failed to record normalized flag: {}", e))
+        });
+
+    if let Err(e) = rewritten {
+        let _ = conn.execute("ROLLBACK", []);
+        return Err(e);
+    }
+
+    // Commit transaction
+    conn.execute("COMMIT", [])
+        .map_err(|e| format!("Failed to commit transaction: {}", e))?;
+
+    Ok(embeddings.len())
+}
+
+/// One-time upgrade so searches can use the dot-product fast path
+#[tauri::command]
+pub async fn normalize_existing_embeddings(app: tauri::AppHandle) -> Result<usize, String> {
+    // Get or initialize database (persists across updates)
+    let db_path = get_or_initialize_database(&app)?;
+
+    let conn = Connection::open(&db_path).map_err(|e| format!("Failed to open database: {}", e))?;
+
+    let count = normalize_stored_embeddings(&conn)?;
+
+    invalidate_embedding_index();
+
+    println!("[RAG] Normalized {} embeddings", count);
+
+    Ok(count)
+}
+
+// ============================================================================
+// Backend Embedding
+// ============================================================================
+
+/// Semantic search from plain text, embedding the query with the given provider
+/// Uses the same provider path as uploads so query and stored dimensions match
+#[tauri::command]
+pub async fn search_scripts_by_text(
+    app: tauri::AppHandle,
+    query: String,
+    provider_url: String,
+    model: String,
+    top_k: usize,
+) -> Result<Vec<SimilarExample>, String> {
+    if query.trim().is_empty() {
+        return Err("Query cannot be empty".to_string());
+    }
+
+    let query_embedding = request_embedding(&query, &provider_url, &model, None).await?;
+
+    search_similar_scripts(app, query_embedding, top_k, None, None, None).await
+}
+
+/// Outcome of re-embedding the examples library
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ReembedResult {
+    pub total: usize,
+    pub succeeded: usize,
+    pub failed: Vec<ReembedFailure>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ReembedFailure {
+    pub id: String,
+    pub title: String,
+    pub error: String,
+}
+
+/// Regenerate every example's embedding with a new provider/model
+/// A failed example keeps its old embedding and the run carries on; the new
+/// embeddings are then written in one transaction, so the index never mixes
+/// models because of a write failure partway through
+#[tauri::command]
+pub async fn reembed_all_examples(
+    app: tauri::AppHandle,
+    provider_url: String,
+    model: String,
+    auth_header: Option<String>,
+) -> Result<ReembedResult, String> {
+    // Get or initialize database (persists across updates)
+    let db_path = get_or_initialize_database(&app)?;
+
+    // Open database connection
+    let mut conn = Connection::open(&db_path)
+        .map_err(|e| format!("Failed to open database: {}", e))?;
+
+    let examples = {
+        let mut stmt = conn
+            .prepare("SELECT id, title, before_text FROM example_scripts ORDER BY created_at")
+            .map_err(|e| format!("Failed to query examples: {}", e))?;
+
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                ))
+            })
+            .map_err(|e| format!("Failed to read examples: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to collect examples: {}", e))?;
+        rows
+    };
+
+    let total = examples.len();
+    let mut result = ReembedResult {
+        total,
+        succeeded: 0,
+        failed: Vec::new(),
+    };
+
+    let mut embeddings = Vec::new();
+    for (index, (id, title, before_text)) in examples.into_iter().enumerate() {
+        let _ = app.emit(
+            "rag_reembed_progress",
+            serde_json::json!({
+                "processed": index,
+                "total": total,
+                "currentTitle": title
+            }),
+        );
+
+        match request_embedding(&before_text, &provider_url, &model, auth_header.as_deref()).await {
+            Ok(embedding) => embeddings.push((id, embedding)),
+            Err(error) => {
+                println!("[RAG] Failed to re-embed {}: {}", title, error);
+                result.failed.push(ReembedFailure { id, title, error });
+            }
+        }
+    }
+
+    // INSERT OR REPLACE also gives an embedding to any example that was missing one
+    if !embeddings.is_empty() {
+        let tx = conn
+            .transaction()
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+        for (id, embedding) in &embeddings {
+            tx.execute(
+                "INSERT OR REPLACE INTO embeddings (script_id, embedding, dimension) VALUES (?, ?, ?)",
+                params![id, vec_f32_to_blob(&normalize_embedding(embedding)), embedding.len()],
+            )
+            .map_err(|e| format!("Failed to update embedding for {}: {}", id, e))?;
+        }
+        tx.commit()
+            .map_err(|e| format!("Failed to save embeddings: {}", e))?;
+
+        result.succeeded = embeddings.len();
+        invalidate_embedding_index();
+    }
+
+    let _ = app.emit(
+        "rag_reembed_progress",
+        serde_json::json!({
+            "processed": total,
+            "total": total,
+            "currentTitle": null
+        }),
+    );
+
+    println!(
+        "[RAG] Re-embed complete: {} succeeded, {} failed",
+        result.succeeded,
+        result.failed.len()
+    );
+
+    Ok(result)
+}
@@ -1,4 +1,9 @@
-use app_lib::media::SproutVideoDetails;
+use crate::baker::extract_sprout_video_id;
+use crate::state::{
+    push_upload_log, update_upload_log, ConfigState, UploadDiagnostics, UploadLogEntry,
+    UploadState, UploadStats, UploadTracker,
+};
+use app_lib::media::{SproutFolder, SproutFolderPage, SproutVideoDetails};
 use bytes::Bytes;
 use futures_util::stream::unfold;
 use futures_util::TryStreamExt;
@@ -8,13 +13,15 @@ use serde_json::Value;
 use std::fs::File;
 use std::path::Path;
 use std::pin::Pin;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex as StdMutex};
 use std::task::{Context, Poll};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tauri::Emitter;
-use tauri::{command, AppHandle};
+use tauri::{command, AppHandle, State};
 use tokio::io::{AsyncRead, AsyncReadExt, BufReader};
 use tokio::sync::Mutex;
+use uuid::Uuid;
 
 #[command]
 pub async fn get_folders(
@@ -39,19 +46,454 @@ pub async fn get_folders(
     Ok(json)
 }
 
+/// Largest page size the Sprout list endpoints accept
+const SPROUT_MAX_PER_PAGE: u32 = 100;
+
+/// Lists one page of Sprout folders under `parent_id` (root folders when None)
+///
+/// Sprout has no name search, so `name_contains` filters the returned page
+/// case-insensitively; `total` still counts every folder in the parent.
 #[command]
-pub fn upload_video(
+pub async fn get_sprout_folders(
+    api_key: String,
+    parent_id: Option<String>,
+    page: Option<u32>,
+    per_page: Option<u32>,
+    name_contains: Option<String>,
+) -> Result<SproutFolderPage, String> {
+    let page = page.unwrap_or(1).max(1);
+    let per_page = per_page.unwrap_or(SPROUT_MAX_PER_PAGE);
+    if per_page == 0 || per_page > SPROUT_MAX_PER_PAGE {
+        return Err(format!(
+            "per_page must be between 1 and {} (got {})",
+            SPROUT_MAX_PER_PAGE, per_page
+        ));
+    }
+
+    let client = Client::builder()
+        .timeout(Duration::from_secs(30))
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    let mut query = vec![
+        ("page", page.to_string()),
+        ("per_page", per_page.to_string()),
+    ];
+    if let Some(parent_id) = parent_id {
+        query.push(("parent_id", parent_id));
+    }
+
+    let response = client
+        .get("https://api.sproutvideo.com/v1/folders")
+        .header("SproutVideo-Api-Key", &api_key)
+        .query(&query)
+        .send()
+        .await
+        .map_err(|e| format!("API request failed: {}", e))?;
+
+    let status = response.status();
+
+    if status == 401 {
+        return Err("Unauthorized: Invalid Sprout Video API key".to_string());
+    }
+
+    if !status.is_success() {
+        return Err(format!("API returned error: {}", status));
+    }
+
+    let mut page_json: Value = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse response: {}", e))?;
+
+    let folders_json = page_json
+        .get_mut("folders")
+        .map(Value::take)
+        .unwrap_or_else(|| Value::Array(Vec::new()));
+    let mut folders: Vec<SproutFolder> = serde_json::from_value(folders_json)
+        .map_err(|e| format!("Failed to parse folders: {}", e))?;
+
+    if let Some(filter) = name_contains
+        .map(|name| name.trim().to_lowercase())
+        .filter(|name| !name.is_empty())
+    {
+        folders.retain(|folder| folder.name.to_lowercase().contains(&filter));
+    }
+
+    Ok(SproutFolderPage {
+        total: page_json["total"].as_u64().unwrap_or(folders.len() as u64),
+        has_next_page: !page_json["next_page"].is_null(),
+        folders,
+        page,
+    })
+}
+
+/// MIME type for a video file, from its extension
+///
+/// Unknown extensions upload as application/octet-stream and Sprout works out the
+/// format; extensions that are clearly not video are rejected before uploading.
+pub(crate) fn video_mime_type(path: &Path) -> Result<&'static str, String> {
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_lowercase())
+        .unwrap_or_default();
+
+    let mime_type = match extension.as_str() {
+        "mp4" | "m4v" => "video/mp4",
+        "mov" | "qt" => "video/quicktime",
+        "mkv" => "video/x-matroska",
+        "webm" => "video/webm",
+        "avi" => "video/x-msvideo",
+        "wmv" => "video/x-ms-wmv",
+        "flv" => "video/x-flv",
+        "mpg" | "mpeg" => "video/mpeg",
+        "mts" | "m2ts" => "video/mp2t",
+        "3gp" => "video/3gpp",
+        "mxf" => "application/mxf",
+        "jpg" | "jpeg" | "png" | "gif" | "bmp" | "tif" | "tiff" | "heic" | "webp" | "psd"
+        | "mp3" | "wav" | "aac" | "flac" | "m4a" | "aif" | "aiff" | "pdf" | "doc" | "docx"
+        | "txt" | "rtf" | "zip" | "rar" | "7z" | "prproj" | "aep" => {
+            return Err(format!(
+                "{} is not a video file (.{}). Choose a video to upload.",
+                path.file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_else(|| path.display().to_string()),
+                extension
+            ));
+        }
+        _ => "application/octet-stream",
+    };
+
+    Ok(mime_type)
+}
+
+/// Error returned when an upload is stopped by `cancel_upload`
+const UPLOAD_CANCELLED_ERROR: &str = "Upload cancelled";
+
+const UPLOAD_DEFAULT_BUFFER_SIZE_KB: usize = 64;
+const UPLOAD_MAX_BUFFER_SIZE_KB: usize = 16 * 1024;
+
+/// Optional tuning for `upload_video`
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct UploadOptions {
+    /// Id for the upload; generated when not given
+    pub upload_id: Option<String>,
+    /// Total attempts for transient failures (default 3)
+    pub max_attempts: Option<u32>,
+    /// Whole-request timeout; defaults to the uploadTimeoutMinutes setting
+    pub timeout_minutes: Option<u64>,
+    /// Size of each chunk read from disk (default 64 KB)
+    pub buffer_size_kb: Option<usize>,
+}
+
+/// UploadOptions with defaults applied
+struct UploadSettings {
+    max_attempts: u32,
+    timeout: Duration,
+    buffer_size: usize,
+}
+
+impl UploadOptions {
+    fn resolve(&self, default_timeout_minutes: u64) -> Result<UploadSettings, String> {
+        let max_attempts = self.max_attempts.unwrap_or(UPLOAD_DEFAULT_MAX_ATTEMPTS);
+        if max_attempts == 0 {
+            return Err("maxAttempts must be at least 1".to_string());
+        }
+
+        let timeout_minutes = self.timeout_minutes.unwrap_or(default_timeout_minutes);
+        if timeout_minutes == 0 {
+            return Err("timeoutMinutes must be at least 1".to_string());
+        }
+
+        let buffer_size_kb = self.buffer_size_kb.unwrap_or(UPLOAD_DEFAULT_BUFFER_SIZE_KB);
+        if buffer_size_kb == 0 || buffer_size_kb > UPLOAD_MAX_BUFFER_SIZE_KB {
+            return Err(format!(
+                "bufferSizeKb must be between 1 and {} (got {})",
+                UPLOAD_MAX_BUFFER_SIZE_KB, buffer_size_kb
+            ));
+        }
+
+        Ok(UploadSettings {
+            max_attempts,
+            timeout: Duration::from_secs(timeout_minutes * 60),
+            buffer_size: buffer_size_kb * 1024,
+        })
+    }
+}
+
+/// Uploads a video and resolves with the new Sprout video once the upload finishes
+///
+/// The upload id is sent in an `upload_started` event and can be passed to
+/// `get_upload_stats` or `cancel_upload` while the upload runs. Callers that
+/// need the id before the event can supply their own in `options`. Transient
+/// failures restart the upload up to `maxAttempts` times, with an `upload_retry`
+/// event before each retry. Progress, completion and error events are still
+/// emitted as the upload proceeds.
+#[command]
+pub async fn upload_video(
     app_handle: AppHandle,
     file_path: String,
     api_key: String,
     folder_id: Option<String>,
-) {
-    tauri::async_runtime::spawn(async move {
-        match upload_video_task(app_handle, file_path, api_key, folder_id).await {
-            Ok(_) => println!("Upload successful"),
-            Err(err) => println!("Upload failed: {}", err),
+    options: Option<UploadOptions>,
+    state: State<'_, UploadState>,
+    config: State<'_, ConfigState>,
+) -> Result<SproutVideoDetails, String> {
+    let options = options.unwrap_or_default();
+    let default_timeout_minutes = config
+        .config
+        .lock()
+        .map_err(|_| "Failed to acquire lock")?
+        .upload_timeout_minutes;
+    let settings = options.resolve(default_timeout_minutes)?;
+
+    // Catch the wrong file before anything is sent
+    video_mime_type(Path::new(&file_path))?;
+
+    let upload_id = options
+        .upload_id
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+    let tracker = Arc::new(StdMutex::new(UploadTracker::new()));
+    let cancelled = Arc::new(AtomicBool::new(false));
+
+    {
+        let mut cancellations = state
+            .cancellations
+            .lock()
+            .map_err(|_| "Failed to acquire lock")?;
+        if cancellations.contains_key(&upload_id) {
+            return Err(format!("Upload {} is already in progress", upload_id));
+        }
+        cancellations.insert(upload_id.clone(), cancelled.clone());
+    }
+
+    if let Ok(mut uploads) = state.uploads.lock() {
+        uploads.insert(upload_id.clone(), tracker.clone());
+    }
+
+    push_upload_log(
+        &state.log,
+        UploadLogEntry {
+            upload_id: upload_id.clone(),
+            file_name: Path::new(&file_path)
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_else(|| file_path.clone()),
+            file_path: file_path.clone(),
+            video_id: None,
+            title: None,
+            thumbnail_url: None,
+            status: "uploading".to_string(),
+            error: None,
+            started_at: chrono::Utc::now().to_rfc3339(),
+            finished_at: None,
+            bytes: 0,
+        },
+    );
+
+    let _ = app_handle.emit(
+        "upload_started",
+        serde_json::json!({
+            "uploadId": upload_id,
+            "filePath": file_path
+        }),
+    );
+
+    let result = upload_video_task(
+        app_handle,
+        file_path,
+        api_key,
+        folder_id,
+        tracker.clone(),
+        cancelled.clone(),
+        &settings,
+    )
+    .await;
+
+    if let Ok(mut cancellations) = state.cancellations.lock() {
+        cancellations.remove(&upload_id);
+    }
+
+    let bytes = match tracker.lock() {
+        Ok(mut tracker) => {
+            tracker.finish();
+            tracker.stats(&upload_id).bytes_uploaded
+        }
+        Err(_) => 0,
+    };
+
+    update_upload_log(&state.log, &upload_id, |entry| {
+        entry.finished_at = Some(chrono::Utc::now().to_rfc3339());
+        entry.bytes = bytes;
+
+        match &result {
+            Ok(response) => {
+                entry.status = "completed".to_string();
+                entry.video_id = response["id"].as_str().map(|id| id.to_string());
+                entry.title = response["title"].as_str().map(|title| title.to_string());
+                entry.thumbnail_url = response["assets"]["poster_frames"][0]
+                    .as_str()
+                    .map(|url| url.to_string());
+            }
+            Err(_) if cancelled.load(Ordering::SeqCst) => {
+                entry.status = "cancelled".to_string();
+            }
+            Err(err) => {
+                entry.status = "failed".to_string();
+                entry.error = Some(err.clone());
+            }
         }
     });
+
+    match &result {
+        Ok(_) => println!("Upload successful"),
+        Err(err) => println!("Upload failed: {}", err),
+    }
+
+    serde_json::from_value(result?)
+        .map_err(|e| format!("Upload succeeded but the response could not be read: {}", e))
+}
+
+/// Stops an in-progress upload; `upload_video` then fails with "Upload cancelled"
+#[command]
+pub fn cancel_upload(
+    app_handle: AppHandle,
+    upload_id: String,
+    state: State<'_, UploadState>,
+) -> Result<(), String> {
+    let cancellations = state
+        .cancellations
+        .lock()
+        .map_err(|_| "Failed to acquire lock")?;
+
+    let cancelled = cancellations
+        .get(&upload_id)
+        .ok_or_else(|| "Upload ID not found or already finished".to_string())?;
+
+    cancelled.store(true, Ordering::SeqCst);
+    println!("Upload cancelled: {}", upload_id);
+
+    let _ = app_handle.emit(
+        "upload_cancelled",
+        serde_json::json!({ "uploadId": upload_id }),
+    );
+
+    Ok(())
+}
+
+/// Returns the most recent uploads this session, newest first
+#[command]
+pub fn get_recent_uploads(
+    limit: usize,
+    state: State<'_, UploadState>,
+) -> Result<Vec<UploadLogEntry>, String> {
+    let log = state.log.lock().map_err(|_| "Failed to acquire lock")?;
+
+    Ok(log.iter().rev().take(limit).cloned().collect())
+}
+
+/// Returns average, peak and current throughput for an upload
+#[command]
+pub fn get_upload_stats(
+    upload_id: String,
+    state: State<'_, UploadState>,
+) -> Result<UploadStats, String> {
+    let uploads = state.uploads.lock().map_err(|_| "Failed to acquire lock")?;
+
+    let tracker = uploads
+        .get(&upload_id)
+        .ok_or_else(|| "Upload ID not found".to_string())?;
+
+    let tracker = tracker.lock().map_err(|_| "Failed to acquire lock")?;
+    Ok(tracker.stats(&upload_id))
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadEstimate {
+    pub file_size_bytes: u64,
+    pub throughput_mbps: Option<f64>,
+    /// "measured" (caller supplied) or "historical" (earlier uploads)
+    pub throughput_source: Option<String>,
+    pub estimated_seconds: Option<f64>,
+}
+
+/// Average throughput of finished uploads this session, in megabits per second
+fn historical_upload_mbps(state: &UploadState) -> Option<f64> {
+    let uploads = state.uploads.lock().ok()?;
+
+    let rates: Vec<f64> = uploads
+        .iter()
+        .filter_map(|(id, tracker)| tracker.lock().ok().map(|tracker| tracker.stats(id)))
+        .filter(|stats| stats.finished && stats.bytes_uploaded > 0)
+        .map(|stats| stats.average_bytes_per_second * 8.0 / 1_000_000.0)
+        .collect();
+
+    if rates.is_empty() {
+        None
+    } else {
+        Some(rates.iter().sum::<f64>() / rates.len() as f64)
+    }
+}
+
+/// Estimates how long uploading a file would take, without uploading it
+///
+/// Uses `measured_mbps` when given, otherwise the average of this session's
+/// finished uploads. With neither, only the file size is returned.
+#[command]
+pub fn estimate_upload(
+    file_path: String,
+    measured_mbps: Option<f64>,
+    state: State<'_, UploadState>,
+) -> Result<UploadEstimate, String> {
+    let file_size_bytes = std::fs::metadata(&file_path)
+        .map_err(|e| format!("Failed to read file {}: {}", file_path, e))?
+        .len();
+
+    let throughput = match measured_mbps.filter(|mbps| *mbps > 0.0) {
+        Some(mbps) => Some((mbps, "measured")),
+        None => historical_upload_mbps(&state).map(|mbps| (mbps, "historical")),
+    };
+
+    Ok(UploadEstimate {
+        file_size_bytes,
+        throughput_mbps: throughput.map(|(mbps, _)| mbps),
+        throughput_source: throughput.map(|(_, source)| source.to_string()),
+        estimated_seconds: throughput
+            .map(|(mbps, _)| (file_size_bytes as f64 * 8.0) / (mbps * 1_000_000.0)),
+    })
+}
+
+/// Returns per-10MB-window stall diagnostics for an upload
+#[command]
+pub fn get_upload_diagnostics(
+    upload_id: String,
+    state: State<'_, UploadState>,
+) -> Result<UploadDiagnostics, String> {
+    let uploads = state.uploads.lock().map_err(|_| "Failed to acquire lock")?;
+
+    let tracker = uploads
+        .get(&upload_id)
+        .ok_or_else(|| "Upload ID not found".to_string())?;
+
+    let tracker = tracker.lock().map_err(|_| "Failed to acquire lock")?;
+    Ok(tracker.diagnostics(&upload_id))
+}
+
+/// Minimum time between `upload_progress` events
+const PROGRESS_UPDATE_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Payload of the `upload_progress` event
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadProgress {
+    pub percentage: f64,
+    pub bytes_uploaded: u64,
+    pub total_bytes: u64,
+    /// Based on the rolling transfer rate; None until the first rate sample
+    pub seconds_remaining: Option<f64>,
 }
 
 // Async Progress Tracking Reader using Tokio's AsyncRead API (with ReadBuf)
@@ -60,6 +502,49 @@ pub struct ProgressReader<R> {
     progress: Arc<Mutex<u64>>,
     total_size: u64,
     app_handle: AppHandle,
+    throughput: Arc<StdMutex<UploadTracker>>,
+    last_progress_update: Option<Instant>,
+}
+
+impl<R> ProgressReader<R> {
+    /// Emit an `upload_progress` event, at most once per PROGRESS_UPDATE_INTERVAL
+    /// apart from the final update
+    fn emit_progress(&mut self, bytes_uploaded: u64) {
+        let finished = bytes_uploaded >= self.total_size;
+        let due = self
+            .last_progress_update
+            .is_none_or(|last| last.elapsed() >= PROGRESS_UPDATE_INTERVAL);
+        if !due && !finished {
+            return;
+        }
+        self.last_progress_update = Some(Instant::now());
+
+        let percentage = if self.total_size > 0 {
+            (bytes_uploaded as f64 / self.total_size as f64) * 100.0
+        } else {
+            100.0
+        };
+        let seconds_remaining = self
+            .throughput
+            .lock()
+            .ok()
+            .and_then(|tracker| tracker.recent_bytes_per_second())
+            .filter(|rate| *rate > 0.0)
+            .map(|rate| self.total_size.saturating_sub(bytes_uploaded) as f64 / rate);
+
+        println!("Upload progress: {:.2}%", percentage);
+
+        // Emit progress event to frontend
+        let payload = UploadProgress {
+            percentage,
+            bytes_uploaded,
+            total_bytes: self.total_size,
+            seconds_remaining,
+        };
+        if let Err(e) = self.app_handle.emit("upload_progress", payload) {
+            eprintln!("Failed to emit progress event: {}", e);
+        }
+    }
 }
 
 impl<R: AsyncRead + Unpin> AsyncRead for ProgressReader<R> {
@@ -77,23 +562,26 @@ impl<R: AsyncRead + Unpin> AsyncRead for ProgressReader<R> {
             let post_filled = buf.filled().len();
             let bytes_read = post_filled - pre_filled;
             if bytes_read > 0 {
+                if let Ok(mut tracker) = self.throughput.lock() {
+                    tracker.record_bytes(bytes_read as u64);
+                }
+
                 // Use try_lock but with better error handling
-                match self.progress.try_lock() {
+                let bytes_uploaded = match self.progress.try_lock() {
                     Ok(mut progress_guard) => {
                         *progress_guard += bytes_read as u64;
-                        let percentage = (*progress_guard as f64 / self.total_size as f64) * 100.0;
-                        println!("Upload progress: {:.2}%", percentage);
-
-                        // Emit progress event to frontend
-                        if let Err(e) = self.app_handle.emit("upload_progress", percentage as u32) {
-                            eprintln!("Failed to emit progress event: {}", e);
-                        }
+                        Some(*progress_guard)
                     }
                     Err(_) => {
                         // Progress update skipped due to lock contention
                         // This is acceptable for progress reporting - we'll catch up on the next read
                         eprintln!("Progress update skipped due to lock contention");
+                        None
                     }
+                };
+
+                if let Some(bytes_uploaded) = bytes_uploaded {
+                    self.emit_progress(bytes_uploaded);
                 }
             }
         }
@@ -101,16 +589,112 @@ impl<R: AsyncRead + Unpin> AsyncRead for ProgressReader<R> {
     }
 }
 
-// Upload function that streams file data with progress tracking
+const UPLOAD_DEFAULT_MAX_ATTEMPTS: u32 = 3;
+const UPLOAD_RETRY_BASE_DELAY_SECS: u64 = 5;
+// How often the retry backoff checks whether the upload was cancelled
+const UPLOAD_RETRY_CANCEL_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Why a single upload attempt failed
+enum UploadAttemptError {
+    /// Network failures and 5xx/429 responses, worth restarting the upload for
+    Transient(String),
+    Fatal(String),
+}
+
+// Upload with retries: SproutVideo has no resumable upload API, so an interrupted
+// upload is restarted from the beginning, with exponential backoff between attempts
 async fn upload_video_task(
     app_handle: AppHandle,
     file_path: String,
     api_key: String,
     folder_id: Option<String>,
-) -> Result<(), String> {
+    throughput: Arc<StdMutex<UploadTracker>>,
+    cancelled: Arc<AtomicBool>,
+    settings: &UploadSettings,
+) -> Result<Value, String> {
+    let max_attempts = settings.max_attempts;
+    let mut attempt = 1;
+    loop {
+        // Each attempt re-sends the whole file, so start its stats from zero
+        if attempt > 1 {
+            if let Ok(mut tracker) = throughput.lock() {
+                *tracker = UploadTracker::new();
+            }
+        }
+
+        let retryable_error = match upload_video_attempt(
+            &app_handle,
+            &file_path,
+            &api_key,
+            folder_id.as_deref(),
+            throughput.clone(),
+            cancelled.clone(),
+            settings,
+        )
+        .await
+        {
+            Ok(response_json) => {
+                println!("Upload complete!");
+                let _ = app_handle.emit("upload_complete", response_json.clone());
+                return Ok(response_json);
+            }
+            Err(UploadAttemptError::Transient(e)) if attempt < max_attempts => e,
+            Err(UploadAttemptError::Transient(e)) | Err(UploadAttemptError::Fatal(e)) => {
+                // cancel_upload has already emitted upload_cancelled
+                if !cancelled.load(Ordering::SeqCst) {
+                    let _ = app_handle.emit("upload_error", e.clone());
+                }
+                return Err(e);
+            }
+        };
+
+        let delay = UPLOAD_RETRY_BASE_DELAY_SECS * 2u64.pow(attempt - 1);
+        println!(
+            "Upload failed ({}), retrying in {}s (attempt {}/{})",
+            retryable_error, delay, attempt, max_attempts
+        );
+        let _ = app_handle.emit(
+            "upload_retry",
+            serde_json::json!({
+                "attempt": attempt,
+                "maxAttempts": max_attempts,
+                "delaySeconds": delay,
+                "error": retryable_error
+            }),
+        );
+
+        // Wait in short slices so cancelling during the backoff takes effect promptly
+        let retry_at = Instant::now() + Duration::from_secs(delay);
+        loop {
+            if cancelled.load(Ordering::SeqCst) {
+                return Err(UPLOAD_CANCELLED_ERROR.to_string());
+            }
+            let remaining = retry_at.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                break;
+            }
+            tokio::time::sleep(remaining.min(UPLOAD_RETRY_CANCEL_POLL_INTERVAL)).await;
+        }
+        attempt += 1;
+    }
+}
+
+// Upload function that streams file data with progress tracking
+async fn upload_video_attempt(
+    app_handle: &AppHandle,
+    file_path: &str,
+    api_key: &str,
+    folder_id: Option<&str>,
+    throughput: Arc<StdMutex<UploadTracker>>,
+    cancelled: Arc<AtomicBool>,
+    settings: &UploadSettings,
+) -> Result<Value, UploadAttemptError> {
     // Open the file
-    let file = File::open(&file_path).map_err(|e| e.to_string())?;
-    let file_size = file.metadata().map_err(|e| e.to_string())?.len();
+    let file = File::open(file_path).map_err(|e| UploadAttemptError::Fatal(e.to_string()))?;
+    let file_size = file
+        .metadata()
+        .map_err(|e| UploadAttemptError::Fatal(e.to_string()))?
+        .len();
 
     // Convert the file into an async Tokio file and wrap it in a BufReader
     let file = tokio::fs::File::from_std(file);
@@ -123,10 +707,12 @@ async fn upload_video_task(
         progress: progress.clone(),
         total_size: file_size,
         app_handle: app_handle.clone(),
+        throughput,
+        last_progress_update: None,
     };
 
     // Extract the original filename
-    let file_name = Path::new(&file_path)
+    let file_name = Path::new(file_path)
         .file_name()
         .and_then(|name| name.to_str())
         .unwrap_or("uploaded_video.mp4")
@@ -134,23 +720,39 @@ async fn upload_video_task(
 
     // Configure client with appropriate timeouts for large file uploads
     let client = Client::builder()
-        .timeout(Duration::from_secs(45 * 60)) // 45 minute timeout for large files
+        .timeout(settings.timeout) // 45 minutes unless configured, for large files
         .connect_timeout(Duration::from_secs(30)) // 30 second connection timeout
         .build()
-        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+        .map_err(|e| UploadAttemptError::Fatal(format!("Failed to create HTTP client: {}", e)))?;
 
     // Wrap the progress_reader into a request body.
     // Body::from_reader() is not available, so we use wrap_stream() with an adapter.
     // Here we convert the ProgressReader into a stream of byte vectors.
-    let stream = unfold(progress_reader, |mut reader| async {
-        let mut buf = vec![0u8; 65536]; // Increased buffer size to 64KB for better performance
-        match reader.read(&mut buf).await {
-            Ok(0) => None,
-            Ok(n) => {
-                buf.truncate(n);
-                Some((Ok::<_, std::io::Error>(buf), reader))
+    // The stream fails once the upload is cancelled, which aborts the request.
+    let stream_cancelled = cancelled.clone();
+    let buffer_size = settings.buffer_size;
+    let stream = unfold(progress_reader, move |mut reader| {
+        let cancelled = stream_cancelled.clone();
+        async move {
+            if cancelled.load(Ordering::SeqCst) {
+                return Some((
+                    Err(std::io::Error::new(
+                        std::io::ErrorKind::Interrupted,
+                        UPLOAD_CANCELLED_ERROR,
+                    )),
+                    reader,
+                ));
+            }
+
+            let mut buf = vec![0u8; buffer_size]; // 64KB by default for better performance
+            match reader.read(&mut buf).await {
+                Ok(0) => None,
+                Ok(n) => {
+                    buf.truncate(n);
+                    Some((Ok::<_, std::io::Error>(buf), reader))
+                }
+                Err(e) => Some((Err(e), reader)),
             }
-            Err(e) => Some((Err(e), reader)),
         }
     })
     // Convert each Vec<u8> into bytes::Bytes.
@@ -161,13 +763,13 @@ async fn upload_video_task(
 
     let part = multipart::Part::stream_with_length(body, file_size)
         .file_name(file_name.clone())
-        .mime_str("video/mp4")
-        .map_err(|e| e.to_string())?;
+        .mime_str(video_mime_type(Path::new(file_path)).map_err(UploadAttemptError::Fatal)?)
+        .map_err(|e| UploadAttemptError::Fatal(e.to_string()))?;
 
     let mut form = multipart::Form::new().part("source_video", part);
     // If a folder_id was provided, add it as a text field.
     if let Some(fid) = folder_id {
-        form = form.text("folder_id", fid);
+        form = form.text("folder_id", fid.to_string());
     }
 
     println!("Starting upload to SproutVideo...");
@@ -178,32 +780,65 @@ async fn upload_video_task(
         .header("SproutVideo-Api-Key", format!("{}", api_key))
         .multipart(form)
         .send()
-        .await
-        .map_err(|e| e.to_string())?;
+        .await;
+
+    let response = match response {
+        Ok(response) => response,
+        Err(_) if cancelled.load(Ordering::SeqCst) => {
+            return Err(UploadAttemptError::Fatal(
+                UPLOAD_CANCELLED_ERROR.to_string(),
+            ))
+        }
+        Err(e) => return Err(UploadAttemptError::Transient(e.to_string())),
+    };
 
     let status = response.status();
-    // Parse the response body as JSON.
-    let response_json: Value = response.json().await.map_err(|e| e.to_string())?;
-    println!("Upload Response: {:?}", response_json);
+    let body = response
+        .text()
+        .await
+        .map_err(|e| UploadAttemptError::Transient(e.to_string()))?;
 
     if status.is_success() {
-        println!("Upload complete!");
-        let _ = app_handle.emit("upload_complete", response_json);
-        Ok(())
+        // Parse the response body as JSON.
+        let response_json: Value =
+            serde_json::from_str(&body).map_err(|e| UploadAttemptError::Fatal(e.to_string()))?;
+        println!("Upload Response: {:?}", response_json);
+        Ok(response_json)
     } else {
+        // Error pages from proxies aren't always JSON, so fall back to the raw body
+        let response_json: Value =
+            serde_json::from_str(&body).unwrap_or_else(|_| Value::String(body));
         let error_message = format!("Upload failed: HTTP {} - {:?}", status, response_json);
-        let _ = app_handle.emit("upload_error", error_message.clone());
-        Err(error_message)
+
+        if status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            Err(UploadAttemptError::Transient(error_message))
+        } else {
+            Err(UploadAttemptError::Fatal(error_message))
+        }
+    }
+}
+
+/// Video id from a Sprout page or embed URL, or a bare video id
+pub(crate) fn sprout_video_id_from_input(input: &str) -> Option<String> {
+    let input = input.trim();
+
+    if !input.is_empty() && input.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Some(input.to_string());
     }
+
+    extract_sprout_video_id(input)
 }
 
-/// Fetches video metadata from Sprout Video API given a video ID
+/// Fetches video metadata from Sprout Video API given a video URL (or bare video ID)
 /// Feature: 004-embed-multiple-video - URL auto-fetch
 #[command]
 pub async fn fetch_sprout_video_details(
-    video_id: String,
+    video_url: String,
     api_key: String,
 ) -> Result<SproutVideoDetails, String> {
+    let video_id = sprout_video_id_from_input(&video_url)
+        .ok_or("Invalid Sprout Video URL format (expected /videos/<id> or /embed/<id>)")?;
+
     let client = Client::builder()
         .timeout(Duration::from_secs(10))
         .build()
@@ -220,6 +855,14 @@ pub async fn fetch_sprout_video_details(
 
     let status = response.status();
 
+    if status == 401 {
+        return Err("Unauthorized: Invalid Sprout Video API key".to_string());
+    }
+
+    if status == 404 {
+        return Err(format!("Video not found: {}", video_id));
+    }
+
     if !status.is_success() {
         return Err(format!("API returned error: {}", status));
     }
@@ -231,3 +874,122 @@ pub async fn fetch_sprout_video_details(
 
     Ok(video_data)
 }
+
+/// Number of videos requested per page from the Sprout videos endpoint
+const SPROUT_VIDEOS_PER_PAGE: u32 = 100;
+
+/// Lists the videos in a Sprout folder
+///
+/// With `page` set, only that page is returned; otherwise pages are followed
+/// until Sprout stops returning a `next_page`.
+#[command]
+pub async fn get_videos_in_folder(
+    api_key: String,
+    folder_id: String,
+    page: Option<u32>,
+) -> Result<Vec<SproutVideoDetails>, String> {
+    let client = Client::builder()
+        .timeout(Duration::from_secs(30))
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    let mut videos = Vec::new();
+    let mut current_page = page.unwrap_or(1);
+
+    loop {
+        let response = client
+            .get("https://api.sproutvideo.com/v1/videos")
+            .header("SproutVideo-Api-Key", &api_key)
+            .query(&[
+                ("folder_id", folder_id.clone()),
+                ("page", current_page.to_string()),
+                ("per_page", SPROUT_VIDEOS_PER_PAGE.to_string()),
+            ])
+            .send()
+            .await
+            .map_err(|e| format!("API request failed: {}", e))?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            return Err(format!("API returned error: {}", status));
+        }
+
+        let mut page_json: Value = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse response: {}", e))?;
+
+        let videos_json = page_json
+            .get_mut("videos")
+            .map(Value::take)
+            .unwrap_or_else(|| Value::Array(Vec::new()));
+        let page_videos: Vec<SproutVideoDetails> = serde_json::from_value(videos_json)
+            .map_err(|e| format!("Failed to parse videos: {}", e))?;
+
+        let is_last_page = page_json["next_page"].is_null() || page_videos.is_empty();
+        videos.extend(page_videos);
+
+        if page.is_some() || is_last_page {
+            break;
+        }
+
+        current_page += 1;
+    }
+
+    Ok(videos)
+}
+
+/// Updates a Sprout video's title and/or description
+///
+/// Only the fields that are provided are sent, so the other stays unchanged.
+#[command]
+pub async fn update_sprout_video(
+    video_id: String,
+    title: Option<String>,
+    description: Option<String>,
+    api_key: String,
+) -> Result<SproutVideoDetails, String> {
+    let mut changes = serde_json::Map::new();
+    if let Some(title) = title {
+        changes.insert("title".to_string(), Value::String(title));
+    }
+    if let Some(description) = description {
+        changes.insert("description".to_string(), Value::String(description));
+    }
+
+    if changes.is_empty() {
+        return Err("No fields to update".to_string());
+    }
+
+    let client = Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    let url = format!("https://api.sproutvideo.com/v1/videos/{}", video_id);
+
+    let response = client
+        .put(&url)
+        .header("SproutVideo-Api-Key", api_key)
+        .json(&Value::Object(changes))
+        .send()
+        .await
+        .map_err(|e| format!("API request failed: {}", e))?;
+
+    let status = response.status();
+
+    match status.as_u16() {
+        401 => return Err("Invalid Sprout Video API key".to_string()),
+        404 => return Err(format!("Video not found: {}", video_id)),
+        _ if !status.is_success() => return Err(format!("API returned error: {}", status)),
+        _ => {}
+    }
+
+    let video_data: SproutVideoDetails = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse response: {}", e))?;
+
+    Ok(video_data)
+}
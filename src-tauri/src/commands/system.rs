@@ -1,18 +1,93 @@
+use crate::utils::open;
+use serde::Serialize;
 use std::env;
 use std::process::Command;
+use std::time::{Duration, Instant};
 use tauri::{command, AppHandle};
 
+/// Set on the relaunched instance to the pid of the instance it replaces
+pub const RESTART_PARENT_PID_ENV: &str = "BUCKET_RESTART_PARENT_PID";
+const RESTART_PARENT_WAIT_TIMEOUT: Duration = Duration::from_secs(10);
+const RESTART_PARENT_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Whether a process with this pid is still running
+fn process_is_running(pid: u32) -> bool {
+    #[cfg(unix)]
+    {
+        Command::new("kill")
+            .args(["-0", &pid.to_string()])
+            .output()
+            .is_ok_and(|output| output.status.success())
+    }
+
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+
+        // Release builds have no console, so without this every poll flashes a window
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+        Command::new("tasklist")
+            .args(["/FI", &format!("PID eq {}", pid), "/NH"])
+            .creation_flags(CREATE_NO_WINDOW)
+            .output()
+            .is_ok_and(|output| String::from_utf8_lossy(&output.stdout).contains(&pid.to_string()))
+    }
+}
+
+/// Block until the instance that launched this one via graceful_restart has exited
+///
+/// Called at startup, before any databases or files are opened, so the old
+/// instance has released its locks. Gives up after RESTART_PARENT_WAIT_TIMEOUT
+/// rather than hanging if the parent never exits.
+pub fn wait_for_restart_parent() {
+    let Some(parent_pid) = env::var(RESTART_PARENT_PID_ENV)
+        .ok()
+        .and_then(|pid| pid.parse::<u32>().ok())
+    else {
+        return;
+    };
+
+    // Don't pass the handoff on to anything this instance spawns
+    env::remove_var(RESTART_PARENT_PID_ENV);
+
+    let started = Instant::now();
+    while process_is_running(parent_pid) {
+        if started.elapsed() >= RESTART_PARENT_WAIT_TIMEOUT {
+            println!(
+                "Previous instance (pid {}) still running after {:?}, starting anyway",
+                parent_pid, RESTART_PARENT_WAIT_TIMEOUT
+            );
+            return;
+        }
+        std::thread::sleep(RESTART_PARENT_POLL_INTERVAL);
+    }
+}
+
+/// Relaunch the app, e.g. after an update has been installed
+///
+/// Debug builds skip the restart unless `force` is set, since the dev binary
+/// is normally managed by `tauri dev`. Forcing only works in bundled debug
+/// builds (`tauri build --debug`): under `tauri dev` the CLI and its dev server
+/// shut down when the app exits, so the relaunched binary would load a dead
+/// devUrl, and an error is returned instead.
 #[tauri::command]
-pub async fn graceful_restart(_app_handle: AppHandle) -> Result<(), String> {
+pub async fn graceful_restart(_app_handle: AppHandle, force: Option<bool>) -> Result<(), String> {
     // Perform any cleanup needed before restarting.
 
-    // In debug mode (development), the executable might not be available.
-    // We can simply log and exit or do nothing.
-    if cfg!(debug_assertions) {
-        println!("Graceful restart is not supported in development mode.");
+    if cfg!(debug_assertions) && !force.unwrap_or(false) {
+        println!("Graceful restart skipped in development mode (pass force to restart anyway).");
         return Ok(());
     }
 
+    if tauri::is_dev() {
+        return Err(
+            "Can't restart under tauri dev, which stops the dev server when the app exits. \
+             Use a debug bundle (tauri build --debug) to test restarts."
+                .to_string(),
+        );
+    }
+
     // Get the current executable's path.
     let current_exe = std::env::current_exe().map_err(|e| e.to_string())?;
 
@@ -24,8 +99,10 @@ pub async fn graceful_restart(_app_handle: AppHandle) -> Result<(), String> {
         ));
     }
 
-    // Spawn a new instance of the application.
+    // Spawn a new instance of the application. It waits for this process to
+    // exit before starting up, so the two never hold the same locks.
     Command::new(current_exe)
+        .env(RESTART_PARENT_PID_ENV, std::process::id().to_string())
         .spawn()
         .map_err(|e| format!("Failed to spawn new process: {}", e))?;
 
@@ -35,37 +112,66 @@ pub async fn graceful_restart(_app_handle: AppHandle) -> Result<(), String> {
     std::process::exit(0);
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsernameInfo {
+    /// Name as reported, e.g. "CORP\jsmith" on a Windows domain machine
+    pub raw: String,
+    /// Name with any domain prefix removed, as written to breadcrumbs
+    pub name: String,
+    /// Where the name came from: "USERNAME", "USER", "whoami" or "unknown"
+    pub source: String,
+}
+
+/// Drop a Windows "DOMAIN\" prefix from a username
+pub(crate) fn strip_username_domain(raw: &str) -> &str {
+    raw.rsplit('\\').next().unwrap_or(raw).trim()
+}
+
+/// Ask the OS via the `whoami` command, available on macOS, Windows and Linux
+fn os_username() -> Option<String> {
+    Command::new("whoami")
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .filter(|username| !username.is_empty())
+}
+
+/// Current user's name, from the environment or falling back to the OS
 #[command]
-pub fn get_username() -> String {
-    match env::var("USERNAME").or(env::var("USER")) {
-        Ok(username) => username,
-        Err(_) => "Unknown User".to_string(),
+pub fn get_username() -> UsernameInfo {
+    let (raw, source) = ["USERNAME", "USER"]
+        .into_iter()
+        .find_map(|var| {
+            env::var(var)
+                .ok()
+                .filter(|value| !value.trim().is_empty())
+                .map(|value| (value, var))
+        })
+        .or_else(|| os_username().map(|username| (username, "whoami")))
+        .unwrap_or_else(|| ("Unknown User".to_string(), "unknown"));
+
+    let name = match strip_username_domain(&raw) {
+        "" => "Unknown User".to_string(),
+        name => name.to_string(),
+    };
+
+    UsernameInfo {
+        raw,
+        name,
+        source: source.to_string(),
     }
 }
 
+/// Open a folder in Finder, Explorer or the default Linux file manager
 #[tauri::command]
-pub fn open_folder(path: String) {
-    #[cfg(target_os = "macos")]
-    {
-        Command::new("open")
-            .arg(path)
-            .spawn()
-            .expect("Failed to open folder");
-    }
-
-    #[cfg(target_os = "windows")]
-    {
-        Command::new("explorer")
-            .arg(path.replace("/", "\\"))
-            .spawn()
-            .expect("Failed to open folder");
-    }
+pub fn open_folder(path: String) -> Result<(), String> {
+    open::open_folder(&path)
+}
 
-    #[cfg(target_os = "linux")]
-    {
-        Command::new("xdg-open")
-            .arg(path)
-            .spawn()
-            .expect("Failed to open folder");
-    }
+/// Show a file selected in Finder or Explorer (its parent folder on Linux)
+#[tauri::command]
+pub fn reveal_in_file_manager(path: String) -> Result<(), String> {
+    open::reveal_file(&path)
 }
@@ -0,0 +1,47 @@
+/**
+ * Auth Token Tests
+ *
+ * Covers token matching, expiry, and masking for display
+ */
+
+use crate::state::{hash_token, mask_token, AuthState, TokenEntry};
+use std::sync::Mutex;
+
+fn entry(token: &str, expires_at: Option<String>) -> TokenEntry {
+    TokenEntry {
+        id: uuid::Uuid::new_v4().to_string(),
+        hash: hash_token(token),
+        masked: mask_token(token),
+        service: "sprout".to_string(),
+        added_at: chrono::Utc::now().to_rfc3339(),
+        expires_at,
+    }
+}
+
+#[test]
+fn test_is_valid_rejects_unknown_and_expired_tokens() {
+    let past = (chrono::Utc::now() - chrono::Duration::hours(1)).to_rfc3339();
+    let future = (chrono::Utc::now() + chrono::Duration::hours(1)).to_rfc3339();
+    let state = AuthState {
+        tokens: Mutex::new(vec![
+            entry("current-token", Some(future)),
+            entry("expired-token", Some(past)),
+            entry("forever-token", None),
+            entry("garbled-expiry", Some("next tuesday".to_string())),
+        ]),
+        path: None,
+    };
+
+    assert!(state.is_valid("current-token").unwrap());
+    assert!(state.is_valid("forever-token").unwrap());
+    assert!(!state.is_valid("expired-token").unwrap());
+    assert!(!state.is_valid("garbled-expiry").unwrap());
+    assert!(!state.is_valid("current-toke").unwrap());
+}
+
+#[test]
+fn test_mask_token_keeps_last_four_characters() {
+    assert_eq!(mask_token("abcdefghijkl"), "********ijkl");
+    assert_eq!(mask_token("short"), "*****");
+    assert_eq!(mask_token(""), "");
+}
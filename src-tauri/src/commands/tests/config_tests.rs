@@ -0,0 +1,52 @@
+/**
+ * App Config Tests
+ *
+ * Covers loading config.json when some of its fields are invalid, and keeping
+ * validation and repair of config values in agreement
+ */
+
+use crate::state::AppConfig;
+use serde_json::json;
+
+#[test]
+fn test_from_json_lenient_resets_only_bad_fields() {
+    let (config, warnings) = AppConfig::from_json_lenient(json!({
+        "scanMaxDepth": 4,
+        "searchTopK": "five",
+        "uploadTimeoutMinutes": 90,
+        "someOldSetting": true
+    }));
+
+    assert_eq!(config.scan_max_depth, 4);
+    assert_eq!(config.upload_timeout_minutes, 90);
+    assert_eq!(config.search_top_k, AppConfig::default().search_top_k);
+    assert_eq!(
+        warnings,
+        vec!["searchTopK has an invalid value, reset to the default"]
+    );
+}
+
+#[test]
+fn test_from_json_lenient_rejects_non_objects() {
+    let (config, warnings) = AppConfig::from_json_lenient(json!([1, 2, 3]));
+
+    assert_eq!(config.scan_max_depth, AppConfig::default().scan_max_depth);
+    assert_eq!(warnings.len(), 1);
+}
+
+#[test]
+fn test_validate_reports_what_sanitize_resets() {
+    let mut config = AppConfig {
+        scan_max_depth: -1,
+        search_top_k: 0,
+        ..AppConfig::default()
+    };
+
+    let problems = config.validate();
+    assert_eq!(problems.len(), 2);
+    assert_eq!(config.scan_max_depth, -1);
+
+    assert_eq!(config.sanitize(), problems);
+    assert_eq!(config.scan_max_depth, AppConfig::default().scan_max_depth);
+    assert!(config.validate().is_empty());
+}
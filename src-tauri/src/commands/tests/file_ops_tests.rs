@@ -0,0 +1,81 @@
+/**
+ * File Operation Tests
+ *
+ * Covers verifying copies against their source, and removing originals only
+ * after their copy has been verified
+ */
+
+use crate::commands::file_ops::remove_verified_source;
+use crate::utils::file_copy::verify_copy;
+use sha2::{Digest, Sha256};
+use std::fs;
+use tempfile::tempdir;
+
+#[test]
+fn test_remove_verified_source_deletes_matching_original() {
+    let dir = tempdir().unwrap();
+    let source = dir.path().join("A001.mov");
+    let copy = dir.path().join("copy.mov");
+    fs::write(&source, b"footage").unwrap();
+    fs::write(&copy, b"footage").unwrap();
+
+    remove_verified_source(&source, &copy).unwrap();
+
+    assert!(!source.exists());
+    assert!(copy.exists());
+}
+
+#[test]
+fn test_remove_verified_source_keeps_original_on_size_mismatch() {
+    let dir = tempdir().unwrap();
+    let source = dir.path().join("A001.mov");
+    let copy = dir.path().join("copy.mov");
+    fs::write(&source, b"footage").unwrap();
+    fs::write(&copy, b"foot").unwrap();
+
+    let error = remove_verified_source(&source, &copy).unwrap_err();
+
+    assert!(
+        error.contains("4 bytes but the original is 7 bytes"),
+        "{}",
+        error
+    );
+    assert!(source.exists());
+}
+
+#[test]
+fn test_verify_copy_accepts_matching_copy() {
+    let dir = tempdir().unwrap();
+    let copy = dir.path().join("A001.mov");
+    fs::write(&copy, b"footage").unwrap();
+
+    verify_copy(&copy, 7, &Sha256::digest(b"footage")).unwrap();
+
+    assert!(copy.exists());
+}
+
+#[test]
+fn test_verify_copy_removes_mismatched_copy() {
+    let dir = tempdir().unwrap();
+    let copy = dir.path().join("A001.mov");
+
+    // Same size, different content
+    fs::write(&copy, b"footage").unwrap();
+    let error = verify_copy(&copy, 7, &Sha256::digest(b"FOOTAGE")).unwrap_err();
+    assert!(
+        error.to_string().contains("doesn't match the source"),
+        "{}",
+        error
+    );
+    assert!(!copy.exists());
+
+    // Truncated copy
+    fs::write(&copy, b"foot").unwrap();
+    let error = verify_copy(&copy, 7, &Sha256::digest(b"footage")).unwrap_err();
+    assert!(
+        error.to_string().contains("is 4 bytes but 7 were read"),
+        "{}",
+        error
+    );
+    assert!(!copy.exists());
+}
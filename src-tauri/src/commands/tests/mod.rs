@@ -1,2 +1,14 @@
+mod auth_tests;
+mod config_tests;
+mod file_ops_tests;
+mod plugins_tests;
 mod premiere_test;
+mod rag_index_tests;
+mod rag_merge_tests;
+mod rag_migration_tests;
+mod rag_stats_tests;
+mod rag_text_search_tests;
+mod rag_upload_tests;
 mod rag_validation_tests;
+mod sprout_upload_tests;
+mod system_tests;
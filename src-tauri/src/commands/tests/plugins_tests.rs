@@ -0,0 +1,193 @@
+/**
+ * Plugin Install Tests
+ *
+ * Covers the ZXP checks made before a plugin is extracted, safe extraction,
+ * reading the installed version from a manifest, and backup retention
+ */
+
+use crate::commands::plugins::{
+    extract_plugin_archive, list_plugin_backups, prune_plugin_backups, read_manifest_details,
+    read_manifest_version, verify_plugin_archive, ManifestDetails,
+};
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use tempfile::tempdir;
+use zip::write::FileOptions;
+
+fn write_zxp(path: &Path, entries: &[&str]) -> u64 {
+    let mut zip = zip::ZipWriter::new(fs::File::create(path).unwrap());
+    for name in entries {
+        zip.start_file(*name, FileOptions::default()).unwrap();
+        zip.write_all(b"<ExtensionManifest/>").unwrap();
+    }
+    zip.finish().unwrap();
+    fs::metadata(path).unwrap().len()
+}
+
+#[test]
+fn test_verify_plugin_archive_accepts_valid_bundle() {
+    let temp_dir = tempdir().unwrap();
+    let zxp_path = temp_dir.path().join("Plugin.zxp");
+    let size = write_zxp(&zxp_path, &["CSXS/manifest.xml", "index.html"]);
+
+    assert!(verify_plugin_archive(&zxp_path, Some(size)).is_ok());
+    assert!(verify_plugin_archive(&zxp_path, None).is_ok());
+}
+
+#[test]
+fn test_verify_plugin_archive_rejects_bad_bundles() {
+    let temp_dir = tempdir().unwrap();
+
+    let truncated_path = temp_dir.path().join("Truncated.zxp");
+    let size = write_zxp(&truncated_path, &["CSXS/manifest.xml"]);
+    let error = verify_plugin_archive(&truncated_path, Some(size + 100)).unwrap_err();
+    assert!(error.contains("corrupt"), "unexpected error: {}", error);
+
+    let no_manifest_path = temp_dir.path().join("NoManifest.zxp");
+    write_zxp(&no_manifest_path, &["index.html"]);
+    let error = verify_plugin_archive(&no_manifest_path, None).unwrap_err();
+    assert!(
+        error.contains("CSXS/manifest.xml"),
+        "unexpected error: {}",
+        error
+    );
+
+    let not_zip_path = temp_dir.path().join("NotZip.zxp");
+    fs::write(&not_zip_path, "not a zip file").unwrap();
+    let error = verify_plugin_archive(&not_zip_path, None).unwrap_err();
+    assert!(
+        error.contains("not a valid ZXP"),
+        "unexpected error: {}",
+        error
+    );
+}
+
+#[test]
+fn test_plugin_archive_with_path_traversal_is_rejected() {
+    let temp_dir = tempdir().unwrap();
+    let zxp_path = temp_dir.path().join("Evil.zxp");
+    write_zxp(&zxp_path, &["CSXS/manifest.xml", "../escaped.txt"]);
+
+    let error = verify_plugin_archive(&zxp_path, None).unwrap_err();
+    assert!(error.contains("unsafe path"), "unexpected error: {}", error);
+
+    let target_dir = temp_dir.path().join("extensions").join("Evil");
+    fs::create_dir_all(&target_dir).unwrap();
+    let error = extract_plugin_archive(&zxp_path, &target_dir).unwrap_err();
+    assert!(
+        error.contains("../escaped.txt"),
+        "unexpected error: {}",
+        error
+    );
+
+    let escaped_path = temp_dir.path().join("extensions").join("escaped.txt");
+    assert!(!escaped_path.exists());
+}
+
+#[test]
+fn test_extract_plugin_archive_writes_entries() {
+    let temp_dir = tempdir().unwrap();
+    let zxp_path = temp_dir.path().join("Plugin.zxp");
+    write_zxp(&zxp_path, &["CSXS/manifest.xml", "js/main.js"]);
+
+    let target_dir = temp_dir.path().join("Plugin");
+    fs::create_dir_all(&target_dir).unwrap();
+    extract_plugin_archive(&zxp_path, &target_dir).unwrap();
+
+    assert!(target_dir.join("CSXS/manifest.xml").exists());
+    assert!(target_dir.join("js/main.js").exists());
+}
+
+#[test]
+fn test_read_manifest_version() {
+    let manifest = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ExtensionManifest Version="7.0" ExtensionBundleId="com.example.panel" ExtensionBundleVersion="1.4.2">
+  <ExtensionList>
+    <Extension Id="com.example.panel.main" Version="1.0.0" />
+  </ExtensionList>
+</ExtensionManifest>"#;
+    assert_eq!(read_manifest_version(manifest).as_deref(), Some("1.4.2"));
+
+    // Without a bundle version, the first extension's version is used
+    let manifest = r#"<ExtensionManifest Version="7.0">
+  <ExtensionList>
+    <Extension Id="com.example.panel.main" Version="2.0.1" />
+    <Extension Id="com.example.panel.other" Version="3.0.0" />
+  </ExtensionList>
+</ExtensionManifest>"#;
+    assert_eq!(read_manifest_version(manifest).as_deref(), Some("2.0.1"));
+
+    assert_eq!(read_manifest_version("<ExtensionManifest/>"), None);
+}
+
+#[test]
+fn test_read_manifest_details() {
+    let manifest = r#"<ExtensionManifest Version="7.0" ExtensionBundleId="com.example.panel" ExtensionBundleName="Example Panel" ExtensionBundleVersion="1.4.2">
+  <ExtensionList>
+    <Extension Id="com.example.panel.main" Version="1.0.0" />
+  </ExtensionList>
+</ExtensionManifest>"#;
+    assert_eq!(
+        read_manifest_details(manifest),
+        ManifestDetails {
+            bundle_id: Some("com.example.panel".to_string()),
+            bundle_name: Some("Example Panel".to_string()),
+            version: Some("1.4.2".to_string()),
+        }
+    );
+
+    // Third-party manifests often leave the bundle attributes off
+    let manifest = r#"<ExtensionManifest Version="6.0">
+  <ExtensionList>
+    <Extension Id="com.thirdparty.tool" Version="3.2.0" />
+  </ExtensionList>
+</ExtensionManifest>"#;
+    assert_eq!(
+        read_manifest_details(manifest),
+        ManifestDetails {
+            bundle_id: Some("com.thirdparty.tool".to_string()),
+            bundle_name: None,
+            version: Some("3.2.0".to_string()),
+        }
+    );
+}
+
+#[test]
+fn test_prune_plugin_backups_keeps_most_recent() {
+    let dir = tempdir().unwrap();
+    for name in [
+        "Panel",
+        "Panel_20240101_090000",
+        "Panel_20240315_120000",
+        "Panel_20240201_080000",
+        "Panel_20240401_170000",
+        "Panel_notes",
+        "OtherPanel_20230101_000000",
+    ] {
+        fs::create_dir(dir.path().join(name)).unwrap();
+    }
+
+    let removed = prune_plugin_backups(dir.path(), "Panel", 3);
+    assert_eq!(removed.len(), 1);
+    assert!(removed[0].ends_with("Panel_20240101_090000"));
+
+    let remaining: Vec<String> = list_plugin_backups(dir.path(), "Panel")
+        .iter()
+        .map(|path| path.file_name().unwrap().to_string_lossy().to_string())
+        .collect();
+    assert_eq!(
+        remaining,
+        vec![
+            "Panel_20240201_080000",
+            "Panel_20240315_120000",
+            "Panel_20240401_170000"
+        ]
+    );
+
+    // Clearing leaves the live install and unrelated folders alone
+    assert_eq!(prune_plugin_backups(dir.path(), "Panel", 0).len(), 3);
+    assert!(dir.path().join("Panel").exists());
+    assert!(dir.path().join("Panel_notes").exists());
+    assert!(dir.path().join("OtherPanel_20230101_000000").exists());
+}
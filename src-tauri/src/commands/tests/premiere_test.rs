@@ -1,3 +1,4 @@
+use crate::commands::premiere::backup_existing_project;
 use std::fs;
 use std::io::Write;
 use tempfile::tempdir;
@@ -101,3 +102,18 @@ fn test_sync_all_prevents_corruption() {
     assert_eq!(source_data.len(), copied_data.len());
     assert_eq!(source_data, copied_data);
 }
+
+#[test]
+fn test_backup_existing_project_replaces_old_backup() {
+    let temp_dir = tempdir().unwrap();
+    let project_path = temp_dir.path().join("TestProject.prproj");
+    let backup_path = temp_dir.path().join("TestProject.prproj.bak");
+    fs::write(&project_path, b"current project").unwrap();
+    fs::write(&backup_path, b"older backup").unwrap();
+
+    let result = backup_existing_project(&project_path).unwrap();
+
+    assert_eq!(result, backup_path);
+    assert!(!project_path.exists());
+    assert_eq!(fs::read(&backup_path).unwrap(), b"current project");
+}
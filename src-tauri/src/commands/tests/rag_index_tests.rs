@@ -0,0 +1,244 @@
+/**
+ * RAG Embedding Index Tests
+ *
+ * Covers the in-memory embedding index used by search_similar_scripts
+ */
+
+use crate::commands::rag::{
+    check_query_dimension, get_embedding_index, invalidate_embedding_index, load_embedding_index,
+    normalize_stored_embeddings, rank_examples, DEFAULT_MIN_QUALITY,
+};
+use rusqlite::{params, Connection};
+use std::path::Path;
+use tempfile::tempdir;
+
+fn create_examples_db(path: &Path) -> Connection {
+    let conn = Connection::open(path).unwrap();
+    conn.execute_batch(
+        "CREATE TABLE example_scripts (
+            id TEXT PRIMARY KEY,
+            title TEXT NOT NULL,
+            category TEXT NOT NULL,
+            before_text TEXT NOT NULL,
+            after_text TEXT NOT NULL,
+            tags TEXT,
+            quality_score INTEGER,
+            source TEXT DEFAULT 'bundled'
+        );
+        CREATE TABLE embeddings (
+            script_id TEXT PRIMARY KEY,
+            embedding BLOB NOT NULL,
+            dimension INTEGER NOT NULL
+        );",
+    )
+    .unwrap();
+    conn
+}
+
+fn insert_example(conn: &Connection, id: &str, quality_score: Option<i32>, embedding: &[f32]) {
+    insert_example_in(conn, id, "educational", quality_score, embedding);
+}
+
+fn insert_example_in(
+    conn: &Connection,
+    id: &str,
+    category: &str,
+    quality_score: Option<i32>,
+    embedding: &[f32],
+) {
+    let embedding_bytes: Vec<u8> = embedding.iter().flat_map(|f| f.to_le_bytes()).collect();
+    conn.execute(
+        "INSERT INTO example_scripts (id, title, category, before_text, after_text, quality_score)
+         VALUES (?, ?, ?, 'before', 'after', ?)",
+        params![id, format!("Example {}", id), category, quality_score],
+    )
+    .unwrap();
+    conn.execute(
+        "INSERT INTO embeddings (script_id, embedding, dimension) VALUES (?, ?, ?)",
+        params![id, embedding_bytes, embedding.len()],
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_rank_examples_orders_and_filters() {
+    let temp_dir = tempdir().unwrap();
+    let db_path = temp_dir.path().join("examples.db");
+    let conn = create_examples_db(&db_path);
+
+    insert_example(&conn, "close", Some(5), &[1.0, 0.1, 0.0]);
+    insert_example(&conn, "far", Some(4), &[0.0, 1.0, 0.0]);
+    insert_example(&conn, "middle", Some(4), &[1.0, 1.0, 0.0]);
+
+    let index = load_embedding_index(&conn, &db_path).unwrap();
+    assert_eq!(index.examples.len(), 3);
+
+    let query = [1.0, 0.0, 0.0];
+    let ids: Vec<String> = rank_examples(&index, &query, 10, None, DEFAULT_MIN_QUALITY, None)
+        .into_iter()
+        .map(|example| example.id)
+        .collect();
+    assert_eq!(ids, vec!["close", "middle", "far"]);
+
+    let filtered = rank_examples(&index, &query, 10, Some(0.5), DEFAULT_MIN_QUALITY, None);
+    assert_eq!(filtered.len(), 2);
+    assert!(filtered[0].similarity > 0.99);
+
+    assert_eq!(
+        rank_examples(&index, &query, 1, None, DEFAULT_MIN_QUALITY, None).len(),
+        1
+    );
+}
+
+#[test]
+fn test_indexed_examples_carry_tags_and_source() {
+    let temp_dir = tempdir().unwrap();
+    let db_path = temp_dir.path().join("examples.db");
+    let conn = create_examples_db(&db_path);
+
+    insert_example(&conn, "tagged", Some(5), &[1.0, 0.0]);
+    conn.execute(
+        "UPDATE example_scripts SET tags = 'news, interview,,', source = 'user-uploaded' WHERE id = 'tagged'",
+        [],
+    )
+    .unwrap();
+
+    let index = load_embedding_index(&conn, &db_path).unwrap();
+    let results = rank_examples(&index, &[1.0, 0.0], 1, None, DEFAULT_MIN_QUALITY, None);
+
+    assert_eq!(results[0].tags, vec!["news", "interview"]);
+    assert_eq!(results[0].source, "user-uploaded");
+}
+
+#[test]
+fn test_rank_examples_min_quality_includes_unrated_at_three() {
+    let temp_dir = tempdir().unwrap();
+    let db_path = temp_dir.path().join("examples.db");
+    let conn = create_examples_db(&db_path);
+
+    insert_example(&conn, "good", Some(5), &[1.0, 0.0]);
+    insert_example(&conn, "average", Some(3), &[1.0, 0.0]);
+    insert_example(&conn, "unrated", None, &[1.0, 0.0]);
+    insert_example(&conn, "poor", Some(1), &[1.0, 0.0]);
+
+    let index = load_embedding_index(&conn, &db_path).unwrap();
+    let ids_at = |min_quality: i32| -> Vec<String> {
+        let mut ids: Vec<String> = rank_examples(&index, &[1.0, 0.0], 10, None, min_quality, None)
+            .into_iter()
+            .map(|example| example.id)
+            .collect();
+        ids.sort();
+        ids
+    };
+
+    assert_eq!(ids_at(DEFAULT_MIN_QUALITY), vec!["good"]);
+    assert_eq!(ids_at(3), vec!["average", "good", "unrated"]);
+    assert_eq!(ids_at(1), vec!["average", "good", "poor", "unrated"]);
+}
+
+#[test]
+fn test_embedding_index_reloads_after_invalidation() {
+    let temp_dir = tempdir().unwrap();
+    let db_path = temp_dir.path().join("examples.db");
+    let conn = create_examples_db(&db_path);
+    insert_example(&conn, "first", Some(5), &[1.0, 0.0]);
+
+    assert_eq!(get_embedding_index(&db_path).unwrap().examples.len(), 1);
+
+    // What upload_example does after committing its insert
+    insert_example(&conn, "second", Some(5), &[0.0, 1.0]);
+    invalidate_embedding_index();
+
+    assert_eq!(get_embedding_index(&db_path).unwrap().examples.len(), 2);
+}
+
+#[test]
+fn test_rank_examples_filters_by_category() {
+    let temp_dir = tempdir().unwrap();
+    let db_path = temp_dir.path().join("examples.db");
+    let conn = create_examples_db(&db_path);
+
+    insert_example_in(&conn, "interview", "interview", Some(5), &[1.0, 0.0]);
+    insert_example_in(&conn, "documentary", "documentary", Some(5), &[1.0, 0.0]);
+
+    let index = load_embedding_index(&conn, &db_path).unwrap();
+    let query = [1.0, 0.0];
+
+    let interview_only = ["interview".to_string()];
+    let results = rank_examples(
+        &index,
+        &query,
+        10,
+        None,
+        DEFAULT_MIN_QUALITY,
+        Some(&interview_only[..]),
+    );
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].category, "interview");
+
+    // No filter, or an empty one, keeps every category
+    assert_eq!(
+        rank_examples(&index, &query, 10, None, DEFAULT_MIN_QUALITY, None).len(),
+        2
+    );
+    assert_eq!(
+        rank_examples(&index, &query, 10, None, DEFAULT_MIN_QUALITY, Some(&[][..])).len(),
+        2
+    );
+}
+
+#[test]
+fn test_query_dimension_must_match_database() {
+    let temp_dir = tempdir().unwrap();
+    let db_path = temp_dir.path().join("examples.db");
+    let conn = create_examples_db(&db_path);
+
+    insert_example(&conn, "small-model", Some(5), &[0.5; 384]);
+
+    let index = load_embedding_index(&conn, &db_path).unwrap();
+
+    assert!(check_query_dimension(&index, &[0.5; 384]).is_ok());
+
+    let error = check_query_dimension(&index, &[0.5; 768]).unwrap_err();
+    assert!(error.contains("768"), "unexpected error: {}", error);
+    assert!(error.contains("384"), "unexpected error: {}", error);
+}
+
+#[test]
+fn test_normalized_index_matches_cosine_ranking() {
+    let temp_dir = tempdir().unwrap();
+    let db_path = temp_dir.path().join("examples.db");
+    let conn = create_examples_db(&db_path);
+    conn.execute(
+        "CREATE TABLE db_metadata (key TEXT PRIMARY KEY, value TEXT NOT NULL, updated_at TEXT)",
+        [],
+    )
+    .unwrap();
+
+    insert_example(&conn, "close", Some(5), &[3.0, 0.3, 0.0]);
+    insert_example(&conn, "middle", Some(5), &[2.0, 2.0, 0.0]);
+    insert_example(&conn, "far", Some(5), &[0.0, 5.0, 0.0]);
+
+    let query = [4.0, 0.0, 0.0];
+    let before = load_embedding_index(&conn, &db_path).unwrap();
+    assert!(!before.normalized);
+    let expected = rank_examples(&before, &query, 10, None, DEFAULT_MIN_QUALITY, None);
+
+    assert_eq!(normalize_stored_embeddings(&conn).unwrap(), 3);
+    // The flag is set, so a second run has nothing to do
+    assert_eq!(normalize_stored_embeddings(&conn).unwrap(), 0);
+
+    let after = load_embedding_index(&conn, &db_path).unwrap();
+    assert!(after.normalized);
+    for indexed in &after.examples {
+        let magnitude: f32 = indexed.embedding.iter().map(|x| x * x).sum::<f32>().sqrt();
+        assert!((magnitude - 1.0).abs() < 1e-5);
+    }
+
+    let actual = rank_examples(&after, &query, 10, None, DEFAULT_MIN_QUALITY, None);
+    assert_eq!(actual.len(), expected.len());
+    for (actual, expected) in actual.iter().zip(&expected) {
+        assert_eq!(actual.id, expected.id);
+        assert!((actual.similarity - expected.similarity).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,134 @@
+/**
+ * RAG Bundled Merge Tests
+ *
+ * Covers merging a bundled examples database into the active one
+ */
+
+use crate::commands::rag::{merge_bundled_database, CURRENT_SCHEMA_VERSION};
+use rusqlite::{params, Connection};
+use std::path::Path;
+use tempfile::tempdir;
+
+/// Create an examples database with the current table layout
+fn create_examples_db(path: &Path) -> Connection {
+    let conn = Connection::open(path).unwrap();
+    conn.execute_batch(
+        "CREATE TABLE example_scripts (
+            id TEXT PRIMARY KEY,
+            title TEXT NOT NULL,
+            category TEXT NOT NULL,
+            before_text TEXT NOT NULL,
+            after_text TEXT NOT NULL,
+            tags TEXT,
+            word_count INTEGER,
+            quality_score INTEGER,
+            source TEXT NOT NULL
+        );
+        CREATE TABLE embeddings (
+            script_id TEXT PRIMARY KEY,
+            embedding BLOB NOT NULL,
+            dimension INTEGER NOT NULL
+        );
+        CREATE TABLE db_metadata (
+            key TEXT PRIMARY KEY,
+            value TEXT NOT NULL,
+            updated_at TEXT DEFAULT CURRENT_TIMESTAMP
+        );",
+    )
+    .unwrap();
+    conn
+}
+
+fn count_examples(conn: &Connection) -> i64 {
+    conn.query_row("SELECT COUNT(*) FROM example_scripts", [], |row| row.get(0))
+        .unwrap()
+}
+
+#[test]
+fn test_merge_skips_bundled_db_with_future_schema_version() {
+    let temp_dir = tempdir().unwrap();
+    let bundled_path = temp_dir.path().join("bundled.db");
+    let active_path = temp_dir.path().join("active.db");
+
+    // Bundled DB from a newer build, with a column this build doesn't know about
+    let bundled = create_examples_db(&bundled_path);
+    bundled
+        .execute("ALTER TABLE example_scripts ADD COLUMN language TEXT", [])
+        .unwrap();
+    bundled
+        .execute(
+            "INSERT INTO db_metadata (key, value) VALUES ('schema_version', ?)",
+            params![(CURRENT_SCHEMA_VERSION + 1).to_string()],
+        )
+        .unwrap();
+    bundled
+        .execute(
+            "INSERT INTO db_metadata (key, value) VALUES ('bundled_version', '2.0.0')",
+            [],
+        )
+        .unwrap();
+    bundled
+        .execute(
+            "INSERT INTO example_scripts (id, title, category, before_text, after_text, source, language)
+             VALUES ('future-1', 'Future', 'educational', 'before', 'after', 'bundled', 'en')",
+            [],
+        )
+        .unwrap();
+    drop(bundled);
+
+    let active = create_examples_db(&active_path);
+    drop(active);
+
+    let result = merge_bundled_database(&bundled_path, &active_path);
+    assert!(result.is_ok(), "Merge should skip, not fail: {:?}", result);
+
+    let active = Connection::open(&active_path).unwrap();
+    assert_eq!(count_examples(&active), 0);
+
+    // bundled_version must not advance, so a later compatible build still merges
+    let active_version: Option<String> = active
+        .query_row(
+            "SELECT value FROM db_metadata WHERE key = 'bundled_version'",
+            [],
+            |row| row.get(0),
+        )
+        .ok();
+    assert_eq!(active_version, None);
+}
+
+#[test]
+fn test_merge_adds_examples_from_supported_schema_version() {
+    let temp_dir = tempdir().unwrap();
+    let bundled_path = temp_dir.path().join("bundled.db");
+    let active_path = temp_dir.path().join("active.db");
+
+    let bundled = create_examples_db(&bundled_path);
+    bundled
+        .execute(
+            "INSERT INTO db_metadata (key, value) VALUES ('schema_version', ?)",
+            params![CURRENT_SCHEMA_VERSION.to_string()],
+        )
+        .unwrap();
+    bundled
+        .execute(
+            "INSERT INTO example_scripts (id, title, category, before_text, after_text, source)
+             VALUES ('bundled-1', 'Bundled', 'educational', 'before', 'after', 'bundled')",
+            [],
+        )
+        .unwrap();
+    bundled
+        .execute(
+            "INSERT INTO embeddings (script_id, embedding, dimension) VALUES ('bundled-1', ?, 1)",
+            params![1.0f32.to_le_bytes().to_vec()],
+        )
+        .unwrap();
+    drop(bundled);
+
+    let active = create_examples_db(&active_path);
+    drop(active);
+
+    merge_bundled_database(&bundled_path, &active_path).unwrap();
+
+    let active = Connection::open(&active_path).unwrap();
+    assert_eq!(count_examples(&active), 1);
+}
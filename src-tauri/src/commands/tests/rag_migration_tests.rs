@@ -0,0 +1,107 @@
+/**
+ * RAG Migration Tests
+ *
+ * Covers bringing example databases from older versions up to the current schema
+ */
+
+use crate::commands::rag::run_migrations;
+use rusqlite::{params, Connection};
+use tempfile::tempdir;
+
+fn column_names(conn: &Connection, table: &str) -> Vec<String> {
+    let mut stmt = conn
+        .prepare(&format!("PRAGMA table_info({})", table))
+        .unwrap();
+    stmt.query_map([], |row| row.get::<_, String>(1))
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap()
+}
+
+fn index_names(conn: &Connection) -> Vec<String> {
+    let mut stmt = conn
+        .prepare("SELECT name FROM sqlite_master WHERE type = 'index' AND name LIKE 'idx_%'")
+        .unwrap();
+    stmt.query_map([], |row| row.get::<_, String>(0))
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap()
+}
+
+#[test]
+fn test_run_migrations_upgrades_old_database() {
+    let temp_dir = tempdir().unwrap();
+    let conn = Connection::open(temp_dir.path().join("old.db")).unwrap();
+
+    // Layout from before source, created_at, dimension and db_metadata existed
+    conn.execute_batch(
+        "CREATE TABLE example_scripts (
+            id TEXT PRIMARY KEY,
+            title TEXT NOT NULL,
+            category TEXT NOT NULL,
+            before_text TEXT NOT NULL,
+            after_text TEXT NOT NULL,
+            tags TEXT,
+            word_count INTEGER,
+            quality_score INTEGER
+        );
+        CREATE TABLE embeddings (
+            script_id TEXT PRIMARY KEY,
+            embedding BLOB NOT NULL
+        );",
+    )
+    .unwrap();
+    conn.execute(
+        "INSERT INTO example_scripts (id, title, category, before_text, after_text)
+         VALUES ('old-1', 'Old Example', 'educational', 'before', 'after')",
+        [],
+    )
+    .unwrap();
+    conn.execute(
+        "INSERT INTO embeddings (script_id, embedding) VALUES ('old-1', ?)",
+        params![vec![0u8; 16]],
+    )
+    .unwrap();
+
+    run_migrations(&conn).unwrap();
+    // Running again on an up-to-date database is a no-op
+    run_migrations(&conn).unwrap();
+
+    let columns = column_names(&conn, "example_scripts");
+    assert!(columns.contains(&"source".to_string()));
+    assert!(columns.contains(&"created_at".to_string()));
+    assert!(column_names(&conn, "embeddings").contains(&"dimension".to_string()));
+
+    let mut indexes = index_names(&conn);
+    indexes.sort();
+    assert_eq!(indexes, ["idx_category", "idx_quality", "idx_source"]);
+
+    // Existing rows are backfilled so queries that read the new columns work
+    let (source, created_at): (String, Option<String>) = conn
+        .query_row(
+            "SELECT source, created_at FROM example_scripts WHERE id = 'old-1'",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .unwrap();
+    assert_eq!(source, "bundled");
+    assert!(created_at.is_some());
+
+    let dimension: i64 = conn
+        .query_row(
+            "SELECT dimension FROM embeddings WHERE script_id = 'old-1'",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(dimension, 4);
+
+    let schema_version: String = conn
+        .query_row(
+            "SELECT value FROM db_metadata WHERE key = 'schema_version'",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(schema_version, "1");
+}
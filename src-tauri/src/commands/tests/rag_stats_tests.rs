@@ -0,0 +1,52 @@
+/**
+ * RAG Database Stats Tests
+ *
+ * Covers the counts and quality summary reported for the examples database
+ */
+
+use crate::commands::rag::{collect_database_stats, run_migrations};
+use rusqlite::{params, Connection};
+use tempfile::tempdir;
+
+#[test]
+fn test_collect_database_stats_counts_examples() {
+    let temp_dir = tempdir().unwrap();
+    let db_path = temp_dir.path().join("examples.db");
+    let conn = Connection::open(&db_path).unwrap();
+    run_migrations(&conn).unwrap();
+
+    let examples = [
+        ("a", "educational", "bundled", Some(5), 768),
+        ("b", "educational", "user-uploaded", Some(3), 768),
+        ("c", "business", "user-uploaded", None, 384),
+    ];
+    for (id, category, source, quality, dimension) in examples {
+        conn.execute(
+            "INSERT INTO example_scripts (id, title, category, before_text, after_text, quality_score, source)
+             VALUES (?1, ?1, ?2, 'before', 'after', ?3, ?4)",
+            params![id, category, quality, source],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO embeddings (script_id, embedding, dimension) VALUES (?, ?, ?)",
+            params![id, vec![0u8; dimension * 4], dimension as i64],
+        )
+        .unwrap();
+    }
+
+    let stats = collect_database_stats(&conn, &db_path).unwrap();
+    assert_eq!(stats.total_examples, 3);
+    assert_eq!(stats.by_source["bundled"], 1);
+    assert_eq!(stats.by_source["user-uploaded"], 2);
+    assert_eq!(stats.by_category["educational"], 2);
+    assert_eq!(stats.by_category["business"], 1);
+    assert_eq!(stats.by_dimension[&768], 2);
+    assert_eq!(stats.by_dimension[&384], 1);
+
+    // The unrated example is left out of the quality summary
+    assert_eq!(stats.min_quality, Some(3));
+    assert_eq!(stats.max_quality, Some(5));
+    assert_eq!(stats.avg_quality, Some(4.0));
+    assert_eq!(stats.unrated_examples, 1);
+    assert!(stats.file_size_bytes > 0);
+}
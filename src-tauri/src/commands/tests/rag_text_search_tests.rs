@@ -0,0 +1,101 @@
+/**
+ * RAG Keyword Search Tests
+ *
+ * Covers the FTS5 index created by run_migrations and search_examples_fts
+ */
+
+use crate::commands::rag::{run_migrations, search_examples_fts};
+use rusqlite::{params, Connection};
+use tempfile::tempdir;
+
+fn insert_example(conn: &Connection, id: &str, title: &str, before_text: &str) {
+    conn.execute(
+        "INSERT INTO example_scripts (id, title, category, before_text, after_text, source, created_at)
+         VALUES (?, ?, 'educational', ?, 'after', 'user-uploaded', '2024-01-01')",
+        params![id, title, before_text],
+    )
+    .unwrap();
+}
+
+fn matching_ids(conn: &Connection, query: &str) -> Vec<String> {
+    search_examples_fts(conn, query, 10)
+        .unwrap()
+        .into_iter()
+        .map(|result| result.example.id)
+        .collect()
+}
+
+#[test]
+fn test_keyword_search_stays_in_sync_with_examples() {
+    let temp_dir = tempdir().unwrap();
+    let conn = Connection::open(temp_dir.path().join("examples.db")).unwrap();
+    run_migrations(&conn).unwrap();
+
+    insert_example(
+        &conn,
+        "a",
+        "Quarterly Review",
+        "Welcome to the quarterly review",
+    );
+    insert_example(
+        &conn,
+        "b",
+        "Product Launch",
+        "Today we review the new product",
+    );
+
+    assert_eq!(matching_ids(&conn, "quarterly"), vec!["a"]);
+    let mut both = matching_ids(&conn, "review");
+    both.sort();
+    assert_eq!(both, vec!["a", "b"]);
+
+    // Quoted input is an exact phrase; unquoted words just all have to appear
+    assert_eq!(matching_ids(&conn, "\"review the new\""), vec!["b"]);
+    assert_eq!(matching_ids(&conn, "new review"), vec!["b"]);
+
+    // FTS5 syntax in the query is treated as plain text rather than an error
+    assert!(matching_ids(&conn, "review AND (").is_empty());
+    assert!(matching_ids(&conn, "   ").is_empty());
+
+    conn.execute(
+        "UPDATE example_scripts SET title = 'Annual Summary', before_text = 'Annual summary' WHERE id = 'a'",
+        [],
+    )
+    .unwrap();
+    assert!(matching_ids(&conn, "quarterly").is_empty());
+    assert_eq!(matching_ids(&conn, "annual"), vec!["a"]);
+
+    conn.execute("DELETE FROM example_scripts WHERE id = 'b'", [])
+        .unwrap();
+    assert!(matching_ids(&conn, "product").is_empty());
+}
+
+#[test]
+fn test_keyword_index_is_built_for_existing_examples() {
+    let temp_dir = tempdir().unwrap();
+    let conn = Connection::open(temp_dir.path().join("examples.db")).unwrap();
+    conn.execute_batch(
+        "CREATE TABLE example_scripts (
+            id TEXT PRIMARY KEY,
+            title TEXT NOT NULL,
+            category TEXT NOT NULL,
+            before_text TEXT NOT NULL,
+            after_text TEXT NOT NULL
+        );",
+    )
+    .unwrap();
+    conn.execute(
+        "INSERT INTO example_scripts (id, title, category, before_text, after_text)
+         VALUES ('old', 'Safety Briefing', 'corporate', 'before', 'after')",
+        [],
+    )
+    .unwrap();
+
+    run_migrations(&conn).unwrap();
+    run_migrations(&conn).unwrap();
+
+    let results = search_examples_fts(&conn, "safety", 10).unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].example.id, "old");
+    assert!(results[0].relevance > 0.0);
+}
@@ -0,0 +1,91 @@
+/**
+ * RAG Batch Upload Tests
+ *
+ * Covers inserting several examples in a single transaction
+ */
+
+use crate::commands::rag::{insert_examples, ExampleMetadataInput, UploadExampleRequest};
+use rusqlite::Connection;
+use tempfile::tempdir;
+
+const EXAMPLE_SCHEMA: &str = "CREATE TABLE example_scripts (
+        id TEXT PRIMARY KEY,
+        title TEXT NOT NULL,
+        category TEXT NOT NULL,
+        before_text TEXT NOT NULL,
+        after_text TEXT NOT NULL,
+        tags TEXT,
+        word_count INTEGER,
+        quality_score INTEGER,
+        source TEXT DEFAULT 'bundled'
+    );";
+
+fn upload_request(title: &str) -> UploadExampleRequest {
+    UploadExampleRequest {
+        before_content: "Before text ".repeat(10),
+        after_content: "After text ".repeat(10),
+        metadata: ExampleMetadataInput {
+            title: title.to_string(),
+            category: "educational".to_string(),
+            tags: Some(vec!["pack".to_string()]),
+            quality_score: Some(4),
+        },
+        embedding: vec![0.1; 384],
+    }
+}
+
+fn count_rows(conn: &Connection, table: &str) -> i64 {
+    conn.query_row(&format!("SELECT COUNT(*) FROM {}", table), [], |row| {
+        row.get(0)
+    })
+    .unwrap()
+}
+
+#[test]
+fn test_insert_examples_returns_ids_in_order() {
+    let temp_dir = tempdir().unwrap();
+    let conn = Connection::open(temp_dir.path().join("examples.db")).unwrap();
+    conn.execute_batch(EXAMPLE_SCHEMA).unwrap();
+    conn.execute_batch(
+        "CREATE TABLE embeddings (
+            script_id TEXT PRIMARY KEY,
+            embedding BLOB NOT NULL,
+            dimension INTEGER NOT NULL
+        );",
+    )
+    .unwrap();
+
+    let requests = [upload_request("First"), upload_request("Second")];
+    let ids = insert_examples(&conn, &requests).unwrap();
+
+    assert_eq!(ids.len(), 2);
+    for (id, expected_title) in ids.iter().zip(["First", "Second"]) {
+        let title: String = conn
+            .query_row(
+                "SELECT title FROM example_scripts WHERE id = ?",
+                [id],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(title, expected_title);
+    }
+    assert_eq!(count_rows(&conn, "embeddings"), 2);
+}
+
+#[test]
+fn test_insert_examples_rolls_back_everything_on_failure() {
+    let temp_dir = tempdir().unwrap();
+    let conn = Connection::open(temp_dir.path().join("examples.db")).unwrap();
+    conn.execute_batch(EXAMPLE_SCHEMA).unwrap();
+    // No embeddings table, so the first embedding insert fails after its script row is written
+
+    let requests = [upload_request("First"), upload_request("Second")];
+    let error = insert_examples(&conn, &requests).unwrap_err();
+
+    assert!(
+        error.starts_with("Example 1 ('First')"),
+        "unexpected error: {}",
+        error
+    );
+    assert_eq!(count_rows(&conn, "example_scripts"), 0);
+}
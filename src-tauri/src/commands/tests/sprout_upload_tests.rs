@@ -0,0 +1,67 @@
+/**
+ * Sprout Upload Tests
+ *
+ * Covers picking the upload MIME type and reading video ids from Sprout URLs
+ */
+
+use crate::commands::sprout_upload::{sprout_video_id_from_input, video_mime_type};
+use std::path::Path;
+
+#[test]
+fn test_video_mime_type_from_extension() {
+    assert_eq!(
+        video_mime_type(Path::new("/footage/clip.mp4")),
+        Ok("video/mp4")
+    );
+    assert_eq!(
+        video_mime_type(Path::new("/footage/Interview.MOV")),
+        Ok("video/quicktime")
+    );
+    assert_eq!(
+        video_mime_type(Path::new("/footage/render.mkv")),
+        Ok("video/x-matroska")
+    );
+
+    // Unknown formats are left for Sprout to identify
+    assert_eq!(
+        video_mime_type(Path::new("/footage/capture.r3d")),
+        Ok("application/octet-stream")
+    );
+    assert_eq!(
+        video_mime_type(Path::new("/footage/no_extension")),
+        Ok("application/octet-stream")
+    );
+}
+
+#[test]
+fn test_video_mime_type_rejects_non_video_files() {
+    let error = video_mime_type(Path::new("/project/Script.docx")).unwrap_err();
+    assert!(error.contains("Script.docx"), "unexpected error: {}", error);
+    assert!(
+        error.contains("not a video file"),
+        "unexpected error: {}",
+        error
+    );
+
+    assert!(video_mime_type(Path::new("/project/Thumbnail.PNG")).is_err());
+}
+
+#[test]
+fn test_sprout_video_id_from_url_or_id() {
+    assert_eq!(
+        sprout_video_id_from_input("https://sproutvideo.com/videos/abc123def").as_deref(),
+        Some("abc123def")
+    );
+    assert_eq!(
+        sprout_video_id_from_input("https://videos.sproutvideo.com/embed/abc123def/token")
+            .as_deref(),
+        Some("abc123def")
+    );
+    assert_eq!(
+        sprout_video_id_from_input(" abc123def ").as_deref(),
+        Some("abc123def")
+    );
+
+    assert!(sprout_video_id_from_input("https://youtube.com/watch?v=abc123").is_none());
+    assert!(sprout_video_id_from_input("").is_none());
+}
@@ -0,0 +1,15 @@
+/**
+ * System Command Tests
+ *
+ * Covers cleaning up usernames before they're written to breadcrumbs
+ */
+
+use crate::commands::system::strip_username_domain;
+
+#[test]
+fn test_strip_username_domain() {
+    assert_eq!(strip_username_domain("CORP\\jsmith"), "jsmith");
+    assert_eq!(strip_username_domain("jsmith"), "jsmith");
+    assert_eq!(strip_username_domain("  jsmith\n"), "jsmith");
+    assert_eq!(strip_username_domain("CORP\\"), "");
+}
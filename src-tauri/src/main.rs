@@ -9,16 +9,19 @@ mod utils;
 // Imports
 use log::info;
 use simple_logger::SimpleLogger;
-use std::sync::Mutex;
+use tauri::Manager;
 
 // Re-exports from modules
 use baker::*;
 use commands::*;
-use state::AuthState;
+use state::{AuthState, ConfigState, UploadState};
 
 fn main() {
     SimpleLogger::new().init().unwrap();
 
+    // When relaunched by graceful_restart, let the old instance release its locks first
+    wait_for_restart_parent();
+
     info!("Tauri App Started");
 
     tauri::Builder::default()
@@ -28,70 +31,133 @@ fn main() {
             app.handle()
                 .plugin(tauri_plugin_updater::Builder::new().build())?;
 
+            // Load settings, resetting invalid values rather than failing startup
+            let config_state = ConfigState::load(app.handle());
+            app.manage(config_state);
+
+            // Restore scan history from previous sessions
+            app.manage(baker::ScanState::load(app.handle()));
+
+            // Restore tokens added in previous sessions
+            app.manage(AuthState::load(app.handle()));
+
             Ok(())
         })
-        .manage(AuthState {
-            tokens: Mutex::new(vec![]),
-        })
-        .manage(baker::ScanState::new())
+        .manage(UploadState::new())
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_macos_permissions::init())
         .invoke_handler(tauri::generate_handler![
             get_folders,
+            get_sprout_folders,
+            get_videos_in_folder,
             upload_video,
+            cancel_upload,
+            get_upload_stats,
+            get_recent_uploads,
+            get_upload_diagnostics,
+            estimate_upload,
             graceful_restart,
             check_auth,
             add_token,
+            remove_token,
+            list_tokens,
+            prune_expired_tokens,
             move_files,
             copy_premiere_project,
             show_confirmation_dialog,
             open_resource_file,
             get_username,
+            get_app_config,
+            update_app_config,
+            validate_config,
+            get_config_warnings,
+            clear_cache,
             open_folder,
+            reveal_in_file_manager,
             baker_start_scan,
             baker_get_scan_status,
+            baker_get_scan_metrics,
+            baker_get_scan_summary,
+            baker_export_scan_results,
             baker_cancel_scan,
+            baker_find_duplicate_projects,
             baker_validate_folder,
             baker_read_breadcrumbs,
             baker_update_breadcrumbs,
+            baker_retry_failed_updates,
             baker_scan_current_files,
+            baker_check_file_locks,
+            baker_verify_footage_paths,
             get_folder_size,
             baker_read_raw_breadcrumbs,
+            baker_delete_breadcrumbs,
+            baker_get_audit_trail,
+            baker_write_breadcrumbs_if_unchanged,
+            baker_normalize_breadcrumbs,
+            baker_probe_media,
+            baker_probe_project_media,
+            baker_check_framerate_consistency,
+            baker_generate_contact_sheet,
+            baker_archive_footage,
+            baker_verify_archive,
+            baker_restore_footage,
+            baker_export_xmp,
             // Feature 004: Multiple video links and Trello cards
             baker_get_video_links,
             baker_associate_video_link,
+            baker_associate_last_upload,
+            baker_import_video_links_csv,
+            baker_generate_handoff_manifest,
             baker_remove_video_link,
             baker_update_video_link,
             baker_reorder_video_links,
+            baker_sync_video_titles,
             baker_get_trello_cards,
+            baker_migrate_trello_format,
             baker_associate_trello_card,
+            baker_associate_trello_card_bulk,
             baker_remove_trello_card,
+            baker_update_trello_card,
+            baker_reorder_trello_cards,
             baker_fetch_trello_card_details,
             fetch_trello_boards,
             // Feature 004 Phase 2: Sprout Video URL auto-fetch
             fetch_sprout_video_details,
+            update_sprout_video,
             // Feature 006: AI-Powered Autocue Script Formatter
             parse_docx_file,
             generate_docx_file,
             validate_docx_file,
             validate_provider_connection,
             validate_provider_with_auth,
+            generate_embedding,
             // Feature 006 RAG: Vector search for script examples
             search_similar_scripts,
+            search_scripts_by_text,
+            reembed_all_examples,
             get_example_by_id,
             get_all_examples,
             // Feature 007: Example embedding management
             get_all_examples_with_metadata,
+            search_examples_text,
             upload_example,
+            upload_examples,
             replace_example,
             delete_example,
+            optimize_examples_database,
+            get_database_stats,
+            normalize_existing_embeddings,
             // Premiere Pro Plugin Management
             get_available_plugins,
             install_plugin,
             check_plugin_installed,
+            get_installed_plugin_info,
+            clear_plugin_backups,
+            list_installed_plugins,
             get_cep_directory,
+            check_cep_writable,
             enable_cep_debug_mode,
             open_cep_folder
         ])
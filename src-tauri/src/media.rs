@@ -1,4 +1,15 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Treat an explicit `null` the same as a missing field
+///
+/// Sprout returns nulls for fields such as duration while a new upload is still processing.
+fn null_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
 
 /// Represents a video link (typically Sprout Video) associated with a project
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -46,6 +57,14 @@ pub struct TrelloCard {
     /// ISO 8601 timestamp of last title fetch
     #[serde(rename = "lastFetched", skip_serializing_if = "Option::is_none")]
     pub last_fetched: Option<String>,
+
+    /// Card due date (ISO 8601) from Trello API
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due: Option<String>,
+
+    /// Card label names (label colour when a label is unnamed)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
 }
 
 /// Represents a Trello board with metadata
@@ -81,7 +100,7 @@ pub struct TrelloBoardPrefs {
 }
 
 /// Sprout Video API response structure
-/// Returned from fetch_sprout_video_details command
+/// Returned from fetch_sprout_video_details and upload_video commands
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SproutVideoDetails {
     /// Sprout Video ID
@@ -94,19 +113,51 @@ pub struct SproutVideoDetails {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 
-    /// Video duration in seconds (floating point for precision)
+    /// Video duration in seconds (floating point for precision); 0 until processing finishes
+    #[serde(default, deserialize_with = "null_as_default")]
     pub duration: f64,
 
     /// Asset URLs including thumbnails
+    #[serde(default, deserialize_with = "null_as_default")]
     pub assets: SproutAssets,
 
     /// ISO 8601 timestamp of video creation
     pub created_at: String,
+
+    /// HTML embed snippet for the video
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embed_code: Option<String>,
+
+    /// Public embed URL, when the video has one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embedded_url: Option<String>,
 }
 
-/// Sprout Video assets structure
+/// Sprout Video folder
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SproutFolder {
+    pub id: String,
+    pub name: String,
+    /// None for a root folder
+    #[serde(default)]
+    pub parent_id: Option<String>,
+}
+
+/// One page of folders from the Sprout folders endpoint
+/// Returned from get_sprout_folders command
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SproutFolderPage {
+    pub folders: Vec<SproutFolder>,
+    /// Folders in the parent across all pages, before any name filter
+    pub total: u64,
+    pub page: u32,
+    pub has_next_page: bool,
+}
+
+/// Sprout Video assets structure
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SproutAssets {
-    /// Array of poster frame/thumbnail URLs
+    /// Array of poster frame/thumbnail URLs (empty while a new upload is processing)
+    #[serde(default, deserialize_with = "null_as_default")]
     pub poster_frames: Vec<String>,
 }
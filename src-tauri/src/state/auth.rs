@@ -1,5 +1,167 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::PathBuf;
 use std::sync::Mutex;
+use subtle::ConstantTimeEq;
+use tauri::{AppHandle, Manager};
+
+/// Result of check_auth, serialized as "authenticated" or "unauthorized"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuthStatus {
+    Authenticated,
+    Unauthorized,
+}
+
+/// Service for tokens added without one, i.e. the app's own login token
+pub const DEFAULT_TOKEN_SERVICE: &str = "app";
+
+pub fn default_token_service() -> String {
+    DEFAULT_TOKEN_SERVICE.to_string()
+}
+
+/// A token as persisted to auth_tokens.json
+///
+/// Only a SHA-256 hash of the token is kept, which is all check_auth needs,
+/// plus a masked form for display.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenEntry {
+    pub id: String,
+    pub hash: String,
+    pub masked: String,
+    /// What the token is for, e.g. "app", "sprout" or "trello"
+    #[serde(default = "default_token_service")]
+    pub service: String,
+    pub added_at: String,
+    /// RFC 3339 timestamp after which check_auth rejects the token
+    #[serde(default)]
+    pub expires_at: Option<String>,
+}
+
+impl TokenEntry {
+    /// An expiry that can't be parsed counts as expired
+    pub fn is_expired(&self, now: chrono::DateTime<chrono::Utc>) -> bool {
+        self.expires_at.as_deref().is_some_and(|expires_at| {
+            !chrono::DateTime::parse_from_rfc3339(expires_at)
+                .is_ok_and(|expires_at| expires_at > now)
+        })
+    }
+}
+
+/// A stored token as shown to the frontend, without its hash
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaskedToken {
+    pub id: String,
+    pub masked: String,
+    pub service: String,
+    pub added_at: String,
+    pub expires_at: Option<String>,
+    pub expired: bool,
+}
+
+impl From<&TokenEntry> for MaskedToken {
+    fn from(token: &TokenEntry) -> Self {
+        Self {
+            id: token.id.clone(),
+            masked: token.masked.clone(),
+            service: token.service.clone(),
+            added_at: token.added_at.clone(),
+            expires_at: token.expires_at.clone(),
+            expired: token.is_expired(chrono::Utc::now()),
+        }
+    }
+}
+
+pub fn hash_token(token: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(token.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+/// Keep the last four characters of a token, or none if it's too short to hide the rest
+pub fn mask_token(token: &str) -> String {
+    let chars: Vec<char> = token.chars().collect();
+    if chars.len() <= 8 {
+        return "*".repeat(chars.len());
+    }
+
+    let visible: String = chars[chars.len() - 4..].iter().collect();
+    format!("{}{}", "*".repeat(chars.len() - 4), visible)
+}
 
 pub struct AuthState {
-    pub tokens: Mutex<Vec<String>>, // Simple token storage
+    pub tokens: Mutex<Vec<TokenEntry>>,
+    pub path: Option<PathBuf>,
+}
+
+impl AuthState {
+    /// Load auth_tokens.json from the app data dir, starting empty if it can't be read
+    pub fn load(app: &AppHandle) -> Self {
+        let path = app
+            .path()
+            .app_data_dir()
+            .ok()
+            .map(|dir| dir.join("auth_tokens.json"));
+
+        let tokens = match path.as_ref().filter(|p| p.exists()) {
+            Some(store_path) => match fs::read_to_string(store_path)
+                .map_err(|e| e.to_string())
+                .and_then(|content| {
+                    serde_json::from_str::<Vec<TokenEntry>>(&content).map_err(|e| e.to_string())
+                }) {
+                Ok(tokens) => tokens,
+                Err(e) => {
+                    println!(
+                        "[Auth] Token store could not be read, starting empty: {}",
+                        e
+                    );
+                    Vec::new()
+                }
+            },
+            None => Vec::new(),
+        };
+
+        Self {
+            tokens: Mutex::new(tokens),
+            path,
+        }
+    }
+
+    /// Whether the token is stored and unexpired, compared in constant time
+    ///
+    /// Every stored hash is checked, even after a match, so the time taken
+    /// doesn't reveal how much of a token was right or where it is in the list.
+    pub fn is_valid(&self, token: &str) -> Result<bool, String> {
+        let hash = hash_token(token);
+        let now = chrono::Utc::now();
+        let tokens = self.tokens.lock().map_err(|_| "Failed to acquire lock")?;
+        let found = tokens
+            .iter()
+            .fold(subtle::Choice::from(0), |found, stored| {
+                let unexpired = subtle::Choice::from(!stored.is_expired(now) as u8);
+                found | (stored.hash.as_bytes().ct_eq(hash.as_bytes()) & unexpired)
+            });
+        Ok(found.into())
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = self
+            .path
+            .as_ref()
+            .ok_or("App data directory not available")?;
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create app data directory: {}", e))?;
+        }
+
+        let tokens = self.tokens.lock().map_err(|_| "Failed to acquire lock")?;
+        let json = serde_json::to_string_pretty(&*tokens)
+            .map_err(|e| format!("Failed to serialize tokens: {}", e))?;
+
+        fs::write(path, json).map_err(|e| format!("Failed to write token store: {}", e))
+    }
 }
@@ -0,0 +1,250 @@
+use crate::baker::{compile_camera_folder_pattern, set_camera_folder_pattern};
+use crate::commands::rag::VALID_CATEGORIES;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager};
+
+/// User-tunable application settings, stored as config.json in the app config dir
+///
+/// Missing fields fall back to their defaults so older config files keep loading.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AppConfig {
+    pub scan_max_depth: i32,
+    pub scan_include_hidden: bool,
+    pub default_search_categories: Vec<String>,
+    pub search_top_k: usize,
+    pub search_min_similarity: f32,
+    pub upload_timeout_minutes: u64,
+    /// Regex for camera folders under Footage/, with the label in group 1; None uses the built-in pattern
+    pub camera_folder_pattern: Option<String>,
+    /// Old copies of a plugin kept in the CEP directory after a reinstall
+    pub plugin_backups_to_keep: usize,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            scan_max_depth: 10,
+            scan_include_hidden: false,
+            default_search_categories: Vec::new(),
+            search_top_k: 3,
+            search_min_similarity: 0.5,
+            upload_timeout_minutes: 45,
+            camera_folder_pattern: None,
+            plugin_backups_to_keep: 3,
+        }
+    }
+}
+
+impl AppConfig {
+    /// Build a config from parsed config.json, resetting only fields that don't deserialize
+    ///
+    /// Returns a warning for each field that was reset, so one bad value
+    /// doesn't cost the user every other setting.
+    pub fn from_json_lenient(value: serde_json::Value) -> (Self, Vec<String>) {
+        let mut warnings = Vec::new();
+        let serde_json::Value::Object(fields) = value else {
+            warnings.push("Config file is not a JSON object, using defaults".to_string());
+            return (Self::default(), warnings);
+        };
+
+        let mut merged = match serde_json::to_value(Self::default()) {
+            Ok(serde_json::Value::Object(defaults)) => defaults,
+            _ => return (Self::default(), warnings),
+        };
+
+        for (key, field) in fields {
+            // Unknown keys are ignored, as they are when deserializing directly
+            if !merged.contains_key(&key) {
+                continue;
+            }
+
+            let mut candidate = merged.clone();
+            candidate.insert(key.clone(), field);
+            if serde_json::from_value::<Self>(serde_json::Value::Object(candidate.clone())).is_ok()
+            {
+                merged = candidate;
+            } else {
+                warnings.push(format!(
+                    "{} has an invalid value, reset to the default",
+                    key
+                ));
+            }
+        }
+
+        let config = serde_json::from_value(serde_json::Value::Object(merged)).unwrap_or_default();
+        (config, warnings)
+    }
+
+    /// Collect every problem with the current values
+    pub fn validate(&self) -> Vec<String> {
+        self.clone().sanitize()
+    }
+
+    /// Reset each invalid field to its default, returning the problem found for each
+    ///
+    /// This is the single list of config rules; `validate` runs it on a copy.
+    pub fn sanitize(&mut self) -> Vec<String> {
+        let defaults = AppConfig::default();
+        let mut problems = Vec::new();
+
+        if self.scan_max_depth < 0 {
+            problems.push(format!(
+                "scanMaxDepth must be 0 (unlimited) or more (got {})",
+                self.scan_max_depth
+            ));
+            self.scan_max_depth = defaults.scan_max_depth;
+        }
+
+        for category in &self.default_search_categories {
+            if !VALID_CATEGORIES.contains(&category.as_str()) {
+                problems.push(format!(
+                    "defaultSearchCategories contains unknown category '{}'",
+                    category
+                ));
+            }
+        }
+        self.default_search_categories
+            .retain(|category| VALID_CATEGORIES.contains(&category.as_str()));
+
+        if self.search_top_k == 0 || self.search_top_k > 50 {
+            problems.push(format!(
+                "searchTopK must be between 1 and 50 (got {})",
+                self.search_top_k
+            ));
+            self.search_top_k = defaults.search_top_k;
+        }
+
+        if !(0.0..=1.0).contains(&self.search_min_similarity) {
+            problems.push(format!(
+                "searchMinSimilarity must be between 0 and 1 (got {})",
+                self.search_min_similarity
+            ));
+            self.search_min_similarity = defaults.search_min_similarity;
+        }
+
+        if self.upload_timeout_minutes == 0 {
+            problems.push("uploadTimeoutMinutes must be at least 1".to_string());
+            self.upload_timeout_minutes = defaults.upload_timeout_minutes;
+        }
+
+        if let Some(pattern) = &self.camera_folder_pattern {
+            if let Err(e) = compile_camera_folder_pattern(pattern) {
+                problems.push(format!("cameraFolderPattern is invalid: {}", e));
+                self.camera_folder_pattern = None;
+            }
+        }
+
+        problems
+    }
+}
+
+pub struct ConfigState {
+    pub config: Mutex<AppConfig>,
+    pub warnings: Mutex<Vec<String>>,
+    pub path: Option<PathBuf>,
+}
+
+impl ConfigState {
+    /// Load config.json, resetting invalid values instead of failing startup
+    ///
+    /// Anything that was reset is emitted as a `config_warnings` event and kept
+    /// in `warnings`, since the frontend may not be listening yet during setup.
+    /// The original file is copied to config.json.bak before the repaired
+    /// config is saved over it.
+    pub fn load(app: &AppHandle) -> Self {
+        let path = app
+            .path()
+            .app_config_dir()
+            .ok()
+            .map(|dir| dir.join("config.json"));
+
+        let mut warnings = Vec::new();
+        let mut config = match path.as_ref().filter(|p| p.exists()) {
+            Some(config_path) => match fs::read_to_string(config_path)
+                .map_err(|e| e.to_string())
+                .and_then(|content| {
+                    serde_json::from_str::<serde_json::Value>(&content).map_err(|e| e.to_string())
+                }) {
+                Ok(value) => {
+                    let (config, field_warnings) = AppConfig::from_json_lenient(value);
+                    warnings.extend(field_warnings);
+                    config
+                }
+                Err(e) => {
+                    warnings.push(format!(
+                        "Config file could not be read, using defaults: {}",
+                        e
+                    ));
+                    AppConfig::default()
+                }
+            },
+            None => AppConfig::default(),
+        };
+
+        warnings.extend(
+            config
+                .sanitize()
+                .into_iter()
+                .map(|problem| format!("Reset invalid setting: {}", problem)),
+        );
+
+        // Keep the user's file if it can't be backed up, rather than overwrite it
+        let mut can_save = true;
+        if let Some(config_path) = path.as_ref().filter(|p| p.exists() && !warnings.is_empty()) {
+            let backup_path = config_path.with_extension("json.bak");
+            match fs::copy(config_path, &backup_path) {
+                Ok(_) => warnings.push(format!(
+                    "Original settings saved to {}",
+                    backup_path.display()
+                )),
+                Err(e) => {
+                    println!(
+                        "[Config] Failed to back up config file, not repairing it: {}",
+                        e
+                    );
+                    can_save = false;
+                }
+            }
+        }
+
+        // Already validated by sanitize, so this can't fail
+        let _ = set_camera_folder_pattern(config.camera_folder_pattern.as_deref());
+
+        let state = Self {
+            config: Mutex::new(config),
+            warnings: Mutex::new(warnings.clone()),
+            path,
+        };
+
+        if !warnings.is_empty() {
+            println!("[Config] Reset invalid settings: {:?}", warnings);
+            if can_save {
+                if let Err(e) = state.save() {
+                    println!("[Config] Failed to save repaired config: {}", e);
+                }
+            }
+            let _ = app.emit("config_warnings", warnings);
+        }
+
+        state
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = self.path.as_ref().ok_or("Config directory not available")?;
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create config directory: {}", e))?;
+        }
+
+        let config = self.config.lock().map_err(|_| "Failed to acquire lock")?;
+        let json = serde_json::to_string_pretty(&*config)
+            .map_err(|e| format!("Failed to serialize config: {}", e))?;
+
+        fs::write(path, json).map_err(|e| format!("Failed to write config file: {}", e))
+    }
+}
@@ -1,3 +1,7 @@
 pub mod auth;
+pub mod config;
+pub mod upload;
 
 pub use auth::*;
+pub use config::*;
+pub use upload::*;
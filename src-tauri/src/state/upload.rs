@@ -0,0 +1,261 @@
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::AtomicBool;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+const THROUGHPUT_SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+const MAX_THROUGHPUT_SAMPLES: usize = 300; // Five minutes of history at one sample per second
+const MAX_UPLOAD_LOG_ENTRIES: usize = 100;
+const RECENT_RATE_SAMPLES: usize = 5;
+const DIAGNOSTIC_WINDOW_BYTES: u64 = 10 * 1024 * 1024;
+// A 10MB window slower than this (under ~2.8 Mbit/s) counts as a stall
+const EXPECTED_WINDOW_DURATION: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThroughputSample {
+    pub timestamp: String,
+    pub bytes_per_second: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadStats {
+    pub upload_id: String,
+    pub bytes_uploaded: u64,
+    pub elapsed_seconds: f64,
+    pub average_bytes_per_second: f64,
+    pub peak_bytes_per_second: f64,
+    pub current_bytes_per_second: f64,
+    pub finished: bool,
+    pub samples: Vec<ThroughputSample>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadDiagnostics {
+    pub upload_id: String,
+    pub window_bytes: u64,
+    pub expected_window_seconds: f64,
+    pub windows_completed: u32,
+    pub stalls_detected: u32,
+    pub average_window_seconds: f64,
+    pub slowest_window_seconds: f64,
+    pub bytes_at_last_stall: Option<u64>,
+}
+
+/// Rolling throughput history for a single upload
+pub struct UploadTracker {
+    started_at: Instant,
+    finished_at: Option<Instant>,
+    bytes_uploaded: u64,
+    last_sample_at: Instant,
+    bytes_at_last_sample: u64,
+    peak_bytes_per_second: f64,
+    samples: VecDeque<ThroughputSample>,
+    window_started_at: Instant,
+    bytes_at_window_start: u64,
+    window_durations: Vec<f64>,
+    stalls_detected: u32,
+    bytes_at_last_stall: Option<u64>,
+}
+
+impl UploadTracker {
+    pub fn new() -> Self {
+        let now = Instant::now();
+        Self {
+            started_at: now,
+            finished_at: None,
+            bytes_uploaded: 0,
+            last_sample_at: now,
+            bytes_at_last_sample: 0,
+            peak_bytes_per_second: 0.0,
+            samples: VecDeque::new(),
+            window_started_at: now,
+            bytes_at_window_start: 0,
+            window_durations: Vec::new(),
+            stalls_detected: 0,
+            bytes_at_last_stall: None,
+        }
+    }
+
+    /// Record bytes sent, taking a throughput sample once per interval
+    pub fn record_bytes(&mut self, bytes: u64) {
+        self.bytes_uploaded += bytes;
+        self.record_window();
+
+        let since_sample = self.last_sample_at.elapsed();
+        if since_sample < THROUGHPUT_SAMPLE_INTERVAL {
+            return;
+        }
+
+        let bytes_per_second =
+            (self.bytes_uploaded - self.bytes_at_last_sample) as f64 / since_sample.as_secs_f64();
+
+        self.peak_bytes_per_second = self.peak_bytes_per_second.max(bytes_per_second);
+        self.samples.push_back(ThroughputSample {
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            bytes_per_second,
+        });
+        if self.samples.len() > MAX_THROUGHPUT_SAMPLES {
+            self.samples.pop_front();
+        }
+
+        self.last_sample_at = Instant::now();
+        self.bytes_at_last_sample = self.bytes_uploaded;
+    }
+
+    /// Close the current diagnostic window once it has reached DIAGNOSTIC_WINDOW_BYTES
+    fn record_window(&mut self) {
+        if self.bytes_uploaded - self.bytes_at_window_start < DIAGNOSTIC_WINDOW_BYTES {
+            return;
+        }
+
+        let duration = self.window_started_at.elapsed();
+        if duration > EXPECTED_WINDOW_DURATION {
+            self.stalls_detected += 1;
+            self.bytes_at_last_stall = Some(self.bytes_uploaded);
+        }
+
+        self.window_durations.push(duration.as_secs_f64());
+        self.window_started_at = Instant::now();
+        self.bytes_at_window_start = self.bytes_uploaded;
+    }
+
+    pub fn diagnostics(&self, upload_id: &str) -> UploadDiagnostics {
+        let windows_completed = self.window_durations.len();
+        let average_window_seconds = if windows_completed > 0 {
+            self.window_durations.iter().sum::<f64>() / windows_completed as f64
+        } else {
+            0.0
+        };
+
+        UploadDiagnostics {
+            upload_id: upload_id.to_string(),
+            window_bytes: DIAGNOSTIC_WINDOW_BYTES,
+            expected_window_seconds: EXPECTED_WINDOW_DURATION.as_secs_f64(),
+            windows_completed: windows_completed as u32,
+            stalls_detected: self.stalls_detected,
+            average_window_seconds,
+            slowest_window_seconds: self.window_durations.iter().cloned().fold(0.0, f64::max),
+            bytes_at_last_stall: self.bytes_at_last_stall,
+        }
+    }
+
+    /// Average of the last few throughput samples, or None before the first sample
+    pub fn recent_bytes_per_second(&self) -> Option<f64> {
+        let recent: Vec<f64> = self
+            .samples
+            .iter()
+            .rev()
+            .take(RECENT_RATE_SAMPLES)
+            .map(|sample| sample.bytes_per_second)
+            .collect();
+
+        if recent.is_empty() {
+            None
+        } else {
+            Some(recent.iter().sum::<f64>() / recent.len() as f64)
+        }
+    }
+
+    pub fn finish(&mut self) {
+        if self.finished_at.is_none() {
+            self.finished_at = Some(Instant::now());
+        }
+    }
+
+    pub fn stats(&self, upload_id: &str) -> UploadStats {
+        let elapsed = self
+            .finished_at
+            .unwrap_or_else(Instant::now)
+            .duration_since(self.started_at)
+            .as_secs_f64();
+
+        let average_bytes_per_second = if elapsed > 0.0 {
+            self.bytes_uploaded as f64 / elapsed
+        } else {
+            0.0
+        };
+
+        let current_bytes_per_second = if self.finished_at.is_some() {
+            0.0
+        } else {
+            self.samples
+                .back()
+                .map(|sample| sample.bytes_per_second)
+                .unwrap_or(0.0)
+        };
+
+        UploadStats {
+            upload_id: upload_id.to_string(),
+            bytes_uploaded: self.bytes_uploaded,
+            elapsed_seconds: elapsed,
+            average_bytes_per_second,
+            peak_bytes_per_second: self.peak_bytes_per_second,
+            current_bytes_per_second,
+            finished: self.finished_at.is_some(),
+            samples: self.samples.iter().cloned().collect(),
+        }
+    }
+}
+
+/// One upload in the session history
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadLogEntry {
+    pub upload_id: String,
+    pub file_name: String,
+    pub file_path: String,
+    pub video_id: Option<String>,
+    pub title: Option<String>,
+    pub thumbnail_url: Option<String>,
+    /// "uploading", "completed", "failed" or "cancelled"
+    pub status: String,
+    pub error: Option<String>,
+    pub started_at: String,
+    pub finished_at: Option<String>,
+    pub bytes: u64,
+}
+
+pub struct UploadState {
+    pub uploads: Arc<Mutex<HashMap<String, Arc<Mutex<UploadTracker>>>>>,
+    /// Session upload history, newest last, capped at MAX_UPLOAD_LOG_ENTRIES
+    pub log: Arc<Mutex<VecDeque<UploadLogEntry>>>,
+    /// Cancellation flags for uploads still in progress, keyed by upload id
+    pub cancellations: Arc<Mutex<HashMap<String, Arc<AtomicBool>>>>,
+}
+
+impl UploadState {
+    pub fn new() -> Self {
+        Self {
+            uploads: Arc::new(Mutex::new(HashMap::new())),
+            log: Arc::new(Mutex::new(VecDeque::new())),
+            cancellations: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+/// Add a new entry to the upload log, dropping the oldest beyond the cap
+pub fn push_upload_log(log: &Mutex<VecDeque<UploadLogEntry>>, entry: UploadLogEntry) {
+    if let Ok(mut log) = log.lock() {
+        log.push_back(entry);
+        while log.len() > MAX_UPLOAD_LOG_ENTRIES {
+            log.pop_front();
+        }
+    }
+}
+
+/// Update the log entry for an upload in place, if it is still in the log
+pub fn update_upload_log(
+    log: &Mutex<VecDeque<UploadLogEntry>>,
+    upload_id: &str,
+    update: impl FnOnce(&mut UploadLogEntry),
+) {
+    if let Ok(mut log) = log.lock() {
+        if let Some(entry) = log.iter_mut().find(|entry| entry.upload_id == upload_id) {
+            update(entry);
+        }
+    }
+}
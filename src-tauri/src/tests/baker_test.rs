@@ -0,0 +1,320 @@
+/**
+ * Baker Tests
+ *
+ * Covers breadcrumbs updates, retries and deletes, rescans, conflict detection,
+ * folder sizing, camera folder detection, stale checks, footage verification
+ * and video link validation
+ */
+
+use super::*;
+use std::path::PathBuf;
+use tempfile::tempdir;
+
+/// Create a minimal valid BuildProject folder with one clip in Camera 1
+fn create_project(root: &Path, name: &str) -> PathBuf {
+    let project = root.join(name);
+
+    for folder in [
+        "Footage/Camera 1",
+        "Graphics",
+        "Renders",
+        "Projects",
+        "Scripts",
+    ] {
+        fs::create_dir_all(project.join(folder)).unwrap();
+    }
+    fs::write(project.join("Footage/Camera 1/clip001.mp4"), b"footage").unwrap();
+
+    project
+}
+
+#[tokio::test]
+async fn test_retry_failed_updates_succeeds_after_transient_failure() {
+    let temp_dir = tempdir().unwrap();
+    let project = create_project(temp_dir.path(), "Transient");
+    let project_path = project.to_string_lossy().to_string();
+
+    // Simulate a transient failure: a required subfolder is briefly unavailable
+    // (e.g. a NAS share that hasn't finished mounting)
+    fs::remove_dir(project.join("Scripts")).unwrap();
+
+    let first = baker_update_breadcrumbs(vec![project_path.clone()], true, false, None)
+        .await
+        .unwrap();
+    assert_eq!(first.failed.len(), 1);
+    assert!(first.successful.is_empty());
+
+    let failed_paths: Vec<String> = first.failed.iter().map(|f| f.path.clone()).collect();
+
+    // The folder comes back, so the retry should go through
+    fs::create_dir(project.join("Scripts")).unwrap();
+
+    let retry = baker_retry_failed_updates(failed_paths, true, false, None)
+        .await
+        .unwrap();
+    assert!(retry.failed.is_empty(), "Retry failed: {:?}", retry.failed);
+    assert_eq!(retry.successful, vec![project_path.clone()]);
+    assert_eq!(retry.created, vec![project_path]);
+    assert!(project.join("breadcrumbs.json").exists());
+}
+
+#[tokio::test]
+async fn test_retry_failed_updates_refreshes_stale_backup() {
+    let temp_dir = tempdir().unwrap();
+    let project = create_project(temp_dir.path(), "Backup");
+    let project_path = project.to_string_lossy().to_string();
+
+    baker_update_breadcrumbs(vec![project_path.clone()], true, false, None)
+        .await
+        .unwrap();
+
+    // Backup left behind by an earlier (failed) attempt
+    let backup_path = project.join("breadcrumbs.json.bak");
+    fs::write(&backup_path, "stale").unwrap();
+    let current = fs::read_to_string(project.join("breadcrumbs.json")).unwrap();
+
+    let retry = baker_retry_failed_updates(vec![project_path], false, true, None)
+        .await
+        .unwrap();
+    assert!(retry.failed.is_empty());
+    assert_eq!(fs::read_to_string(&backup_path).unwrap(), current);
+}
+
+#[tokio::test]
+async fn test_rescan_preserves_creator_and_appends_audit_entry() {
+    let temp_dir = tempdir().unwrap();
+    let project = create_project(temp_dir.path(), "Audit");
+    let project_path = project.to_string_lossy().to_string();
+
+    baker_update_breadcrumbs(vec![project_path.clone()], true, false, None)
+        .await
+        .unwrap();
+    baker_update_breadcrumbs(vec![project_path.clone()], false, false, None)
+        .await
+        .unwrap();
+
+    let breadcrumbs = baker_read_breadcrumbs(project_path.clone())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(breadcrumbs.created_by, "Baker");
+
+    let trail = baker_get_audit_trail(project_path).await.unwrap();
+    let actions: Vec<&str> = trail.iter().map(|entry| entry.action.as_str()).collect();
+    assert_eq!(actions, vec!["created", "rescanned"]);
+}
+
+#[tokio::test]
+async fn test_write_if_unchanged_rejects_external_modification() {
+    let temp_dir = tempdir().unwrap();
+    let project = create_project(temp_dir.path(), "Conflict");
+    let project_path = project.to_string_lossy().to_string();
+
+    baker_update_breadcrumbs(vec![project_path.clone()], true, false, None)
+        .await
+        .unwrap();
+    let read = baker_read_breadcrumbs(project_path.clone())
+        .await
+        .unwrap()
+        .unwrap();
+    let expected = read.last_modified.clone();
+
+    // Another editor writes in between our read and write
+    let mut external = read.clone();
+    external.last_modified = Some("2000-01-01T00:00:00Z".to_string());
+    fs::write(
+        project.join("breadcrumbs.json"),
+        serde_json::to_string_pretty(&external).unwrap(),
+    )
+    .unwrap();
+
+    let err = baker_write_breadcrumbs_if_unchanged(project_path.clone(), read.clone(), expected)
+        .await
+        .unwrap_err();
+    assert!(err.starts_with("Conflict:"), "Unexpected error: {}", err);
+
+    let written = baker_write_breadcrumbs_if_unchanged(
+        project_path,
+        read,
+        Some("2000-01-01T00:00:00Z".to_string()),
+    )
+    .await
+    .unwrap();
+    assert_ne!(
+        written.last_modified.as_deref(),
+        Some("2000-01-01T00:00:00Z")
+    );
+}
+
+#[tokio::test]
+async fn test_verify_footage_paths_reports_deleted_clips() {
+    let temp_dir = tempdir().unwrap();
+    let project = create_project(temp_dir.path(), "Missing");
+    fs::write(project.join("Footage/Camera 1/clip002.mp4"), b"footage").unwrap();
+    let project_path = project.to_string_lossy().to_string();
+
+    baker_update_breadcrumbs(vec![project_path.clone()], true, false, None)
+        .await
+        .unwrap();
+    assert!(baker_verify_footage_paths(project_path.clone())
+        .await
+        .unwrap()
+        .is_empty());
+
+    fs::remove_file(project.join("Footage/Camera 1/clip002.mp4")).unwrap();
+
+    let missing = baker_verify_footage_paths(project_path).await.unwrap();
+    assert_eq!(missing.len(), 1);
+    assert_eq!(missing[0].name, "clip002.mp4");
+    assert_eq!(missing[0].camera, 1);
+}
+
+/// Build `dirs` camera folders of `files_per_dir` small files under `root`
+fn create_size_fixture(root: &Path, dirs: usize, files_per_dir: usize) -> u64 {
+    let mut expected = 0u64;
+
+    for dir in 0..dirs {
+        let camera = root.join(format!("Footage/Camera {}", dir + 1));
+        fs::create_dir_all(&camera).unwrap();
+
+        for file in 0..files_per_dir {
+            let contents = vec![0u8; (file % 7) + 1];
+            fs::write(camera.join(format!("clip{:04}.mp4", file)), &contents).unwrap();
+            expected += contents.len() as u64;
+        }
+    }
+
+    expected
+}
+
+#[test]
+fn test_parallel_folder_size_matches_serial() {
+    let temp_dir = tempdir().unwrap();
+    let expected = create_size_fixture(temp_dir.path(), 4, 25);
+    fs::write(temp_dir.path().join("notes.txt"), b"top level").unwrap();
+
+    let serial = calculate_folder_size(temp_dir.path()).unwrap();
+    let parallel = calculate_folder_size_parallel(temp_dir.path()).unwrap();
+
+    assert_eq!(serial, expected + 9);
+    assert_eq!(parallel, serial);
+}
+
+#[test]
+#[ignore = "benchmark; run with --ignored --nocapture"]
+fn bench_parallel_folder_size_on_10k_files() {
+    let temp_dir = tempdir().unwrap();
+    let project = temp_dir.path().join("Project");
+    let expected = create_size_fixture(&project, 20, 500);
+
+    let serial_start = Instant::now();
+    let serial = calculate_folder_size(&project).unwrap();
+    let serial_elapsed = serial_start.elapsed();
+
+    let parallel_start = Instant::now();
+    let parallel = calculate_folder_size_parallel(&project).unwrap();
+    let parallel_elapsed = parallel_start.elapsed();
+
+    assert_eq!(serial, expected);
+    assert_eq!(parallel, expected);
+    println!(
+        "[Bench] 10k files: serial {:?}, parallel {:?} ({:.2}x)",
+        serial_elapsed,
+        parallel_elapsed,
+        serial_elapsed.as_secs_f64() / parallel_elapsed.as_secs_f64()
+    );
+}
+
+#[tokio::test]
+async fn test_delete_breadcrumbs_with_backup() {
+    let temp_dir = tempdir().unwrap();
+    let project = create_project(temp_dir.path(), "Delete");
+    let project_path = project.to_string_lossy().to_string();
+
+    baker_update_breadcrumbs(vec![project_path.clone()], true, false, None)
+        .await
+        .unwrap();
+
+    baker_delete_breadcrumbs(project_path.clone(), true)
+        .await
+        .unwrap();
+    assert!(!project.join("breadcrumbs.json").exists());
+    assert!(project.join("breadcrumbs.json.bak").exists());
+
+    let err = baker_delete_breadcrumbs(project_path, false)
+        .await
+        .unwrap_err();
+    assert_eq!(err, "No breadcrumbs file found");
+}
+
+#[tokio::test]
+async fn test_scan_current_files_accepts_lettered_and_padded_cameras() {
+    let temp_dir = tempdir().unwrap();
+    let project = create_project(temp_dir.path(), "Cameras");
+    for folder in ["Camera A", "Cam 02", "Camera Angles"] {
+        fs::create_dir_all(project.join("Footage").join(folder)).unwrap();
+        fs::write(project.join("Footage").join(folder).join("clip.mp4"), b"x").unwrap();
+    }
+
+    let files = baker_scan_current_files(project.to_string_lossy().to_string())
+        .await
+        .unwrap();
+    let cameras: Vec<(i32, Option<&str>)> = files
+        .iter()
+        .map(|file| (file.camera, file.camera_label.as_deref()))
+        .collect();
+
+    // Lettered cameras are numbered after the numeric ones instead of colliding
+    assert_eq!(
+        cameras,
+        vec![(1, Some("1")), (2, Some("02")), (3, Some("A"))]
+    );
+}
+
+#[tokio::test]
+async fn test_stale_check_catches_same_size_edit_when_hashed() {
+    let temp_dir = tempdir().unwrap();
+    let project = create_project(temp_dir.path(), "Hashed");
+    let project_path = project.to_string_lossy().to_string();
+
+    baker_update_breadcrumbs(vec![project_path.clone()], true, false, Some(true))
+        .await
+        .unwrap();
+    let breadcrumbs = baker_read_breadcrumbs(project_path.clone())
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(breadcrumbs.files[0].sha256.is_some());
+    assert!(!check_breadcrumbs_stale(&project, None, true).unwrap());
+
+    // Re-rendered in place: same name, same size, different contents
+    fs::write(project.join("Footage/Camera 1/clip001.mp4"), b"FOOTAGE").unwrap();
+
+    assert!(!check_breadcrumbs_stale(&project, None, false).unwrap());
+    assert!(check_breadcrumbs_stale(&project, None, true).unwrap());
+}
+
+#[test]
+fn test_validate_video_link_rejects_bad_urls_and_extracts_sprout_id() {
+    let link = |url: &str| VideoLink {
+        url: url.to_string(),
+        sprout_video_id: None,
+        title: "Edit".to_string(),
+        thumbnail_url: None,
+        upload_date: None,
+        source_render_file: None,
+    };
+
+    assert!(validate_video_link(&mut link("  ")).is_err());
+    assert!(validate_video_link(&mut link("ftp://sproutvideo.com/videos/abc123")).is_err());
+    assert!(validate_video_link(&mut link("not a url")).is_err());
+
+    let mut sprout = link(" https://sproutvideo.com/videos/abc123def ");
+    validate_video_link(&mut sprout).unwrap();
+    assert_eq!(sprout.url, "https://sproutvideo.com/videos/abc123def");
+    assert_eq!(sprout.sprout_video_id.as_deref(), Some("abc123def"));
+
+    let mut other = link("https://vimeo.com/12345");
+    validate_video_link(&mut other).unwrap();
+    assert!(other.sprout_video_id.is_none());
+}
@@ -0,0 +1,141 @@
+/**
+ * DOCX Tests
+ *
+ * Covers parsing .docx text and formatting, round-tripping generated
+ * documents through the parser, and file validation and size limits
+ */
+
+use super::*;
+
+fn fixture_path() -> String {
+    format!("{}/test_fixtures/sample.docx", env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Slice text_content by character offsets, as the ranges use
+fn slice_chars(text: &str, start: usize, end: usize) -> String {
+    text.chars().skip(start).take(end - start).collect()
+}
+
+#[test]
+fn test_parse_docx_file_bold_range_matches_text() {
+    let result = parse_docx_file(fixture_path(), None).unwrap();
+    let bold_ranges = &result.formatting_metadata.bold_ranges;
+
+    // "annual " and "review" are separate runs in the fixture, merged into one range
+    assert_eq!(bold_ranges.len(), 1);
+    let bold = &bold_ranges[0];
+    assert_eq!(bold.text, "annual review");
+    assert_eq!(
+        slice_chars(&result.text_content, bold.start, bold.end),
+        "annual review"
+    );
+
+    let italic = &result.formatting_metadata.italic_ranges[0];
+    assert_eq!(
+        slice_chars(&result.text_content, italic.start, italic.end),
+        "thank you"
+    );
+
+    // Offsets are in characters, so the accented "Café " doesn't shift them
+    let underline = &result.formatting_metadata.underline_ranges[0];
+    assert_eq!(
+        slice_chars(&result.text_content, underline.start, underline.end),
+        "underlined words"
+    );
+}
+
+#[test]
+fn test_parse_docx_file_extracts_structure() {
+    let result = parse_docx_file(fixture_path(), None).unwrap();
+    let metadata = &result.formatting_metadata;
+
+    // The empty paragraph in the fixture is dropped
+    assert_eq!(
+        result.text_content,
+        "Opening Scene\nWelcome to the annual review and thank you for coming.\nFirst point\nSecond point\nCafé underlined words"
+    );
+    assert_eq!(metadata.paragraphs.len(), 5);
+    for paragraph in &metadata.paragraphs {
+        assert_eq!(
+            slice_chars(&result.text_content, paragraph.start, paragraph.end),
+            paragraph.text
+        );
+    }
+
+    assert_eq!(metadata.headings.len(), 1);
+    assert_eq!(metadata.headings[0].level, 1);
+    assert_eq!(metadata.headings[0].text, "Opening Scene");
+    assert_eq!(metadata.headings[0].position, 0);
+
+    let list_types: Vec<&str> = metadata
+        .lists
+        .iter()
+        .map(|item| item.item_type.as_str())
+        .collect();
+    assert_eq!(list_types, vec!["unordered", "ordered"]);
+    assert_eq!(metadata.lists[0].level, 1);
+
+    assert!(result
+        .html_content
+        .starts_with("<h1>Opening Scene</h1><p>Welcome to the <strong>annual review</strong>"));
+}
+
+#[test]
+fn test_generate_docx_round_trips_through_parser() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let output_path = resolve_output_path(temp_dir.path(), "Formatted Script.docx");
+
+    let html = "<h2>Intro &amp; Welcome</h2>\n<p>Hello <strong>everyone</strong>, and <em>thanks</em> for coming.</p>\n<ol><li>One</li><li>Two</li></ol><p></p>";
+    write_docx(html, &output_path).unwrap();
+
+    let result = parse_docx_file(output_path.to_string_lossy().to_string(), None).unwrap();
+    assert_eq!(
+        result.text_content,
+        "Intro & Welcome\nHello everyone, and thanks for coming.\nOne\nTwo"
+    );
+
+    let metadata = &result.formatting_metadata;
+    assert_eq!(metadata.headings.len(), 1);
+    assert_eq!(metadata.headings[0].level, 2);
+    assert_eq!(metadata.bold_ranges[0].text, "everyone");
+    assert_eq!(metadata.italic_ranges[0].text, "thanks");
+    assert_eq!(metadata.lists.len(), 2);
+    assert!(metadata
+        .lists
+        .iter()
+        .all(|item| item.item_type == "ordered"));
+
+    // A second file with the same name doesn't overwrite the first
+    let second_path = resolve_output_path(temp_dir.path(), "Formatted Script.docx");
+    assert_eq!(
+        second_path.file_name().unwrap(),
+        "Formatted Script (1).docx"
+    );
+}
+
+#[test]
+fn test_validate_docx_file_rejects_renamed_text_file() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let fake_path = temp_dir.path().join("notes.docx");
+    fs::write(&fake_path, "Just some plain text, not a Word document").unwrap();
+
+    let result = validate_docx_file(fake_path.to_string_lossy().to_string(), None);
+    assert_eq!(
+        result.unwrap_err(),
+        "Not a valid Word document (corrupt or wrong format)"
+    );
+
+    assert!(validate_docx_file(fixture_path(), None).unwrap());
+}
+
+#[test]
+fn test_docx_size_limit_is_configurable() {
+    // The fixture is a couple of KB, so a 0 MB cap rejects it
+    let error = parse_docx_file(fixture_path(), Some(0)).unwrap_err();
+    assert!(error.contains("0 MB limit"), "unexpected error: {}", error);
+
+    let error = validate_docx_file(fixture_path(), Some(0)).unwrap_err();
+    assert!(error.contains("0 MB limit"), "unexpected error: {}", error);
+
+    assert!(validate_docx_file(fixture_path(), Some(DEFAULT_DOCX_SIZE_LIMIT_MB)).unwrap());
+}
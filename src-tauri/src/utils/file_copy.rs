@@ -1,25 +1,83 @@
+use serde::Serialize;
+use sha2::{Digest, Sha256};
 use std::fs::File;
-use std::io::{BufReader, BufWriter, Read, Write};
+use std::io::{BufReader, BufWriter, ErrorKind, Read, Write};
 use std::path::Path;
+use std::time::{Duration, Instant};
 use tauri::{AppHandle, Emitter};
 
+const PROGRESS_UPDATE_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Payload of the `copy_file_progress` event
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CopyFileProgress<'a> {
+    pub file_name: &'a str,
+    /// Zero-based position of this file in the batch
+    pub file_index: usize,
+    pub total_files: usize,
+    /// Progress through the whole batch, 0-100
+    pub overall_percentage: f64,
+}
+
 /// File Copy with Overall Progress Tracking across multiple files
+///
+/// Emits `copy_progress` with the batch percentage, combining the files
+/// already copied with progress through this one, and `copy_file_progress`
+/// with the same percentage plus the name of the file being copied. Both
+/// are sent at most once per PROGRESS_UPDATE_INTERVAL, plus once when the
+/// file is done.
+///
+/// With `sync`, the copy is synced to disk before returning, so the source
+/// can safely be deleted. `verify` implies `sync` and also checks the copy's
+/// size and SHA-256 against the source, which means reading the copy back.
 pub fn copy_file_with_overall_progress(
     src: &Path,
     dest: &Path,
     app_handle: &AppHandle,
     file_index: usize,
     total_files: usize,
+    sync: bool,
+    verify: bool,
 ) -> std::io::Result<()> {
     let src_file = File::open(src)?;
     let dest_file = File::create(dest)?;
     let metadata = src.metadata()?;
     let total_size = metadata.len();
     let mut copied_size: u64 = 0;
+    let file_name = src
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
 
     let mut reader = BufReader::new(src_file);
     let mut writer = BufWriter::new(dest_file);
     let mut buffer = [0; 8192];
+    let mut source_hasher = verify.then(Sha256::new);
+    let mut last_progress_update: Option<Instant> = None;
+
+    let emit_progress = |copied_size: u64| {
+        // Calculate overall progress across all files, capping this file at
+        // 100% in case it grew after its size was read
+        let file_progress = if total_size == 0 {
+            1.0
+        } else {
+            copied_size as f64 / total_size.max(copied_size) as f64
+        };
+        let files_completed = file_index as f64;
+        let overall_progress = (files_completed + file_progress) / total_files as f64 * 100.0;
+
+        let _ = app_handle.emit("copy_progress", overall_progress);
+        let _ = app_handle.emit(
+            "copy_file_progress",
+            CopyFileProgress {
+                file_name: &file_name,
+                file_index,
+                total_files,
+                overall_percentage: overall_progress,
+            },
+        );
+    };
 
     loop {
         let bytes_read = reader.read(&mut buffer)?;
@@ -27,16 +85,85 @@ pub fn copy_file_with_overall_progress(
             break;
         }
         writer.write_all(&buffer[..bytes_read])?;
+        if let Some(hasher) = source_hasher.as_mut() {
+            hasher.update(&buffer[..bytes_read]);
+        }
         copied_size += bytes_read as u64;
 
-        // Calculate overall progress across all files
-        let file_progress = copied_size as f64 / total_size as f64;
-        let files_completed = file_index as f64;
-        let overall_progress = (files_completed + file_progress) / total_files as f64 * 100.0;
-
-        let _ = app_handle.emit("copy_progress", overall_progress);
+        if last_progress_update.is_none_or(|last| last.elapsed() >= PROGRESS_UPDATE_INTERVAL) {
+            emit_progress(copied_size);
+            last_progress_update = Some(Instant::now());
+        }
     }
 
     writer.flush()?;
+    emit_progress(copied_size);
+
+    if sync || verify {
+        let dest_file = writer.into_inner().map_err(|e| e.into_error())?;
+        dest_file.sync_all()?;
+    }
+
+    if let Some(hasher) = source_hasher {
+        verify_copy(dest, copied_size, &hasher.finalize())?;
+    }
+
     Ok(())
 }
+
+/// Check a synced copy has the expected size and SHA-256
+///
+/// A copy that doesn't match is deleted, so nothing later mistakes it for a
+/// good one.
+pub(crate) fn verify_copy(
+    dest: &Path,
+    expected_size: u64,
+    expected_hash: &[u8],
+) -> std::io::Result<()> {
+    let mismatch = copy_mismatch(dest, expected_size, expected_hash)?;
+
+    match mismatch {
+        Some(reason) => {
+            std::fs::remove_file(dest)?;
+            Err(std::io::Error::new(ErrorKind::InvalidData, reason))
+        }
+        None => Ok(()),
+    }
+}
+
+/// Describe how a copy differs from the source, or None if it matches
+fn copy_mismatch(
+    dest: &Path,
+    expected_size: u64,
+    expected_hash: &[u8],
+) -> std::io::Result<Option<String>> {
+    let dest_size = dest.metadata()?.len();
+    if dest_size != expected_size {
+        return Ok(Some(format!(
+            "Copy of {} is {} bytes but {} were read from the source",
+            dest.display(),
+            dest_size,
+            expected_size
+        )));
+    }
+
+    let mut reader = BufReader::new(File::open(dest)?);
+    let mut hasher = Sha256::new();
+    let mut buffer = [0; 8192];
+    loop {
+        let bytes_read = reader.read(&mut buffer)?;
+        if bytes_read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..bytes_read]);
+    }
+
+    if hasher.finalize().as_slice() != expected_hash {
+        return Ok(Some(format!(
+            "Copy of {} doesn't match the source",
+            dest.display()
+        )));
+    }
+
+    Ok(None)
+}
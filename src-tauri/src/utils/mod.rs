@@ -1 +1,2 @@
 pub mod file_copy;
+pub mod open;
@@ -0,0 +1,68 @@
+use std::path::Path;
+use std::process::Command;
+
+#[cfg(target_os = "macos")]
+const FILE_MANAGER: &str = "open";
+#[cfg(target_os = "windows")]
+const FILE_MANAGER: &str = "explorer";
+#[cfg(target_os = "linux")]
+const FILE_MANAGER: &str = "xdg-open";
+
+/// Explorer only understands backslash-separated paths
+fn native_path(path: &str) -> String {
+    if cfg!(target_os = "windows") {
+        path.replace('/', "\\")
+    } else {
+        path.to_string()
+    }
+}
+
+/// Launch the file manager, explaining a missing launcher rather than just "not found"
+fn spawn_file_manager(command: &mut Command, action: &str) -> Result<(), String> {
+    command.spawn().map(|_| ()).map_err(|e| match e.kind() {
+        std::io::ErrorKind::NotFound => format!(
+            "Could not {} because {} is not available on this system",
+            action, FILE_MANAGER
+        ),
+        _ => format!("Failed to {}: {}", action, e),
+    })
+}
+
+/// Open a folder in Finder, Explorer or the default Linux file manager
+pub fn open_folder(path: &str) -> Result<(), String> {
+    if !Path::new(path).exists() {
+        return Err(format!("Folder not found: {}", path));
+    }
+
+    spawn_file_manager(
+        Command::new(FILE_MANAGER).arg(native_path(path)),
+        "open the folder",
+    )?;
+
+    println!("Opened folder: {}", path);
+    Ok(())
+}
+
+/// Show a file selected in Finder or Explorer
+///
+/// Linux file managers have no common way to select a file, so its parent
+/// folder is opened instead.
+pub fn reveal_file(path: &str) -> Result<(), String> {
+    let target = Path::new(path);
+    if !target.exists() {
+        return Err(format!("File not found: {}", path));
+    }
+
+    let mut command = Command::new(FILE_MANAGER);
+
+    #[cfg(target_os = "macos")]
+    command.arg("-R").arg(target);
+
+    #[cfg(target_os = "windows")]
+    command.arg(format!("/select,{}", native_path(path)));
+
+    #[cfg(target_os = "linux")]
+    command.arg(target.parent().unwrap_or(target));
+
+    spawn_file_manager(&mut command, "show the file")
+}
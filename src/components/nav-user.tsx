@@ -16,6 +16,7 @@ import { createQueryError, createQueryOptions, shouldRetry } from '@lib/query-ut
 import { useQuery } from '@tanstack/react-query'
 import { core } from '@tauri-apps/api'
 import { getVersion } from '@tauri-apps/api/app'
+import type { UsernameInfo } from '@utils/types'
 import { ChevronsUpDown, LogOut } from 'lucide-react'
 import { Link } from 'react-router-dom'
 
@@ -58,7 +59,8 @@ export function NavUser({ user, onLogout, onUpdateClicked }: Props) {
       queryKeys.user.authentication(),
       async () => {
         try {
-          return await core.invoke<string>('get_username')
+          const username = await core.invoke<UsernameInfo>('get_username')
+          return username.name
         } catch (error) {
           throw createQueryError(`Failed to fetch username: ${error}`, 'AUTHENTICATION')
         }
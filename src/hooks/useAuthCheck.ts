@@ -21,8 +21,10 @@ async function checkAuthStatus(): Promise<AuthCheckResult> {
   }
 
   try {
-    const response = await invoke<string>('check_auth', { token })
-    if (response.includes('authenticated')) {
+    const response = await invoke<'authenticated' | 'unauthorized'>('check_auth', {
+      token
+    })
+    if (response === 'authenticated') {
       return {
         isAuthenticated: true,
         username: storedUsername
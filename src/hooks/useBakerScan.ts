@@ -37,7 +37,7 @@ export function useBakerScan(): UseBakerScanResult {
             prev
               ? {
                   ...prev,
-                  totalFolders: progressData.totalFolders,
+                  totalFolders: progressData.foldersScanned,
                   validProjects: progressData.projectsFound
                 }
               : null
@@ -63,6 +63,10 @@ export function useBakerScan(): UseBakerScanResult {
       listen<ScanErrorEvent>('baker_scan_error', (event) => {
         const errorData = event.payload
         if (currentScanId && errorData.scanId === currentScanId) {
+          // Unreadable folders are skipped and listed in the final result's errors
+          if (errorData.fatal === false) {
+            return
+          }
           setError(errorData.error.message)
           setIsScanning(false)
           setCurrentScanId(null)
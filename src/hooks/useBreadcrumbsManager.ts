@@ -18,7 +18,7 @@ export function useBreadcrumbsManager(): UseBreadcrumbsManagerResult {
   const updateBreadcrumbs = useCallback(
     async (
       projectPaths: string[],
-      options: { createMissing: boolean; backupOriginals: boolean }
+      options: { createMissing: boolean; backupOriginals: boolean; hashFiles?: boolean }
     ): Promise<BatchUpdateResult> => {
       if (isUpdating) {
         const error = new Error('Update operation already in progress')
@@ -39,7 +39,8 @@ export function useBreadcrumbsManager(): UseBreadcrumbsManagerResult {
         const result = await invoke<BatchUpdateResult>('baker_update_breadcrumbs', {
           projectPaths,
           createMissing: options.createMissing,
-          backupOriginals: options.backupOriginals
+          backupOriginals: options.backupOriginals,
+          hashFiles: options.hashFiles ?? false
         })
 
         setLastUpdateResult(result)
@@ -40,6 +40,16 @@ export function useExampleManagement() {
     }
   })
 
+  // Mutation: Upload a pack of examples in one transaction (all or nothing)
+  const uploadExamples = useMutation({
+    mutationFn: async (requests: UploadRequest[]) => {
+      return await invoke<string[]>('upload_examples', { requests })
+    },
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['examples'] })
+    }
+  })
+
   // Mutation: Replace existing example
   const replaceExample = useMutation({
     mutationFn: async ({ id, request }: { id: string; request: ReplaceRequest }) => {
@@ -69,6 +79,7 @@ export function useExampleManagement() {
 
     // Mutations
     uploadExample,
+    uploadExamples,
     replaceExample,
     deleteExample
   }
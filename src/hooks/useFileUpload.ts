@@ -1,10 +1,11 @@
+import { createDefaultSproutUploadResponse } from '@pages/UploadTrello/UploadTrelloTypes'
 import { appStore } from '@store/useAppStore'
 import { invoke } from '@tauri-apps/api/core'
-import { listen } from '@tauri-apps/api/event'
 import { open } from '@tauri-apps/plugin-dialog'
 import { SproutUploadResponse } from '@utils/types'
 import { useState } from 'react'
 
+import type { SproutVideoDetails } from '@/types/media'
 import { logger } from '@/utils/logger'
 
 interface UseFileUploadReturn {
@@ -53,66 +54,23 @@ export const useFileUpload = (): UseFileUploadReturn => {
     setResponse(null)
 
     try {
-      // Create a promise with timeout that will wait for either upload_complete or upload_error event
-      const finalResponse = await new Promise<SproutUploadResponse>((resolve, reject) => {
-        let completeUnlisten: Promise<() => void> | null = null
-        let errorUnlisten: Promise<() => void> | null = null
-        let timeoutId: NodeJS.Timeout | null = null
-
-        const cleanup = async () => {
-          if (timeoutId) clearTimeout(timeoutId)
-          if (completeUnlisten) {
-            try {
-              const unsub = await completeUnlisten
-              unsub()
-            } catch (e) {
-              logger.warn('Failed to unsubscribe from upload_complete:', e)
-            }
-          }
-          if (errorUnlisten) {
-            try {
-              const unsub = await errorUnlisten
-              unsub()
-            } catch (e) {
-              logger.warn('Failed to unsubscribe from upload_error:', e)
-            }
-          }
-        }
-
-        // Set up 45-minute timeout for large file uploads
-        timeoutId = setTimeout(
-          async () => {
-            await cleanup()
-            reject(
-              'Upload timed out after 45 minutes. Please try again or check your network connection.'
-            )
-          },
-          45 * 60 * 1000
-        ) // 45 minutes
-
-        // Listen for the upload_complete event and resolve with its payload
-        completeUnlisten = listen('upload_complete', async (event) => {
-          await cleanup()
-          resolve(event.payload as SproutUploadResponse)
-        })
-
-        // Listen for the upload_error event and reject with its payload
-        errorUnlisten = listen('upload_error', async (event) => {
-          await cleanup()
-          reject(event.payload)
-        })
-
-        // Invoke the Rust backend command to start the upload
-        invoke('upload_video', {
-          filePath: selectedFile,
-          apiKey: apiKey,
-          folderId: selectedFolder
-        }).catch(async (error) => {
-          await cleanup()
-          reject(error)
-        })
+      // upload_video resolves with this call's video, or rejects if it fails
+      const video = await invoke<SproutVideoDetails>('upload_video', {
+        filePath: selectedFile,
+        apiKey: apiKey,
+        folderId: selectedFolder
       })
 
+      const defaults = createDefaultSproutUploadResponse()
+      const finalResponse: SproutUploadResponse = {
+        ...defaults,
+        ...video,
+        description: video.description ?? '',
+        embed_code: video.embed_code ?? '',
+        embedded_url: video.embedded_url ?? null,
+        assets: { ...defaults.assets, poster_frames: video.assets.poster_frames }
+      }
+
       // Update the state with the final response from the backend upload
       setResponse(finalResponse)
       appStore.getState().setLatestSproutUpload(finalResponse)
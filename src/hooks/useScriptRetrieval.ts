@@ -19,12 +19,18 @@ export interface SimilarExample {
   before_text: string
   after_text: string
   similarity: number
+  tags: string[]
+  source: 'bundled' | 'user-uploaded'
 }
 
 interface UseScriptRetrievalOptions {
   enabled?: boolean
   topK?: number
   minSimilarity?: number
+  /** Lowest quality_score to include (backend default 4; unrated examples count as 3) */
+  minQuality?: number
+  /** Only return examples in these categories (e.g. ['interview']) */
+  categories?: string[]
 }
 
 interface UseScriptRetrievalResult {
@@ -38,11 +44,24 @@ export function useScriptRetrieval(
   scriptText: string,
   options: UseScriptRetrievalOptions = {}
 ): UseScriptRetrievalResult {
-  const { enabled = true, topK = 3, minSimilarity = 0.65 } = options
+  const {
+    enabled = true,
+    topK = 3,
+    minSimilarity = 0.65,
+    minQuality,
+    categories
+  } = options
   const { embed, isReady } = useEmbedding()
 
   const { data, isLoading, isError, error } = useQuery({
-    queryKey: ['script-retrieval', scriptText.slice(0, 100), topK, minSimilarity],
+    queryKey: [
+      'script-retrieval',
+      scriptText.slice(0, 100),
+      topK,
+      minSimilarity,
+      minQuality,
+      categories
+    ],
     queryFn: async (): Promise<SimilarExample[]> => {
       logger.log('Starting retrieval...')
 
@@ -55,7 +74,9 @@ export function useScriptRetrieval(
       const results = await invoke<SimilarExample[]>('search_similar_scripts', {
         queryEmbedding: embedding,
         topK,
-        minSimilarity
+        minSimilarity,
+        minQuality,
+        categories
       })
 
       logger.log(`Found ${results.length} similar examples`)
@@ -35,16 +35,14 @@ interface FetchVideoDetailsParams {
 export function useSproutVideoApi() {
   const fetchVideoDetails = useMutation({
     mutationFn: async ({ videoUrl, apiKey }: FetchVideoDetailsParams) => {
-      // Parse URL to extract video ID
-      const videoId = parseSproutVideoUrl(videoUrl)
-
-      if (!videoId) {
+      // Reject non-Sprout URLs before calling the backend
+      if (!parseSproutVideoUrl(videoUrl)) {
         throw new Error('Invalid Sprout Video URL format')
       }
 
-      // Fetch details from Sprout API via Tauri command
+      // Fetch details from Sprout API via Tauri command (the backend extracts the ID)
       const details = await invoke<SproutVideoDetails>('fetch_sprout_video_details', {
-        videoId,
+        videoUrl,
         apiKey
       })
 
@@ -44,7 +44,8 @@ async function performUpdate(): Promise<void> {
   }
 
   await update.downloadAndInstall()
-  await invoke('graceful_restart')
+  // Force the restart in dev so the update flow can be tested end to end
+  await invoke('graceful_restart', { force: import.meta.env.DEV })
 }
 
 /**
@@ -7,6 +7,14 @@ import { useCallback, useEffect, useRef } from 'react'
 
 import { logger } from '@/utils/logger'
 
+// Payload of the upload_progress event, emitted at most every 100ms
+interface UploadProgressPayload {
+  percentage: number
+  bytesUploaded: number
+  totalBytes: number
+  secondsRemaining: number | null
+}
+
 interface UseUploadEventsReturn {
   progress: number
   uploading: boolean
@@ -109,10 +117,9 @@ export const useUploadEvents = (): UseUploadEventsReturn => {
 
     const setupListeners = async () => {
       try {
-        unlistenProgress = await listen('upload_progress', (event) => {
+        unlistenProgress = await listen<UploadProgressPayload>('upload_progress', (event) => {
           if (isMounted) {
-            const progressValue = event.payload as number
-            updateUploadState({ progress: progressValue })
+            updateUploadState({ progress: Math.round(event.payload.percentage) })
           }
         })
 
@@ -1,5 +1,6 @@
 import { useQuery } from '@tanstack/react-query'
 import { core } from '@tauri-apps/api'
+import type { UsernameInfo } from '@utils/types'
 
 /**
  * Custom hook that fetches the current user's username using TanStack React Query.
@@ -9,8 +10,8 @@ export function useUsername() {
   return useQuery({
     queryKey: ['username'],
     queryFn: async () => {
-      const name = await core.invoke<string>('get_username')
-      return name
+      const username = await core.invoke<UsernameInfo>('get_username')
+      return username.name
     },
     staleTime: 1000 * 60 * 5, // Consider data fresh for 5 minutes
     retry: 2 // Retry failed requests twice
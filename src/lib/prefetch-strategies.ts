@@ -5,6 +5,7 @@ import { getVersion } from '@tauri-apps/api/app'
 import { invoke } from '@tauri-apps/api/core'
 import { createNamespacedLogger } from '@utils/logger'
 import { loadApiKeys } from '@utils/storage'
+import type { UsernameInfo } from '@utils/types'
 
 import { queryKeys } from './query-keys'
 import { createQueryError, createQueryOptions, shouldRetry } from './query-utils'
@@ -86,7 +87,8 @@ export class QueryPrefetchManager {
         queryKeys.user.authentication(),
         async () => {
           try {
-            return await core.invoke<string>('get_username')
+            const username = await core.invoke<UsernameInfo>('get_username')
+            return username.name
           } catch (error) {
             throw createQueryError(`Failed to fetch username: ${error}`, 'AUTHENTICATION')
           }
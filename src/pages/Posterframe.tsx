@@ -99,7 +99,10 @@ const PosterframeContent: React.FC = () => {
       try {
         await writeFile(fullPath, uint8Array)
         toast.success(`Thumbnail saved at: ${fullPath}`)
-        invoke('open_folder', { path: savePath })
+        invoke('open_folder', { path: savePath }).catch((openError) => {
+          logger.error('Open folder failed:', openError)
+          toast.error(String(openError))
+        })
       } catch (err) {
         logger.error('Save failed:', err)
         toast.error('Error saving file. Please check permissions and try again.')
@@ -19,7 +19,16 @@ export interface ProjectFolder {
   invalidBreadcrumbs: boolean // true if breadcrumbs file exists but is corrupted/unparseable
   lastScanned: string // ISO timestamp
   cameraCount: number
-  validationErrors: string[]
+  validationErrors: ValidationError[]
+  folderSize?: number // Bytes; a lower bound when scanned in fast size mode
+}
+
+export type ValidationErrorCode = 'FOLDER_NOT_FOUND' | 'MISSING_SUBFOLDER' | 'NO_CAMERAS'
+
+export interface ValidationError {
+  code: ValidationErrorCode
+  message: string // Human-readable description
+  subfolder?: string // Set for MISSING_SUBFOLDER
 }
 
 export interface BreadcrumbsFile {
@@ -42,12 +51,26 @@ export interface BreadcrumbsFile {
 
   /** Array of Trello cards associated with this project */
   trelloCards?: TrelloCard[]
+
+  /** One entry per write, oldest first */
+  modificationHistory?: AuditEntry[]
+
+  /** Cold-storage archive holding this project's Footage folder, if archived */
+  archivedTo?: string
+}
+
+export interface AuditEntry {
+  by: string
+  at: string // ISO timestamp
+  action: string
 }
 
 export interface FileInfo {
   camera: number
+  cameraLabel?: string // Raw camera folder suffix, e.g. "A" or "01"
   name: string
   path: string
+  sha256?: string // Only present when breadcrumbs were written with hashFiles
 }
 
 export interface ScanResult {
@@ -61,22 +84,58 @@ export interface ScanResult {
   totalFolderSize: number // Total size in bytes of all scanned folders
   errors: ScanError[]
   projects: ProjectFolder[]
+  metrics?: ScanMetrics
+  cancelled?: boolean // True if stopped by baker_cancel_scan
+  bakerScanSkipped?: number // Projects reused unchanged from the incremental scan cache
+}
+
+/** Aggregate totals for a scan, from baker_get_scan_summary */
+export interface ScanSummary {
+  totalProjects: number
+  validProjects: number
+  invalidProjects: number
+  staleBreadcrumbs: number
+  missingBreadcrumbs: number
+  totalCameras: number
+  totalFolderSize: number // Bytes
+  averageFolderSize: number // Bytes
+  largestProject: { path: string; name: string; folderSize: number } | null
+}
+
+/** Time spent in each scan phase, in milliseconds */
+export interface ScanMetrics {
+  totalMs: number
+  enumerationMs: number
+  validationMs: number
+  sizeCalculationMs: number
+  staleDetectionMs: number
+  foldersPerSecond: number
 }
 
 export interface ScanError {
   path: string
-  type: 'permission' | 'structure' | 'filesystem' | 'corruption'
+  type: 'permission' | 'structure' | 'filesystem' | 'corruption' | 'io'
   message: string
   timestamp: string
 }
 
 export interface ScanOptions {
-  maxDepth: number
+  maxDepth: number // 0 = unlimited
   includeHidden: boolean
   createMissing: boolean
   backupOriginals: boolean
+  /** 'fast' trades exact folder sizes for a quicker scan; defaults to 'exact' */
+  sizeMode?: SizeMode
+  /** Count folders first so progress has a real total; defaults to true */
+  estimateTotal?: boolean
+  /** Reuse cached results for project folders unchanged since the last scan */
+  incremental?: boolean
+  /** Hash footage contents so in-place edits are detected as stale (slow on large media) */
+  hashFiles?: boolean
 }
 
+export type SizeMode = 'exact' | 'fast'
+
 export interface ScanPreferences {
   autoUpdate: boolean
   createMissing: boolean
@@ -119,6 +178,7 @@ export interface ScanCompleteEvent {
 
 export interface ScanErrorEvent {
   scanId: string
+  fatal?: boolean // false for a single unreadable folder; the scan carries on
   error: ScanError
 }
 
@@ -173,6 +233,7 @@ export interface UseBreadcrumbsManagerResult {
     options: {
       createMissing: boolean
       backupOriginals: boolean
+      hashFiles?: boolean
     }
   ) => Promise<BatchUpdateResult>
   clearResults: () => void
@@ -44,6 +44,12 @@ export interface TrelloCard {
 
   /** ISO 8601 timestamp of last title fetch */
   lastFetched?: string
+
+  /** Card due date (ISO 8601) from Trello API */
+  due?: string
+
+  /** Card label names (label colour when a label is unnamed) */
+  labels?: string[]
 }
 
 /**
@@ -82,7 +88,7 @@ export interface TrelloBoardPrefs {
 
 /**
  * Sprout Video API response structure
- * Returned from fetch_sprout_video_details Tauri command
+ * Returned from fetch_sprout_video_details and upload_video Tauri commands
  */
 export interface SproutVideoDetails {
   /** Sprout Video ID */
@@ -102,6 +108,12 @@ export interface SproutVideoDetails {
 
   /** ISO 8601 timestamp of video creation */
   created_at: string
+
+  /** HTML embed snippet for the video */
+  embed_code?: string
+
+  /** Public embed URL, when the video has one */
+  embedded_url?: string
 }
 
 /**
@@ -50,4 +50,33 @@ export interface InstallResult {
 
   /** Full path where plugin was installed */
   installedPath: string
+
+  /** Where the previous installation was backed up, null on a fresh install */
+  backupPath: string | null
+
+  /** Older backups deleted to stay within the retention limit */
+  removedBackups: string[]
+}
+
+/**
+ * An extension found in a CEP extensions directory, bundled or third-party
+ */
+export interface InstalledPlugin {
+  /** Bundle name from the manifest, or the folder name if it has none */
+  name: string
+
+  /** Folder name inside the CEP extensions directory */
+  directoryName: string
+
+  /** Bundle id from the manifest (e.g., "com.example.panel") */
+  bundleId: string | null
+
+  /** Bundle version from the manifest */
+  version: string | null
+
+  /** Full path to the extension folder */
+  installedPath: string
+
+  /** Which CEP extensions directory it was found in */
+  location: 'user' | 'system'
 }
@@ -29,10 +29,6 @@ export function generateBreadcrumbsPreview(
         ...current,
         files: projectData.files,
         numberOfCameras: projectData.cameraCount,
-        // Update createdBy format if it's an update
-        createdBy: current.createdBy.endsWith(' - updated by Baker')
-          ? current.createdBy
-          : `${current.createdBy} - updated by Baker`,
         lastModified: now,
         scannedBy: 'Baker',
         // Add folder size if missing
@@ -11,6 +11,35 @@ export interface GetFoldersResponse {
   folders: SproutFolder[]
 }
 
+// One page of folders from the get_sprout_folders Tauri command
+export interface SproutFolderPage {
+  folders: SproutFolder[]
+  total: number
+  page: number
+  has_next_page: boolean
+}
+
+// Current user's name from the get_username Tauri command
+export interface UsernameInfo {
+  raw: string
+  name: string
+  source: 'USERNAME' | 'USER' | 'whoami' | 'unknown'
+}
+
+// A file the move_files Tauri command couldn't copy or clean up
+export interface MoveFailure {
+  source: string
+  error: string
+}
+
+// Payload of the move_result and move_complete events from move_files
+export interface MoveReport {
+  copiedFiles: string[]
+  deletedSources: string[]
+  retainedSources: string[]
+  failures: MoveFailure[]
+}
+
 // interface for camera number and footage filename
 export interface FootageData {
   camera: number
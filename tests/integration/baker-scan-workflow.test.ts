@@ -72,7 +72,13 @@ describe('Baker Scan Workflow Integration', () => {
       invalidBreadcrumbs: false,
       lastScanned: '2025-01-01T00:02:00Z',
       cameraCount: 0,
-      validationErrors: ['Missing Footage folder']
+      validationErrors: [
+        {
+          code: 'MISSING_SUBFOLDER',
+          message: 'Missing required subfolder: Footage',
+          subfolder: 'Footage'
+        }
+      ]
     }
   ]
 
@@ -260,7 +266,9 @@ describe('Baker Scan Workflow Integration', () => {
     const project3 = scanResult?.projects.find(p => p.name === 'project3')
     expect(project3).toBeDefined()
     expect(project3?.isValid).toBe(false)
-    expect(project3?.validationErrors).toContain('Missing Footage folder')
+    expect(project3?.validationErrors.map((error) => error.code)).toContain(
+      'MISSING_SUBFOLDER'
+    )
   })
 
   test('should handle progress events during scan', async () => {
@@ -116,7 +116,13 @@ describe('ProjectListPanel - Performance Optimizations', () => {
           invalidBreadcrumbs: false,
           cameraCount: 1,
           lastScanned: '2025-12-11T10:00:00.000Z',
-          validationErrors: ['Missing required folder']
+          validationErrors: [
+            {
+              code: 'MISSING_SUBFOLDER',
+              message: 'Missing required subfolder: Footage',
+              subfolder: 'Footage'
+            }
+          ]
         }
       ]
 
@@ -414,7 +420,12 @@ describe('ProjectListPanel - Performance Optimizations', () => {
           invalidBreadcrumbs: true,
           cameraCount: 0,
           lastScanned: '2025-12-11T10:00:00.000Z',
-          validationErrors: ['Invalid structure', 'Missing folders']
+          validationErrors: [
+            {
+              code: 'NO_CAMERAS',
+              message: 'No Camera folders found in Footage directory'
+            }
+          ]
         }
       ]
 
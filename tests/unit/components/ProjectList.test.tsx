@@ -85,7 +85,13 @@ describe('ProjectList Component', () => {
       invalidBreadcrumbs: false,
       lastScanned: '2024-01-02T00:00:00Z',
       cameraCount: 2,
-      validationErrors: ['Missing Footage folder']
+      validationErrors: [
+        {
+          code: 'MISSING_SUBFOLDER',
+          message: 'Missing required subfolder: Footage',
+          subfolder: 'Footage'
+        }
+      ]
     },
     {
       path: '/projects/project3',
@@ -81,7 +81,13 @@ describe('ScanResults Component', () => {
       invalidBreadcrumbs: false,
       lastScanned: '2024-01-03T00:00:00Z',
       cameraCount: 0,
-      validationErrors: ['Missing Footage folder']
+      validationErrors: [
+        {
+          code: 'MISSING_SUBFOLDER',
+          message: 'Missing required subfolder: Footage',
+          subfolder: 'Footage'
+        }
+      ]
     },
     {
       path: '/projects/project4',
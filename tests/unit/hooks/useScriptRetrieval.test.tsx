@@ -58,7 +58,9 @@ describe('useScriptRetrieval Hook', () => {
       category: 'news',
       before_text: 'Raw news script text',
       after_text: 'Formatted news script',
-      similarity: 0.89
+      similarity: 0.89,
+      tags: ['news'],
+      source: 'bundled'
     },
     {
       id: '2',
@@ -66,7 +68,9 @@ describe('useScriptRetrieval Hook', () => {
       category: 'interview',
       before_text: 'Raw interview text',
       after_text: 'Formatted interview script',
-      similarity: 0.78
+      similarity: 0.78,
+      tags: ['interview'],
+      source: 'user-uploaded'
     },
     {
       id: '3',
@@ -74,7 +78,9 @@ describe('useScriptRetrieval Hook', () => {
       category: 'documentary',
       before_text: 'Raw documentary text',
       after_text: 'Formatted documentary script',
-      similarity: 0.71
+      similarity: 0.71,
+      tags: ['documentary'],
+      source: 'bundled'
     }
   ]
 
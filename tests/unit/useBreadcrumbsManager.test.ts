@@ -63,7 +63,8 @@ describe('useBreadcrumbsManager Hook', () => {
     expect(invoke).toHaveBeenCalledWith('baker_update_breadcrumbs', {
       projectPaths,
       createMissing: true,
-      backupOriginals: true
+      backupOriginals: true,
+      hashFiles: false
     })
   })
 